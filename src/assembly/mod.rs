@@ -11,7 +11,13 @@ pub enum InstructionSet
   i486,
   Pentium,
   Pentium2,
+  Pentium3,
+  Pentium4,
   amd64,
+  SandyBridge,
+  Bulldozer,
+  Haswell,
+  SkylakeX,
 }
 
 pub fn InstructionSet
@@ -28,7 +34,13 @@ pub fn InstructionSet
     InstructionSet::i486                =>  { "i486 (x86)"              },
     InstructionSet::Pentium             =>  { "Pentium (x86)"           },
     InstructionSet::Pentium2            =>  { "Pentium II (x86)"        },
-    _                                   =>  { "Unknown Instruction Set" },
+    InstructionSet::Pentium3            =>  { "Pentium III (x86)"       },
+    InstructionSet::Pentium4            =>  { "Pentium 4 (x86)"         },
+    InstructionSet::amd64               =>  { "AMD64 (x86-64)"          },
+    InstructionSet::SandyBridge         =>  { "Sandy Bridge (x86-64)"   },
+    InstructionSet::Bulldozer           =>  { "Bulldozer (x86-64)"      },
+    InstructionSet::Haswell             =>  { "Haswell (x86-64)"        },
+    InstructionSet::SkylakeX            =>  { "Skylake-X (x86-64)"      },
   }
 }
 
@@ -37,7 +49,7 @@ bitflags!
   pub struct AssemblyFeatures:          usize
   {
     const None                          =   0b0000_0000_0000_0000_0000_0000_0000_0000;  //  No Features
-    const Default                       =   0b0000_0000_0000_0000_0000_0000_0000_0000;  //  Default
+    const Default                       =   Self::X86BranchExpansion.bits;                  //  Default
 
     //  Randomise Order
     const RandomExecutionOrder          =   0b0000_0000_0000_0000_0000_0000_0000_0001;  //  Randomise Order of Execution by moving around instructions
@@ -68,6 +80,8 @@ bitflags!
     const X86ThreeByteVEX               =   0b0000_0100_0000_0000_0000_0000_0000_0000;  //  Instruction is a Three Byte VEX Instruction
     const X86TwoByteOpcode              =   0b0000_0010_0000_0000_0000_0000_0000_0000;  //  Instruction is a Two Byte Instruction/haz 0x0f-Prefix
     const X86SignExtensionAllowed       =   0b0000_0001_0000_0000_0000_0000_0000_0000;  //  Sign Extended Immediate Values are allowed before i386 (true: add, adc, sub, sbb, cmp; false: and, or, xor)
+    const X86UndocumentedOpcodes        =   0b0000_0000_1000_0000_0000_0000_0000_0000;  //  Allow undocumented Opcodes like pop cs on 8086
+    const X86BranchExpansion            =   0b0000_0000_0100_0000_0000_0000_0000_0000;  //  Expand Conditional Jumps out of Range to a Jump over a Near Jump, if there is no Near Form
   }
 }
 
@@ -3,6 +3,7 @@ use super::
   memory::
   {
     Memory16Registers,
+    Memory32NoRegister,
   },
   operands::
   {
@@ -47,6 +48,8 @@ impl Expression
           =>  Ok                        ( ( 0,            vec!  ( token ) ) ),  //←
           ExpressionToken::Memory16               { size, segment,  registers,  displacement  }
           =>  unimplemented!(),
+          ExpressionToken::Memory32               { .. }
+          =>  Err                       ( "Nested Memory Operand in Memory Address Calculation" ),
           ExpressionToken::Add        |
           ExpressionToken::Substract
           =>  {
@@ -203,6 +206,70 @@ impl Expression
                 Err         ( "Non-Constant Rest as Displacement in Memory Address Calculation" )
               }
             },
+        ExpressionToken::Memory32               { width,  size, segment,  .. }
+        =>  {
+              let mut rest              =   Expression::calculate ( stack )?;
+              let mut registers         =   vec!();
+              //  64 bit addressing has twice as many registers
+              for number                in  0 .. ( width / 4 ) as u8
+              {
+                let ( factor, remainder )
+                                        =   Expression::dimension ( &mut rest,  &ExpressionToken::GeneralPurposeRegister { rex:  false,  size: width / 8,  number } )?;
+                rest                    =   remainder;
+                if factor != 0
+                {
+                  registers.push        ( ( number, factor ) );
+                }
+              }
+              let rest                  =   Expression::calculate ( &mut rest )?;
+              if let  [ ExpressionToken::Constant ( value ) ] = rest.as_slice()
+              {
+                //  esp cannot be an index, but a factor of 3, 5 or 9 can be expressed as base plus scaled index
+                let ( base, scale, index )
+                                        =   match registers.as_slice()
+                                            {
+                                              []
+                                              =>  ( Memory32NoRegister, 1,                    Memory32NoRegister  ),
+                                              [ ( base,   1                   ) ]
+                                              =>  ( *base,              1,                    Memory32NoRegister  ),
+                                              [ ( index,  scale @ ( 2 | 4 | 8 ) ) ]
+                                              if *index != 4
+                                              =>  ( Memory32NoRegister, *scale as u8,         *index              ),
+                                              [ ( index,  factor @ ( 3 | 5 | 9 ) ) ]
+                                              if *index != 4
+                                              =>  ( *index,             ( *factor - 1 ) as u8,  *index              ),
+                                              [ ( first,  1                   ), ( second, 1                   ) ]
+                                              =>  if *second == 4
+                                                  {
+                                                    ( *second,          1,                    *first              )
+                                                  }
+                                                  else
+                                                  {
+                                                    ( *first,           1,                    *second             )
+                                                  },
+                                              [ ( base,   1                   ), ( index,  scale @ ( 2 | 4 | 8 ) ) ]
+                                              if *index != 4
+                                              =>  ( *base,              *scale as u8,         *index              ),
+                                              [ ( index,  scale @ ( 2 | 4 | 8 ) ), ( base,   1                   ) ]
+                                              if *index != 4
+                                              =>  ( *base,              *scale as u8,         *index              ),
+                                              _
+                                              =>  return Err ( "Invalid Combination of Registers for 32 Bit Addressing" ),
+                                            };
+                let segment             =   match ( segment, base )
+                                            {
+                                              ( SegmentRegisterNumber::Default, 4 ) |
+                                              ( SegmentRegisterNumber::Default, 5 ) =>  SegmentRegisterNumber::SS,
+                                              ( SegmentRegisterNumber::Default, _ ) =>  SegmentRegisterNumber::DS,
+                                              ( _,                              _ ) =>  segment,
+                                            };
+                Ok  ( vec!  ( ExpressionToken::Memory32 { width, size, segment, base, scale, index, displacement: *value } ) )
+              }
+              else
+              {
+                Err         ( "Non-Constant Rest as Displacement in Memory Address Calculation" )
+              }
+            },
         _
         if  token >= ExpressionToken::Add
         =>  {
@@ -338,17 +405,25 @@ impl Expression
     &self
   ) -> Result<( Option<usize>, OperandType ), &'static str>
   {
-    let     stack                       =   Expression::calculate ( &mut self.0.clone() )?;
+    let mut tokens                      =   self.0.clone();
+    let     stack                       =   Expression::calculate ( &mut tokens )?;
+    //  every operator consumes its operands, so remaining operands lack an operator
+    if !tokens.is_empty()
+    {
+      return Err ( "Operator Expected, but Operands Remain on the Stack" );
+    }
     match stack.as_slice()
     {
       [ ExpressionToken::Constant               ( value                                     ) ]
       =>  Ok  ( ( Some  ( 0 ),      OperandType::Constant               ( *value                                                                  ) ) ),
       [ ExpressionToken::GeneralPurposeRegister { rex, size, number                         } ]
-      =>  Ok  ( ( Some  ( *size ),  OperandType::GeneralPurposeRegister { rex:  *rex, number:  *number                                            } ) ),
+      =>  Ok  ( ( Some  ( *size ),  OperandType::GeneralPurposeRegister { rex:  *rex, number:  *number, size: *size                               } ) ),
       [ ExpressionToken::SegmentRegister        ( register                                  ) ]
       =>  Ok  ( ( Some  ( 2 ),      OperandType::SegmentRegister        ( *register                                                               ) ) ),
       [ ExpressionToken::Memory16               { size, segment,  registers,  displacement  } ]
       =>  Ok  ( ( Some  ( *size ),  OperandType::Memory16               { segment:  *segment, registers:  *registers, displacement: *displacement } ) ),
+      [ ExpressionToken::Memory32               { width,  size, segment,  base, scale,  index,  displacement  } ]
+      =>  Ok  ( ( Some  ( *size ),  OperandType::Memory32               { width:  *width, segment:  *segment, base: *base, scale: *scale, index: *index, displacement: *displacement } ) ),
       _
       =>  Ok  ( ( None,             OperandType::Expression             ( Expression  ( stack )                                                   ) ) ),
     }
@@ -370,7 +445,7 @@ impl Expression
         ExpressionToken::Constant               ( value               )
                                         =>  output  +=  &format! ( "{}", value ),
         ExpressionToken::GeneralPurposeRegister { rex,  size, number  }
-                                        =>  output  +=  &OperandType::GeneralPurposeRegister  { rex:  *rex, number: *number }.to_string ( *size ),
+                                        =>  output  +=  &OperandType::GeneralPurposeRegister  { rex:  *rex, number: *number, size: *size }.to_string ( *size ),
         ExpressionToken::SegmentRegister        ( register            )
                                         =>  output  +=  &OperandType::SegmentRegister         ( *register                   ).to_string ( 2     ),
         ExpressionToken::Memory16               {       size, segment,  registers,  displacement  }
@@ -383,6 +458,10 @@ impl Expression
                                                             2 =>  "word".to_string(),
                                                             4 =>  "dword".to_string(),
                                                             8 =>  "qword".to_string(),
+                                                            10  =>  "tword".to_string(),
+                                                            16  =>  "oword".to_string(),
+                                                            32  =>  "yword".to_string(),
+                                                            64  =>  "zword".to_string(),
                                                             _ =>  format! ( "{}", size ),
                                                           },
                                                           segment.to_string(),
@@ -401,6 +480,21 @@ impl Expression
                                                             _                       =>  " + ???",
                                                           }
                                                         ),
+        ExpressionToken::Memory32               { width,  size, segment,  base, scale,  index,  displacement  }
+                                        =>  output  +=  &format!
+                                                        (
+                                                          "{} as mem{}",
+                                                          OperandType::Memory32
+                                                          {
+                                                            width:        *width,
+                                                            segment:      *segment,
+                                                            base:         *base,
+                                                            scale:        *scale,
+                                                            index:        *index,
+                                                            displacement: *displacement,
+                                                          }.to_string ( *size ),
+                                                          width,
+                                                        ),
         ExpressionToken::Neg            =>  output  +=  "~",
         ExpressionToken::Add            =>  output  +=  "+",
         ExpressionToken::Substract      =>  output  +=  "-",
@@ -441,6 +535,16 @@ pub enum ExpressionToken
     registers:                          Memory16Registers,
     displacement:                       i128,
   },
+  Memory32
+  {
+    width:                              usize,              //  32 or 64 bit registers
+    size:                               usize,
+    segment:                            SegmentRegisterNumber,
+    base:                               u8,
+    scale:                              u8,
+    index:                              u8,
+    displacement:                       i128,
+  },
   //  One Operand Operators
   Neg,
   BitwiseNot,
@@ -491,6 +595,14 @@ macro_rules! nextToken
   ( bpl                 )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  true,   size: 1,  number: 5 } };
   ( sil                 )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  true,   size: 1,  number: 6 } };
   ( dil                 )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  true,   size: 1,  number: 7 } };
+  ( r8b                 )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 1,  number: 8 } };
+  ( r9b                 )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 1,  number: 9 } };
+  ( r10b                )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 1,  number: 10 } };
+  ( r11b                )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 1,  number: 11 } };
+  ( r12b                )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 1,  number: 12 } };
+  ( r13b                )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 1,  number: 13 } };
+  ( r14b                )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 1,  number: 14 } };
+  ( r15b                )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 1,  number: 15 } };
   ( ax                  )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 2,  number: 0 } };
   ( cx                  )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 2,  number: 1 } };
   ( dx                  )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 2,  number: 2 } };
@@ -499,6 +611,14 @@ macro_rules! nextToken
   ( bp                  )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 2,  number: 5 } };
   ( si                  )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 2,  number: 6 } };
   ( di                  )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 2,  number: 7 } };
+  ( r8w                 )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 2,  number: 8 } };
+  ( r9w                 )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 2,  number: 9 } };
+  ( r10w                )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 2,  number: 10 } };
+  ( r11w                )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 2,  number: 11 } };
+  ( r12w                )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 2,  number: 12 } };
+  ( r13w                )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 2,  number: 13 } };
+  ( r14w                )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 2,  number: 14 } };
+  ( r15w                )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 2,  number: 15 } };
   ( eax                 )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 4,  number: 0 } };
   ( ecx                 )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 4,  number: 1 } };
   ( edx                 )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 4,  number: 2 } };
@@ -507,6 +627,14 @@ macro_rules! nextToken
   ( ebp                 )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 4,  number: 5 } };
   ( esi                 )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 4,  number: 6 } };
   ( edi                 )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 4,  number: 7 } };
+  ( r8d                 )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 4,  number: 8 } };
+  ( r9d                 )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 4,  number: 9 } };
+  ( r10d                )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 4,  number: 10 } };
+  ( r11d                )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 4,  number: 11 } };
+  ( r12d                )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 4,  number: 12 } };
+  ( r13d                )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 4,  number: 13 } };
+  ( r14d                )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 4,  number: 14 } };
+  ( r15d                )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 4,  number: 15 } };
   ( rax                 )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 8,  number: 0 } };
   ( rcx                 )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 8,  number: 1 } };
   ( rdx                 )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 8,  number: 2 } };
@@ -515,6 +643,14 @@ macro_rules! nextToken
   ( rbp                 )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 8,  number: 5 } };
   ( rsi                 )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 8,  number: 6 } };
   ( rdi                 )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 8,  number: 7 } };
+  ( r8                  )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 8,  number: 8 } };
+  ( r9                  )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 8,  number: 9 } };
+  ( r10                 )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 8,  number: 10 } };
+  ( r11                 )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 8,  number: 11 } };
+  ( r12                 )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 8,  number: 12 } };
+  ( r13                 )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 8,  number: 13 } };
+  ( r14                 )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 8,  number: 14 } };
+  ( r15                 )               =>  { ExpressionToken::GeneralPurposeRegister { rex:  false,  size: 8,  number: 15 } };
   ( cs                  )               =>  { ExpressionToken::SegmentRegister        ( SegmentRegisterNumber::CS         ) };
   ( ss                  )               =>  { ExpressionToken::SegmentRegister        ( SegmentRegisterNumber::SS         ) };
   ( ds                  )               =>  { ExpressionToken::SegmentRegister        ( SegmentRegisterNumber::DS         ) };
@@ -0,0 +1,87 @@
+use super::
+{
+  Instruction,
+  InstructionType,
+  super::
+  {
+    X86,
+    operands::
+    {
+      Operand,
+      OperandType,
+    },
+  },
+};
+
+macro_rules! theInstruction
+{
+  (
+    $theName:ident,
+    $theInstruction:expr
+  )
+  =>  {
+        pub fn $theName
+        (
+          mut self,
+          base:                         impl Operand,
+        ) -> Self
+        {
+          let ( baseThis, _ )           =   base.this();
+          self.instructions.push
+          (
+            Instruction
+            (
+              self.line,
+              self.features,
+              0,
+              $theInstruction,
+              vec!  ( baseThis ),
+            )
+          );
+          self.line                     +=  1;
+          self
+        }
+      }
+}
+
+impl X86
+{
+  theInstruction! ( aadImm, InstructionType::AAD    );
+  theInstruction! ( aamImm, InstructionType::AAM    );
+}
+
+impl  Instruction
+{
+  pub fn compileAsciiAdjustInstruction
+  (
+    &mut self,
+    opcode:                             u8,
+  ) -> Result<Option<usize>, String>
+  {
+    //  the base is an immediate byte, the documented mnemonics just imply base 10
+    let base                            =   match self.operands.as_slice()
+                                            {
+                                              []                                  =>  10,
+                                              [ OperandType::Constant ( base )  ] =>  *base,
+                                              _
+                                              =>  return self.fail
+                                                  (
+                                                    format!
+                                                    (
+                                                      "Instruction Takes At Most One Constant Argument, got {}",
+                                                      self.operands.len(),
+                                                    )
+                                                  ),
+                                            };
+    if ( 0 ..= 0xff ).contains ( &base )
+    {
+      self.setOpcode                        ( opcode                                                          );
+      self.setImmediate                     ( 1,            base                                              );
+      Ok  ( Some  ( self.getLength() ) )
+    }
+    else
+    {
+      self.failOutOfBounds                  ( 0,            0xff,         base                                )
+    }
+  }
+}
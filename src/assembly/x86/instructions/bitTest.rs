@@ -0,0 +1,158 @@
+use super::
+{
+  Instruction,
+  InstructionType,
+  super::
+  {
+    InstructionSet,
+    X86,
+    operands::
+    {
+      Operand,
+      OperandType,
+    },
+  },
+};
+
+macro_rules! theInstruction
+{
+  (
+    $theName:ident,
+    $theInstruction:expr
+  )
+  =>  {
+        pub fn $theName
+        (
+          mut self,
+          dst:                          impl Operand,
+          src:                          impl Operand,
+        ) -> Self
+        {
+          let ( dstThis, dstSize )      =   dst.this();
+          let ( srcThis, srcSize )      =   src.this();
+          let size                      =   ( dstSize | srcSize ) as usize;
+          self.instructions.push
+          (
+            Instruction
+            (
+              self.line,
+              self.features,
+              size,
+              $theInstruction,
+              vec!  ( dstThis,  srcThis ),
+            )
+          );
+          self.line                     +=  1;
+          self
+        }
+      }
+}
+
+impl X86
+{
+  theInstruction! ( bsf,  InstructionType::BSF  );
+  theInstruction! ( bsr,  InstructionType::BSR  );
+  theInstruction! ( bt,   InstructionType::BT   );
+  theInstruction! ( btc,  InstructionType::BTC  );
+  theInstruction! ( btr,  InstructionType::BTR  );
+  theInstruction! ( bts,  InstructionType::BTS  );
+}
+
+impl  Instruction
+{
+  pub fn compileBitTestInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    operandSize:                        usize,
+    addressSize:                        usize,
+    regField:                           u8,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet              ( architecture, InstructionSet::i386                              )?;
+    match self.operands.clone().as_slice()
+    {
+      [
+        dst @ OperandType::GeneralPurposeRegister { .. } |
+        dst @ OperandType::Memory16               { .. } |
+        dst @ OperandType::Memory32               { .. },
+        OperandType::GeneralPurposeRegister { number:   srcRegister,  .. }
+      ]
+      if self.size != 1
+      =>  {
+            //  0x0f 0xa3, 0xab, 0xb3 and 0xbb follow the reg field of 0x0f 0xba
+            self.encodeOperandSize          ( architecture, operandSize                                       )?;
+            self.setTwoByteOpcode           ( true                                                            );
+            self.setOpcode                  ( 0x83  | regField  <<  3                                         );
+            self.encodeModRegRMdata         ( addressSize,  *srcRegister, dst                                 )?;
+            Ok  ( Some  ( self.getLength() ) )
+          },
+      [
+        dst @ OperandType::GeneralPurposeRegister { .. } |
+        dst @ OperandType::Memory16               { .. } |
+        dst @ OperandType::Memory32               { .. },
+        OperandType::Constant               ( immediate )
+      ]
+      if self.size != 1
+      =>  {
+            self.encodeOperandSize          ( architecture, operandSize                                       )?;
+            if  *immediate >= 0
+            &&  *immediate <= 0xff
+            {
+              self.setTwoByteOpcode         ( true                                                            );
+              self.setOpcode                ( 0xba                                                            );
+              self.setImmediate             ( 1,            *immediate                                        );
+              self.encodeModRegRMdata       ( addressSize,  regField,     dst                                 )?;
+              Ok  ( Some  ( self.getLength() ) )
+            }
+            else
+            {
+              self.failOutOfBounds          ( 0,            0xff,         *immediate                          )
+            }
+          },
+      [
+        OperandType::GeneralPurposeRegister { .. } | OperandType::Memory16 { .. } | OperandType::Memory32 { .. },
+        OperandType::GeneralPurposeRegister { .. } | OperandType::Constant ( _ )
+      ]
+      =>  self.failOperandSize(),
+      _
+      =>  self.fail ( "Instruction Must Take a Register or Memory and a Register or Constant Argument".to_string() ),
+    }
+  }
+
+  pub fn compileBitScanInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    operandSize:                        usize,
+    addressSize:                        usize,
+    opcode:                             u8,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet              ( architecture, InstructionSet::i386                              )?;
+    match self.operands.clone().as_slice()
+    {
+      [
+        OperandType::GeneralPurposeRegister { number:   dstRegister,  .. },
+        src @ OperandType::GeneralPurposeRegister { .. } |
+        src @ OperandType::Memory16               { .. } |
+        src @ OperandType::Memory32               { .. }
+      ]
+      if self.size != 1
+      =>  {
+            self.encodeOperandSize          ( architecture, operandSize                                       )?;
+            self.setTwoByteOpcode           ( true                                                            );
+            self.setOpcode                  ( opcode                                                          );
+            self.encodeModRegRMdata         ( addressSize,  *dstRegister, src                                 )?;
+            Ok  ( Some  ( self.getLength() ) )
+          },
+      [
+        OperandType::GeneralPurposeRegister { .. },
+        OperandType::GeneralPurposeRegister { .. } | OperandType::Memory16 { .. } | OperandType::Memory32 { .. }
+      ]
+      =>  self.failOperandSize(),
+      _
+      =>  self.fail ( "Instruction Must Take a Register and a Register or Memory Argument".to_string() ),
+    }
+  }
+}
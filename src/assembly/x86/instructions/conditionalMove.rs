@@ -0,0 +1,109 @@
+use super::
+{
+  Instruction,
+  InstructionType,
+  super::
+  {
+    InstructionSet,
+    X86,
+    operands::
+    {
+      Operand,
+      OperandType,
+    },
+  },
+};
+
+macro_rules! theInstruction
+{
+  (
+    $theName:ident,
+    $theInstruction:expr
+  )
+  =>  {
+        pub fn $theName
+        (
+          mut self,
+          dst:                          impl Operand,
+          src:                          impl Operand,
+        ) -> Self
+        {
+          let ( dstThis, dstSize )      =   dst.this();
+          let ( srcThis, srcSize )      =   src.this();
+          let size                      =   ( dstSize | srcSize ) as usize;
+          self.instructions.push
+          (
+            Instruction
+            (
+              self.line,
+              self.features,
+              size,
+              $theInstruction,
+              vec!  ( dstThis,  srcThis ),
+            )
+          );
+          self.line                     +=  1;
+          self
+        }
+      }
+}
+
+impl X86
+{
+  theInstruction! ( cmovb,   InstructionType::CMOVB   );
+  theInstruction! ( cmovbe,  InstructionType::CMOVBE  );
+  theInstruction! ( cmove,   InstructionType::CMOVE   );
+  theInstruction! ( cmovl,   InstructionType::CMOVL   );
+  theInstruction! ( cmovle,  InstructionType::CMOVLE  );
+  theInstruction! ( cmovnb,  InstructionType::CMOVNB  );
+  theInstruction! ( cmovnbe, InstructionType::CMOVNBE );
+  theInstruction! ( cmovne,  InstructionType::CMOVNE  );
+  theInstruction! ( cmovnl,  InstructionType::CMOVNL  );
+  theInstruction! ( cmovnle, InstructionType::CMOVNLE );
+  theInstruction! ( cmovno,  InstructionType::CMOVNO  );
+  theInstruction! ( cmovnp,  InstructionType::CMOVNP  );
+  theInstruction! ( cmovns,  InstructionType::CMOVNS  );
+  theInstruction! ( cmovo,   InstructionType::CMOVO   );
+  theInstruction! ( cmovp,   InstructionType::CMOVP   );
+  theInstruction! ( cmovs,   InstructionType::CMOVS   );
+  theInstruction! ( cmovz,   InstructionType::CMOVE   );
+}
+
+impl  Instruction
+{
+  pub fn compileConditionalMoveInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    operandSize:                        usize,
+    addressSize:                        usize,
+    condition:                          u8,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet              ( architecture, InstructionSet::Pentium2                          )?;
+    match self.operands.clone().as_slice()
+    {
+      [
+        OperandType::GeneralPurposeRegister { number:   dstRegister,  .. },
+        src @ OperandType::GeneralPurposeRegister { .. } |
+        src @ OperandType::Memory16               { .. } |
+        src @ OperandType::Memory32               { .. }
+      ]
+      if self.size != 1
+      =>  {
+            self.encodeOperandSize          ( architecture, operandSize                                       )?;
+            self.setTwoByteOpcode           ( true                                                            );
+            self.setOpcode                  ( 0x40  | condition                                               );
+            self.encodeModRegRMdata         ( addressSize,  *dstRegister, src                                 )?;
+            Ok  ( Some  ( self.getLength() ) )
+          },
+      [
+        OperandType::GeneralPurposeRegister { .. },
+        OperandType::GeneralPurposeRegister { .. } | OperandType::Memory16 { .. } | OperandType::Memory32 { .. }
+      ]
+      =>  self.failOperandSize(),
+      _
+      =>  self.fail ( "Instruction Must Take a Register and a Register or Memory Argument".to_string() ),
+    }
+  }
+}
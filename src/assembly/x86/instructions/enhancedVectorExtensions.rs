@@ -0,0 +1,763 @@
+use super::
+{
+  EnhancedVectorOptions,
+  Instruction,
+  InstructionType,
+  RoundingControl,
+  REX,
+  REXX,
+  VectorOpcode,
+  super::
+  {
+    InstructionSet,
+    X86,
+    operands::
+    {
+      Operand,
+      OperandType,
+    },
+    registers::
+    {
+      MaskRegister,
+    },
+  },
+};
+
+macro_rules! theInstruction
+{
+  (
+    $theName:ident,
+    $theInstruction:expr,
+    dst,
+    src
+  )
+  =>  {
+        pub fn $theName
+        (
+          mut self,
+          dst:                          impl Operand,
+          src:                          impl Operand,
+        ) -> Self
+        {
+          let ( dstThis, dstSize )      =   dst.this();
+          let ( srcThis, srcSize )      =   src.this();
+          let size                      =   ( dstSize | srcSize ) as usize;
+          self.instructions.push
+          (
+            Instruction
+            (
+              self.line,
+              self.features,
+              size,
+              $theInstruction,
+              vec!  ( dstThis,  srcThis ),
+            )
+          );
+          self.line                     +=  1;
+          self
+        }
+      };
+  (
+    $theName:ident,
+    $theInstruction:expr,
+    dst,
+    src1,
+    src2
+  )
+  =>  {
+        pub fn $theName
+        (
+          mut self,
+          dst:                          impl Operand,
+          src1:                         impl Operand,
+          src2:                         impl Operand,
+        ) -> Self
+        {
+          let ( dstThis,  dstSize  )    =   dst.this();
+          let ( src1This, src1Size )    =   src1.this();
+          let ( src2This, src2Size )    =   src2.this();
+          let size                      =   ( dstSize | src1Size | src2Size ) as usize;
+          self.instructions.push
+          (
+            Instruction
+            (
+              self.line,
+              self.features,
+              size,
+              $theInstruction,
+              vec!  ( dstThis,  src1This, src2This  ),
+            )
+          );
+          self.line                     +=  1;
+          self
+        }
+      };
+  (
+    $theName:ident,
+    $theInstruction:expr,
+    dst,
+    src1,
+    src2,
+    immediate
+  )
+  =>  {
+        pub fn $theName
+        (
+          mut self,
+          dst:                          impl Operand,
+          src1:                         impl Operand,
+          src2:                         impl Operand,
+          immediate:                    impl Operand,
+        ) -> Self
+        {
+          let ( dstThis,        dstSize  )  =   dst.this();
+          let ( src1This,       src1Size )  =   src1.this();
+          let ( src2This,       src2Size )  =   src2.this();
+          let ( immediateThis,  _        )  =   immediate.this();
+          let size                      =   ( dstSize | src1Size | src2Size ) as usize;
+          self.instructions.push
+          (
+            Instruction
+            (
+              self.line,
+              self.features,
+              size,
+              $theInstruction,
+              vec!  ( dstThis,  src1This, src2This, immediateThis ),
+            )
+          );
+          self.line                     +=  1;
+          self
+        }
+      };
+}
+
+impl X86
+{
+  theInstruction! ( kandnw,       InstructionType::KANDNW,        dst,  src1, src2            );
+  theInstruction! ( kandw,        InstructionType::KANDW,         dst,  src1, src2            );
+  theInstruction! ( kmovw,        InstructionType::KMOVW,         dst,  src                   );
+  theInstruction! ( knotw,        InstructionType::KNOTW,         dst,  src                   );
+  theInstruction! ( kortestw,     InstructionType::KORTESTW,      dst,  src                   );
+  theInstruction! ( korw,         InstructionType::KORW,          dst,  src1, src2            );
+  theInstruction! ( kxorw,        InstructionType::KXORW,         dst,  src1, src2            );
+  theInstruction! ( vmovdqa32,    InstructionType::VMOVDQA32,     dst,  src                   );
+  theInstruction! ( vmovdqa64,    InstructionType::VMOVDQA64,     dst,  src                   );
+  theInstruction! ( vmovdqu32,    InstructionType::VMOVDQU32,     dst,  src                   );
+  theInstruction! ( vmovdqu64,    InstructionType::VMOVDQU64,     dst,  src                   );
+  theInstruction! ( vpandd,       InstructionType::VPANDD,        dst,  src1, src2            );
+  theInstruction! ( vpandnd,      InstructionType::VPANDND,       dst,  src1, src2            );
+  theInstruction! ( vpandnq,      InstructionType::VPANDNQ,       dst,  src1, src2            );
+  theInstruction! ( vpandq,       InstructionType::VPANDQ,        dst,  src1, src2            );
+  theInstruction! ( vpcmpeqq,     InstructionType::VPCMPEQQ,      dst,  src1, src2            );
+  theInstruction! ( vpord,        InstructionType::VPORD,         dst,  src1, src2            );
+  theInstruction! ( vporq,        InstructionType::VPORQ,         dst,  src1, src2            );
+  theInstruction! ( vpternlogd,   InstructionType::VPTERNLOGD,    dst,  src1, src2, immediate );
+  theInstruction! ( vpternlogq,   InstructionType::VPTERNLOGQ,    dst,  src1, src2, immediate );
+  theInstruction! ( vpxord,       InstructionType::VPXORD,        dst,  src1, src2            );
+  theInstruction! ( vpxorq,       InstructionType::VPXORQ,        dst,  src1, src2            );
+
+  //  the decorations apply to the previous instruction, like the {k1}{z}, {1toN} and {rn-sae} suffixes of other assemblers
+  pub fn mask
+  (
+    mut self,
+    register:                           MaskRegister,
+  ) -> Self
+  {
+    if let Some ( instruction ) = self.instructions.last_mut()
+    {
+      instruction.setOpmask             ( register.number );
+    }
+    self
+  }
+
+  pub fn zeroing
+  (
+    mut self,
+  ) -> Self
+  {
+    if let Some ( instruction ) = self.instructions.last_mut()
+    {
+      instruction.setZeroing            ( true            );
+    }
+    self
+  }
+
+  pub fn broadcast
+  (
+    mut self,
+  ) -> Self
+  {
+    if let Some ( instruction ) = self.instructions.last_mut()
+    {
+      instruction.setBroadcast          ( true            );
+    }
+    self
+  }
+
+  pub fn rounding
+  (
+    mut self,
+    rounding:                           RoundingControl,
+  ) -> Self
+  {
+    if let Some ( instruction ) = self.instructions.last_mut()
+    {
+      instruction.setRounding           ( rounding        );
+    }
+    self
+  }
+}
+
+impl  Instruction
+{
+  //  AVX-512 reuses the mnemonics of AVX, which are only EVEX encoded, if they need one of its features
+  pub fn needsEnhancedVector
+  (
+    &self,
+  ) -> bool
+  {
+    self.size & 64 != 0
+    ||  self.getOpmask() != 0
+    ||  self.hazZeroing()
+    ||  self.hazBroadcast()
+    ||  self.getRounding().is_some()
+    ||  self.operands.iter().any
+        (
+          |operand|
+          matches!
+          (
+            operand,
+            OperandType::MulitMediaRegister ( 16 ..= 31 ) |
+            OperandType::MaskRegister       ( _         )
+          )
+        )
+  }
+
+  //  the opmask is always allowed, zeroing, broadcast and embedded rounding only if the instruction supports them
+  fn validateDecorations
+  (
+    &self,
+    options:                            EnhancedVectorOptions,
+  ) -> Result<(), String>
+  {
+    if  self.hazZeroing()
+    &&  !options.zeroing
+    {
+      self.fail ( "Instruction does not Support Zeroing".to_string() )
+    }
+    else if self.hazZeroing()
+    &&      self.getOpmask() == 0
+    {
+      self.fail ( "Zeroing Requires an Opmask".to_string() )
+    }
+    else if self.hazBroadcast()
+    &&      !options.broadcast
+    {
+      self.fail ( "Instruction does not Support Broadcast".to_string() )
+    }
+    else if self.getRounding().is_some()
+    &&      !options.rounding
+    {
+      self.fail ( "Instruction does not Support Embedded Rounding".to_string() )
+    }
+    else
+    {
+      Ok  ( () )
+    }
+  }
+
+  //  the prefix is compiled into the EVEX prefix later, eight bit displacements are scaled by the displacement scale
+  pub fn encodeEnhancedVectorOperands
+  (
+    &mut self,
+    addressSize:                        usize,
+    opcode:                             VectorOpcode,
+    options:                            EnhancedVectorOptions,
+    regField:                           u8,
+    vectorRegister:                     u8,
+    operand:                            &OperandType,
+  ) -> Result<(), String>
+  {
+    //  X extends the register in r/m to the registers 16 to 31
+    if let OperandType::MulitMediaRegister ( 16 ..= 31 ) = operand
+    {
+      self.theREX                       |=  REX | REXX;
+    }
+    let   vectorSize                    =   if self.size & 64 != 0 { 64 } else if self.size & 32 != 0 { 32 } else { 16 };
+    self.setFourByteEVEX                    ( true                                                            );
+    self.setMandatoryPrefix                 ( opcode.prefix                                                   );
+    self.setVectorMap                       ( opcode.map                                                      );
+    self.setVectorRegister                  ( vectorRegister                                                  );
+    self.setExtendedRegField                ( regField & 16 != 0                                              );
+    self.setVectorLength                    ( match vectorSize { 64 => 2, 32 => 1, _ => 0 }                   );
+    self.setVectorWidth                     ( options.wide                                                    );
+    self.setOpcode                          ( opcode.opcode                                                   );
+    self.encodeModRegRMdata                 ( addressSize,  regField,     operand                             )
+  }
+
+  //  like encodeVectorInstruction, but with ZMM registers, broadcasts of single elements and rounding of register operands
+  pub fn encodeEnhancedVectorInstruction
+  (
+    &mut self,
+    addressSize:                        usize,
+    opcode:                             VectorOpcode,
+    options:                            EnhancedVectorOptions,
+    regField:                           u8,
+    vectorRegister:                     u8,
+    operand:                            &OperandType,
+  ) -> Result<(), String>
+  {
+    self.validateDecorations                ( options                                                         )?;
+    let   memorySize                    =   options.memorySize;
+    let   elementSize                   =   if options.wide { 8 } else { 4 };
+    let   vectorSize                    =   if self.size & 64 != 0 { 64 } else if self.size & 32 != 0 { 32 } else { 16 };
+    if  memorySize  != 0
+    &&  vectorSize  != 16
+    {
+      return self.failOperandSize();
+    }
+    if self.getRounding().is_some()
+    {
+      if !matches! ( operand, OperandType::MulitMediaRegister ( _ ) )
+      {
+        return self.fail ( "Embedded Rounding Requires Register Operands".to_string() );
+      }
+      if  vectorSize  != 64
+      &&  memorySize  == 0
+      {
+        return self.fail ( "Embedded Rounding Requires ZMM Registers or Scalar Operands".to_string() );
+      }
+    }
+    if  self.hazBroadcast()
+    &&  !matches! ( operand, OperandType::Memory16 { .. } | OperandType::Memory32 { .. } )
+    {
+      return self.fail ( "Broadcast Requires a Memory Operand".to_string() );
+    }
+    let   scale                         =   match operand
+                                            {
+                                              OperandType::MulitMediaRegister     ( _   )
+                                              if self.size == vectorSize
+                                              =>  1,
+                                              OperandType::Memory16               { .. } |
+                                              OperandType::Memory32               { .. }
+                                              if  self.hazBroadcast()
+                                              &&  (
+                                                    self.size == vectorSize
+                                                  ||
+                                                    self.size == vectorSize | elementSize
+                                                  )
+                                              =>  elementSize,
+                                              OperandType::Memory16               { .. } |
+                                              OperandType::Memory32               { .. }
+                                              if  !self.hazBroadcast()
+                                              &&  (
+                                                    self.size == vectorSize
+                                                  ||
+                                                    self.size == vectorSize | memorySize
+                                                  )
+                                              =>  if memorySize != 0 { memorySize } else { vectorSize },
+                                              OperandType::MulitMediaRegister     ( _   ) |
+                                              OperandType::Memory16               { .. } |
+                                              OperandType::Memory32               { .. }
+                                              =>  return self.failOperandSize(),
+                                              _
+                                              =>  return self.fail ( "Instruction Must Take XMM, YMM or ZMM Registers and a XMM, YMM or ZMM Register or Memory Argument".to_string() ),
+                                            };
+    self.setDisplacementScale               ( scale as i128                                                   );
+    self.encodeEnhancedVectorOperands       ( addressSize,  opcode,       options,      regField,     vectorRegister, operand )
+  }
+
+  //  instructions with a VEX form fall back to it, unless they need AVX-512
+  pub fn compileEnhancedVectorInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+    fallback:                           Option<InstructionSet>,
+    opcode:                             VectorOpcode,
+    options:                            EnhancedVectorOptions,
+  ) -> Result<Option<usize>, String>
+  {
+    if let Some ( minimum ) = fallback
+    {
+      if !self.needsEnhancedVector()
+      {
+        return self.compileVectorInstruction  ( architecture, addressSize,  minimum,  opcode, options.memorySize  );
+      }
+    }
+    self.requireInstructionSet              ( architecture, InstructionSet::SkylakeX                          )?;
+    match self.operands.clone().as_slice()
+    {
+      [
+        OperandType::MulitMediaRegister     ( dstRegister ),
+        OperandType::MulitMediaRegister     ( srcRegister ),
+        src
+      ]
+      =>  {
+            self.encodeEnhancedVectorInstruction  ( addressSize,  opcode, options,  *dstRegister, *srcRegister, src )?;
+            Ok  ( Some  ( self.getLength() ) )
+          },
+      _
+      =>  self.fail ( "Instruction Must Take Two XMM, YMM or ZMM Registers and a XMM, YMM or ZMM Register or Memory Argument".to_string() ),
+    }
+  }
+
+  pub fn compileEnhancedVectorIntegerInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+    map:                                u8,
+    opcode:                             u8,
+    wide:                               bool,
+  ) -> Result<Option<usize>, String>
+  {
+    if !self.needsEnhancedVector()
+    {
+      return self.compileVectorIntegerInstruction ( architecture, addressSize,  map,  opcode                  );
+    }
+    self.compileEnhancedVectorInstruction   ( architecture, addressSize,  None, VectorOpcode  ( 0x66, map,  opcode  ),  EnhancedVectorOptions ( wide, 0,  false ) )
+  }
+
+  pub fn compileEnhancedVectorUnaryInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+    prefix:                             u8,
+    opcode:                             u8,
+    wide:                               bool,
+  ) -> Result<Option<usize>, String>
+  {
+    if !self.needsEnhancedVector()
+    {
+      return self.compileVectorUnaryInstruction ( architecture, addressSize,  prefix, opcode                  );
+    }
+    self.requireInstructionSet              ( architecture, InstructionSet::SkylakeX                          )?;
+    match self.operands.clone().as_slice()
+    {
+      [
+        OperandType::MulitMediaRegister     ( dstRegister ),
+        src
+      ]
+      =>  {
+            self.encodeEnhancedVectorInstruction  ( addressSize,  VectorOpcode  ( prefix, 1,  opcode  ),  EnhancedVectorOptions ( wide, 0,  true  ),  *dstRegister, 0,  src )?;
+            Ok  ( Some  ( self.getLength() ) )
+          },
+      _
+      =>  self.fail ( "Instruction Must Take a XMM, YMM or ZMM Register and a XMM, YMM or ZMM Register or Memory Argument".to_string() ),
+    }
+  }
+
+  //  masked stores leave the masked elements in memory untouched, they cannot be zeroed
+  pub fn compileEnhancedVectorMoveInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+    loadOpcode:                         VectorOpcode,
+    storeOpcode:                        u8,
+    wide:                               bool,
+    fallback:                           bool,
+  ) -> Result<Option<usize>, String>
+  {
+    if  fallback
+    &&  !self.needsEnhancedVector()
+    {
+      return self.compileVectorMoveInstruction  ( architecture, addressSize,  loadOpcode, storeOpcode         );
+    }
+    self.requireInstructionSet              ( architecture, InstructionSet::SkylakeX                          )?;
+    let   storeOpcode                   =   VectorOpcode  ( loadOpcode.prefix,  loadOpcode.map, storeOpcode   );
+    let   options                       =   EnhancedVectorOptions
+                                            {
+                                              broadcast:  false,
+                                              ..EnhancedVectorOptions ( wide, 0,  false )
+                                            };
+    match self.operands.clone().as_slice()
+    {
+      [
+        OperandType::MulitMediaRegister     ( dstRegister ),
+        src
+      ]
+      =>  self.encodeEnhancedVectorInstruction    ( addressSize,  loadOpcode,   options,  *dstRegister, 0,  src )?,
+      [
+        OperandType::Memory16               { .. } |
+        OperandType::Memory32               { .. },
+        OperandType::MulitMediaRegister     ( _   )
+      ]
+      if self.hazZeroing()
+      =>  return self.fail ( "Zeroing is not Possible with a Memory Destination".to_string() ),
+      [
+        dst @ OperandType::Memory16               { .. } |
+        dst @ OperandType::Memory32               { .. },
+        OperandType::MulitMediaRegister     ( srcRegister )
+      ]
+      =>  self.encodeEnhancedVectorInstruction    ( addressSize,  storeOpcode,  options,  *srcRegister, 0,  dst )?,
+      _
+      =>  return self.fail ( "Instruction Must Take a XMM, YMM or ZMM Register and a XMM, YMM or ZMM Register or Memory Argument".to_string() ),
+    }
+    Ok  ( Some  ( self.getLength() ) )
+  }
+
+  //  the EVEX form also takes ZMM destinations, the source is still a XMM register or a single element
+  pub fn compileEnhancedVectorBroadcastInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+    minimum:                            InstructionSet,
+    opcode:                             u8,
+    memorySize:                         usize,
+    wide:                               bool,
+  ) -> Result<Option<usize>, String>
+  {
+    if !self.needsEnhancedVector()
+    {
+      return self.compileVectorBroadcastInstruction ( architecture, addressSize,  minimum,  opcode, memorySize  );
+    }
+    self.requireInstructionSet              ( architecture, InstructionSet::SkylakeX                          )?;
+    //  the source is a single element already, therefore there is nothing to broadcast
+    let   options                       =   EnhancedVectorOptions ( wide, memorySize, false );
+    self.validateDecorations                ( options                                                         )?;
+    let   opcode                        =   VectorOpcode  ( 0x66, 2,  opcode  );
+    let   vectorSize                    =   if self.size & 64 != 0 { 64 } else if self.size & 32 != 0 { 32 } else { 16 };
+    match self.operands.clone().as_slice()
+    {
+      [
+        OperandType::MulitMediaRegister     ( dstRegister ),
+        src @ OperandType::MulitMediaRegister     ( _   )
+      ]
+      if  self.size == vectorSize | 16
+      =>  self.encodeEnhancedVectorOperands ( addressSize,  opcode,       options,      *dstRegister, 0,  src )?,
+      [
+        OperandType::MulitMediaRegister     ( dstRegister ),
+        src @ OperandType::Memory16               { .. } |
+        src @ OperandType::Memory32               { .. }
+      ]
+      if  self.size == vectorSize
+      ||  self.size == vectorSize | memorySize
+      =>  {
+            self.setDisplacementScale       ( memorySize as i128                                              );
+            self.encodeEnhancedVectorOperands ( addressSize,  opcode,     options,      *dstRegister, 0,  src )?;
+          },
+      [
+        OperandType::MulitMediaRegister     ( _   ),
+        OperandType::MulitMediaRegister     ( _   ) |
+        OperandType::Memory16               { .. } |
+        OperandType::Memory32               { .. }
+      ]
+      =>  return self.failOperandSize(),
+      _
+      =>  return self.fail ( "Instruction Must Take a XMM, YMM or ZMM Register and a XMM Register or Memory Argument".to_string() ),
+    }
+    Ok  ( Some  ( self.getLength() ) )
+  }
+
+  //  the EVEX forms of the comparisons write a mask register, the VEX forms a vector register
+  pub fn compileMaskCompareInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+    opcode:                             VectorOpcode,
+    wide:                               bool,
+    predicate:                          bool,
+  ) -> Result<Option<usize>, String>
+  {
+    if !matches! ( self.operands.first(), Some ( OperandType::MaskRegister ( _ ) ) )
+    {
+      return  if predicate
+              {
+                self.compileVectorCompareInstruction  ( architecture, addressSize,  opcode.prefix,  0         )
+              }
+              else
+              {
+                self.compileVectorIntegerInstruction  ( architecture, addressSize,  opcode.map,     opcode.opcode )
+              };
+    }
+    self.requireInstructionSet              ( architecture, InstructionSet::SkylakeX                          )?;
+    //  a mask register destination cannot be zeroed
+    let   options                       =   EnhancedVectorOptions
+                                            {
+                                              zeroing:    false,
+                                              ..EnhancedVectorOptions ( wide, 0,  false )
+                                            };
+    match ( self.operands.clone().as_slice(), predicate )
+    {
+      (
+        [
+          OperandType::MaskRegister         ( dstRegister ),
+          OperandType::MulitMediaRegister   ( srcRegister ),
+          src
+        ],
+        false
+      )
+      =>  {
+            self.encodeEnhancedVectorInstruction  ( addressSize,  opcode, options,  *dstRegister, *srcRegister, src )?;
+            Ok  ( Some  ( self.getLength() ) )
+          },
+      (
+        [
+          OperandType::MaskRegister         ( dstRegister ),
+          OperandType::MulitMediaRegister   ( srcRegister ),
+          src,
+          OperandType::Constant             ( predicate   )
+        ],
+        true
+      )
+      =>  {
+            self.encodeEnhancedVectorInstruction  ( addressSize,  opcode, options,  *dstRegister, *srcRegister, src )?;
+            if  *predicate >= 0
+            &&  *predicate <= 31
+            {
+              self.setImmediate             ( 1,            *predicate                                        );
+              Ok  ( Some  ( self.getLength() ) )
+            }
+            else
+            {
+              self.failOutOfBounds          ( 0,            31,           *predicate                          )
+            }
+          },
+      ( _,  false )
+      =>  self.fail ( "Instruction Must Take a Mask Register, a XMM, YMM or ZMM Register and a XMM, YMM or ZMM Register or Memory Argument".to_string() ),
+      ( _,  true  )
+      =>  self.fail ( "Instruction Must Take a Mask Register, a XMM, YMM or ZMM Register, a XMM, YMM or ZMM Register or Memory and an Immediate Argument".to_string() ),
+    }
+  }
+
+  //  the immediate is the truth table of the three operands
+  pub fn compileTernaryLogicInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+    wide:                               bool,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet              ( architecture, InstructionSet::SkylakeX                          )?;
+    match self.operands.clone().as_slice()
+    {
+      [
+        OperandType::MulitMediaRegister     ( dstRegister ),
+        OperandType::MulitMediaRegister     ( srcRegister ),
+        src,
+        OperandType::Constant               ( immediate   )
+      ]
+      =>  {
+            self.encodeEnhancedVectorInstruction  ( addressSize,  VectorOpcode  ( 0x66, 3,  0x25  ),  EnhancedVectorOptions ( wide, 0,  false ),  *dstRegister, *srcRegister, src )?;
+            if  *immediate >= 0
+            &&  *immediate <= 0xff
+            {
+              self.setImmediate             ( 1,            *immediate                                        );
+              Ok  ( Some  ( self.getLength() ) )
+            }
+            else
+            {
+              self.failOutOfBounds          ( 0,            0xff,         *immediate                          )
+            }
+          },
+      _
+      =>  self.fail ( "Instruction Must Take Two XMM, YMM or ZMM Registers, a XMM, YMM or ZMM Register or Memory and an Immediate Argument".to_string() ),
+    }
+  }
+
+  //  the instructions on mask registers are VEX encoded, those with three operands with VEX.L set
+  pub fn compileMaskInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+    opcode:                             u8,
+    binary:                             bool,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet              ( architecture, InstructionSet::SkylakeX                          )?;
+    match ( self.operands.clone().as_slice(), binary )
+    {
+      (
+        [
+          OperandType::MaskRegister         ( dstRegister ),
+          OperandType::MaskRegister         ( srcRegister ),
+          src @ OperandType::MaskRegister   ( _           )
+        ],
+        true
+      )
+      =>  self.encodeVectorOperands         ( addressSize,  VectorOpcode  ( 0x00, 1,  opcode  ),  true,   *dstRegister, *srcRegister, src )?,
+      (
+        [
+          OperandType::MaskRegister         ( dstRegister ),
+          src @ OperandType::MaskRegister   ( _           )
+        ],
+        false
+      )
+      =>  self.encodeVectorOperands         ( addressSize,  VectorOpcode  ( 0x00, 1,  opcode  ),  false,  *dstRegister, 0,            src )?,
+      ( _,  true  )
+      =>  return self.fail ( "Instruction Must Take Three Mask Registers".to_string() ),
+      ( _,  false )
+      =>  return self.fail ( "Instruction Must Take Two Mask Registers".to_string() ),
+    }
+    Ok  ( Some  ( self.getLength() ) )
+  }
+
+  //  mask registers are moved from and to other mask registers, words in memory and 32 bit registers
+  pub fn compileMaskMoveInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet              ( architecture, InstructionSet::SkylakeX                          )?;
+    match self.operands.clone().as_slice()
+    {
+      [
+        OperandType::MaskRegister           ( dstRegister ),
+        src @ OperandType::MaskRegister     ( _   )
+      ]
+      =>  self.encodeVectorOperands         ( addressSize,  VectorOpcode  ( 0x00, 1,  0x90  ),  false,  *dstRegister, 0,  src )?,
+      [
+        OperandType::MaskRegister           ( dstRegister ),
+        src @ OperandType::Memory16               { .. } |
+        src @ OperandType::Memory32               { .. }
+      ]
+      if self.size == 2
+      =>  self.encodeVectorOperands         ( addressSize,  VectorOpcode  ( 0x00, 1,  0x90  ),  false,  *dstRegister, 0,  src )?,
+      [
+        dst @ OperandType::Memory16               { .. } |
+        dst @ OperandType::Memory32               { .. },
+        OperandType::MaskRegister           ( srcRegister )
+      ]
+      if self.size == 2
+      =>  self.encodeVectorOperands         ( addressSize,  VectorOpcode  ( 0x00, 1,  0x91  ),  false,  *srcRegister, 0,  dst )?,
+      [
+        OperandType::MaskRegister           ( dstRegister ),
+        src @ OperandType::GeneralPurposeRegister { .. }
+      ]
+      if self.size == 4
+      =>  self.encodeVectorOperands         ( addressSize,  VectorOpcode  ( 0x00, 1,  0x92  ),  false,  *dstRegister, 0,  src )?,
+      [
+        OperandType::GeneralPurposeRegister { number, .. },
+        src @ OperandType::MaskRegister     ( _   )
+      ]
+      if self.size == 4
+      =>  self.encodeVectorOperands         ( addressSize,  VectorOpcode  ( 0x00, 1,  0x93  ),  false,  *number,      0,  src )?,
+      [
+        OperandType::MaskRegister           ( _   ),
+        OperandType::Memory16               { .. } |
+        OperandType::Memory32               { .. } |
+        OperandType::GeneralPurposeRegister { .. }
+      ] |
+      [
+        OperandType::Memory16               { .. } |
+        OperandType::Memory32               { .. } |
+        OperandType::GeneralPurposeRegister { .. },
+        OperandType::MaskRegister           ( _   )
+      ]
+      =>  return self.failOperandSize(),
+      _
+      =>  return self.fail ( "Instruction Must Take a Mask Register and a Mask Register, Memory or 32 Bit Register Argument".to_string() ),
+    }
+    Ok  ( Some  ( self.getLength() ) )
+  }
+}
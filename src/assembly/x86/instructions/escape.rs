@@ -0,0 +1,77 @@
+use super::
+{
+  Instruction,
+  InstructionType,
+  super::
+  {
+    X86,
+    operands::
+    {
+      Operand,
+      OperandType,
+    },
+  },
+};
+
+impl X86
+{
+  pub fn esc
+  (
+    mut self,
+    opcode:                             impl Operand,
+    operand:                            impl Operand,
+  ) -> Self
+  {
+    let ( opcodeThis,   _           )   =   opcode.this();
+    let ( operandThis,  operandSize )   =   operand.this();
+    self.instructions.push
+    (
+      Instruction
+      (
+        self.line,
+        self.features,
+        operandSize,
+        InstructionType::ESC,
+        vec!  ( opcodeThis, operandThis ),
+      )
+    );
+    self.line                           +=  1;
+    self
+  }
+}
+
+impl  Instruction
+{
+  pub fn compileEscapeInstruction
+  (
+    &mut self,
+    addressSize:                        usize,
+  ) -> Result<Option<usize>, String>
+  {
+    //  the upper three bits of the opcode select 0xd8 to 0xdf, the lower three go into the reg field
+    match self.operands.clone().as_slice()
+    {
+      [
+        OperandType::Constant               ( opcode  ),
+        operand @ OperandType::GeneralPurposeRegister { .. } |
+        operand @ OperandType::Memory16               { .. } |
+        operand @ OperandType::Memory32               { .. }
+      ]
+      =>  {
+            let opcode                  =   *opcode;
+            if ( 0 ..= 0x3f ).contains ( &opcode )
+            {
+              self.setOpcode                ( 0xd8  | ( opcode >> 3 ) as u8                                   );
+              self.encodeModRegRMdata       ( addressSize,  ( opcode & 7 ) as u8, operand                     )?;
+              Ok  ( Some  ( self.getLength() ) )
+            }
+            else
+            {
+              self.failOutOfBounds          ( 0,            0x3f,         opcode                              )
+            }
+          },
+      _
+      =>  self.fail ( "Instruction Must Take a Constant Opcode and a Register or Memory Argument".to_string() ),
+    }
+  }
+}
@@ -0,0 +1,284 @@
+use super::
+{
+  Instruction,
+  InstructionType,
+  VectorOpcode,
+  super::
+  {
+    AssemblyFeatures,
+    InstructionSet,
+    X86,
+    operands::
+    {
+      Operand,
+      OperandType,
+    },
+  },
+};
+
+use rand;
+
+//  XOP is an extension of AMD, which Intel processors never implemented
+const ExtendedOperationsInstructionSets:  &[InstructionSet]  =   &[ InstructionSet::Bulldozer ];
+
+macro_rules! theInstruction
+{
+  (
+    $theName:ident,
+    $theInstruction:expr,
+    dst,
+    src,
+    count
+  )
+  =>  {
+        pub fn $theName
+        (
+          mut self,
+          dst:                          impl Operand,
+          src:                          impl Operand,
+          count:                        impl Operand,
+        ) -> Self
+        {
+          let ( dstThis,    dstSize   ) =   dst.this();
+          let ( srcThis,    srcSize   ) =   src.this();
+          let ( countThis,  countSize ) =   count.this();
+          let size                      =   ( dstSize | srcSize | countSize ) as usize;
+          self.instructions.push
+          (
+            Instruction
+            (
+              self.line,
+              self.features,
+              size,
+              $theInstruction,
+              vec!  ( dstThis,  srcThis,  countThis ),
+            )
+          );
+          self.line                     +=  1;
+          self
+        }
+      };
+  (
+    $theName:ident,
+    $theInstruction:expr,
+    dst,
+    src1,
+    src2,
+    src3
+  )
+  =>  {
+        pub fn $theName
+        (
+          mut self,
+          dst:                          impl Operand,
+          src1:                         impl Operand,
+          src2:                         impl Operand,
+          src3:                         impl Operand,
+        ) -> Self
+        {
+          let ( dstThis,  dstSize  )    =   dst.this();
+          let ( src1This, src1Size )    =   src1.this();
+          let ( src2This, src2Size )    =   src2.this();
+          let ( src3This, src3Size )    =   src3.this();
+          let size                      =   ( dstSize | src1Size | src2Size | src3Size ) as usize;
+          self.instructions.push
+          (
+            Instruction
+            (
+              self.line,
+              self.features,
+              size,
+              $theInstruction,
+              vec!  ( dstThis,  src1This, src2This, src3This  ),
+            )
+          );
+          self.line                     +=  1;
+          self
+        }
+      };
+}
+
+impl X86
+{
+  theInstruction! ( vpcmov,     InstructionType::VPCMOV,      dst,  src1, src2, src3  );
+  theInstruction! ( vpmacsdd,   InstructionType::VPMACSDD,    dst,  src1, src2, src3  );
+  theInstruction! ( vpmacsdqh,  InstructionType::VPMACSDQH,   dst,  src1, src2, src3  );
+  theInstruction! ( vpmacsdql,  InstructionType::VPMACSDQL,   dst,  src1, src2, src3  );
+  theInstruction! ( vpmacssdd,  InstructionType::VPMACSSDD,   dst,  src1, src2, src3  );
+  theInstruction! ( vpmacssdqh, InstructionType::VPMACSSDQH,  dst,  src1, src2, src3  );
+  theInstruction! ( vpmacssdql, InstructionType::VPMACSSDQL,  dst,  src1, src2, src3  );
+  theInstruction! ( vpmacsswd,  InstructionType::VPMACSSWD,   dst,  src1, src2, src3  );
+  theInstruction! ( vpmacssww,  InstructionType::VPMACSSWW,   dst,  src1, src2, src3  );
+  theInstruction! ( vpmacswd,   InstructionType::VPMACSWD,    dst,  src1, src2, src3  );
+  theInstruction! ( vpmacsww,   InstructionType::VPMACSWW,    dst,  src1, src2, src3  );
+  theInstruction! ( vpperm,     InstructionType::VPPERM,      dst,  src1, src2, src3  );
+  theInstruction! ( vprotb,     InstructionType::VPROTB,      dst,  src,  count       );
+  theInstruction! ( vprotd,     InstructionType::VPROTD,      dst,  src,  count       );
+  theInstruction! ( vprotq,     InstructionType::VPROTQ,      dst,  src,  count       );
+  theInstruction! ( vprotw,     InstructionType::VPROTW,      dst,  src,  count       );
+  theInstruction! ( vpshab,     InstructionType::VPSHAB,      dst,  src,  count       );
+  theInstruction! ( vpshad,     InstructionType::VPSHAD,      dst,  src,  count       );
+  theInstruction! ( vpshaq,     InstructionType::VPSHAQ,      dst,  src,  count       );
+  theInstruction! ( vpshaw,     InstructionType::VPSHAW,      dst,  src,  count       );
+}
+
+impl  Instruction
+{
+  //  XOP.W selects, whether the count or the source is encoded in r/m, so either of them might be in memory.
+  //  only the rotations have a form with an immediate count, which is encoded in map 8 instead of map 9.
+  pub fn compileExtendedShiftInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+    opcode:                             u8,
+    immediateOpcode:                    Option<u8>,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSetOf            ( architecture, ExtendedOperationsInstructionSets                 )?;
+    if self.size & 32 != 0
+    {
+      return self.failOperandSize();
+    }
+    match ( self.operands.clone().as_slice(), immediateOpcode )
+    {
+      (
+        [
+          OperandType::MulitMediaRegister   ( dstRegister ),
+          src,
+          OperandType::Constant             ( count       )
+        ],
+        Some  ( immediateOpcode )
+      )
+      =>  {
+            self.encodeVectorInstruction    ( addressSize,  VectorOpcode  ( 0x00, 8,  immediateOpcode  ),  0,  *dstRegister, 0,            src )?;
+            if  *count >= -0x80
+            &&  *count <=  0xff
+            {
+              self.setImmediate             ( 1,            *count                                            );
+              Ok  ( Some  ( self.getLength() ) )
+            }
+            else
+            {
+              self.failOutOfBounds          ( -0x80,        0xff,         *count                              )
+            }
+          },
+      (
+        [
+          OperandType::MulitMediaRegister   ( dstRegister ),
+          OperandType::MulitMediaRegister   ( srcRegister ),
+          count @ OperandType::MulitMediaRegister   ( _   )
+        ],
+        _
+      )
+      if  self.features.hazFeature ( AssemblyFeatures::RandomOpcode )
+      &&  rand::random()
+      =>  {
+            self.encodeVectorInstruction    ( addressSize,  VectorOpcode  ( 0x00, 9,  opcode  ),  0,  *dstRegister, *srcRegister, count )?;
+            self.setVectorWidth             ( true                                                            );
+            Ok  ( Some  ( self.getLength() ) )
+          },
+      (
+        [
+          OperandType::MulitMediaRegister   ( dstRegister ),
+          src @ OperandType::MulitMediaRegister     ( _   ) |
+          src @ OperandType::Memory16               { .. } |
+          src @ OperandType::Memory32               { .. },
+          OperandType::MulitMediaRegister   ( countRegister )
+        ],
+        _
+      )
+      =>  {
+            self.encodeVectorInstruction    ( addressSize,  VectorOpcode  ( 0x00, 9,  opcode  ),  0,  *dstRegister, *countRegister, src )?;
+            Ok  ( Some  ( self.getLength() ) )
+          },
+      (
+        [
+          OperandType::MulitMediaRegister   ( dstRegister ),
+          OperandType::MulitMediaRegister   ( srcRegister ),
+          count @ OperandType::Memory16             { .. } |
+          count @ OperandType::Memory32             { .. }
+        ],
+        _
+      )
+      =>  {
+            self.encodeVectorInstruction    ( addressSize,  VectorOpcode  ( 0x00, 9,  opcode  ),  0,  *dstRegister, *srcRegister, count )?;
+            self.setVectorWidth             ( true                                                            );
+            Ok  ( Some  ( self.getLength() ) )
+          },
+      _
+      =>  self.fail ( "Instruction Must Take a XMM Register, a XMM Register or Memory and a XMM Register, Memory or Immediate Argument".to_string() ),
+    }
+  }
+
+  //  the fourth operand is encoded in the upper four bits of an immediate.
+  //  with XOP.W, the third and fourth operand are swapped, so either of them might be in memory, if the instruction allows it.
+  pub fn compileExtendedFourOperandInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+    opcode:                             u8,
+    long:                               bool,
+    swappable:                          bool,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSetOf            ( architecture, ExtendedOperationsInstructionSets                 )?;
+    if  !long
+    &&  self.size & 32 != 0
+    {
+      return self.failOperandSize();
+    }
+    let   immediateRegister             =   match self.operands.clone().as_slice()
+                                            {
+                                              [
+                                                OperandType::MulitMediaRegister     ( dstRegister ),
+                                                OperandType::MulitMediaRegister     ( srcRegister ),
+                                                OperandType::MulitMediaRegister     ( thirdRegister ),
+                                                fourth @ OperandType::MulitMediaRegister  ( _   )
+                                              ]
+                                              if  swappable
+                                              &&  self.features.hazFeature ( AssemblyFeatures::RandomOpcode )
+                                              &&  rand::random()
+                                              =>  {
+                                                    self.encodeVectorInstruction  ( addressSize,  VectorOpcode  ( 0x00, 8,  opcode  ),  0,  *dstRegister, *srcRegister, fourth )?;
+                                                    self.setVectorWidth           ( true                                              );
+                                                    *thirdRegister
+                                                  },
+                                              [
+                                                OperandType::MulitMediaRegister     ( dstRegister ),
+                                                OperandType::MulitMediaRegister     ( srcRegister ),
+                                                third @ OperandType::MulitMediaRegister   ( _   ) |
+                                                third @ OperandType::Memory16             { .. } |
+                                                third @ OperandType::Memory32             { .. },
+                                                OperandType::MulitMediaRegister     ( fourthRegister )
+                                              ]
+                                              =>  {
+                                                    self.encodeVectorInstruction  ( addressSize,  VectorOpcode  ( 0x00, 8,  opcode  ),  0,  *dstRegister, *srcRegister, third )?;
+                                                    *fourthRegister
+                                                  },
+                                              [
+                                                OperandType::MulitMediaRegister     ( dstRegister ),
+                                                OperandType::MulitMediaRegister     ( srcRegister ),
+                                                OperandType::MulitMediaRegister     ( thirdRegister ),
+                                                fourth @ OperandType::Memory16            { .. } |
+                                                fourth @ OperandType::Memory32            { .. }
+                                              ]
+                                              if swappable
+                                              =>  {
+                                                    self.encodeVectorInstruction  ( addressSize,  VectorOpcode  ( 0x00, 8,  opcode  ),  0,  *dstRegister, *srcRegister, fourth )?;
+                                                    self.setVectorWidth           ( true                                              );
+                                                    *thirdRegister
+                                                  },
+                                              _
+                                              =>  return self.fail ( "Instruction Must Take XMM or YMM Registers and one XMM or YMM Register or Memory Argument".to_string() ),
+                                            };
+    if  immediateRegister > 7
+    &&  addressSize       != 64
+    {
+      return self.fail ( "Extended Registers and 64 Bit Operands Require 64 Bit Mode".to_string() );
+    }
+    self.setImmediate                       ( 1,            ( immediateRegister << 4 ) as i128                );
+    Ok  ( Some  ( self.getLength() ) )
+  }
+}
@@ -0,0 +1,392 @@
+use super::
+{
+  Instruction,
+  InstructionType,
+  super::
+  {
+    InstructionSet,
+    X86,
+    operands::
+    {
+      Operand,
+      OperandType,
+    },
+  },
+};
+
+macro_rules! theInstruction
+{
+  (
+    $theName:ident,
+    $theInstruction:expr
+  )
+  =>  {
+        pub fn $theName
+        (
+          mut self,
+          operand:                      impl Operand,
+        ) -> Self
+        {
+          let ( operandThis, operandSize )
+                                        =   operand.this();
+          self.instructions.push
+          (
+            Instruction
+            (
+              self.line,
+              self.features,
+              operandSize,
+              $theInstruction,
+              vec!  ( operandThis ),
+            )
+          );
+          self.line                     +=  1;
+          self
+        }
+      };
+  (
+    $theName:ident,
+    $theInstruction:expr,
+    dst,
+    src
+  )
+  =>  {
+        pub fn $theName
+        (
+          mut self,
+          dst:                          impl Operand,
+          src:                          impl Operand,
+        ) -> Self
+        {
+          let ( dstThis, dstSize )      =   dst.this();
+          let ( srcThis, srcSize )      =   src.this();
+          let size                      =   ( dstSize | srcSize ) as usize;
+          self.instructions.push
+          (
+            Instruction
+            (
+              self.line,
+              self.features,
+              size,
+              $theInstruction,
+              vec!  ( dstThis,  srcThis ),
+            )
+          );
+          self.line                     +=  1;
+          self
+        }
+      };
+}
+
+impl X86
+{
+  theInstruction! ( fadd,     InstructionType::FADD,     dst,  src );
+  theInstruction! ( faddp,    InstructionType::FADDP,    dst,  src );
+  theInstruction! ( fcmovb,   InstructionType::FCMOVB,   dst,  src );
+  theInstruction! ( fcmovbe,  InstructionType::FCMOVBE,  dst,  src );
+  theInstruction! ( fcmove,   InstructionType::FCMOVE,   dst,  src );
+  theInstruction! ( fcmovnb,  InstructionType::FCMOVNB,  dst,  src );
+  theInstruction! ( fcmovnbe, InstructionType::FCMOVNBE, dst,  src );
+  theInstruction! ( fcmovne,  InstructionType::FCMOVNE,  dst,  src );
+  theInstruction! ( fcmovnu,  InstructionType::FCMOVNU,  dst,  src );
+  theInstruction! ( fcmovu,   InstructionType::FCMOVU,   dst,  src );
+  theInstruction! ( fcom,     InstructionType::FCOM                );
+  theInstruction! ( fcomi,    InstructionType::FCOMI,    dst,  src );
+  theInstruction! ( fcomip,   InstructionType::FCOMIP,   dst,  src );
+  theInstruction! ( fcomp,    InstructionType::FCOMP               );
+  theInstruction! ( fdiv,     InstructionType::FDIV,     dst,  src );
+  theInstruction! ( fdivp,    InstructionType::FDIVP,    dst,  src );
+  theInstruction! ( fdivr,    InstructionType::FDIVR,    dst,  src );
+  theInstruction! ( fdivrp,   InstructionType::FDIVRP,   dst,  src );
+  theInstruction! ( fld,      InstructionType::FLD                 );
+  theInstruction! ( fmul,     InstructionType::FMUL,     dst,  src );
+  theInstruction! ( fmulp,    InstructionType::FMULP,    dst,  src );
+  theInstruction! ( fst,      InstructionType::FST                 );
+  theInstruction! ( fstp,     InstructionType::FSTP                );
+  theInstruction! ( fsub,     InstructionType::FSUB,     dst,  src );
+  theInstruction! ( fsubp,    InstructionType::FSUBP,    dst,  src );
+  theInstruction! ( fsubr,    InstructionType::FSUBR,    dst,  src );
+  theInstruction! ( fsubrp,   InstructionType::FSUBRP,   dst,  src );
+  theInstruction! ( fucomi,   InstructionType::FUCOMI,   dst,  src );
+  theInstruction! ( fucomip,  InstructionType::FUCOMIP,  dst,  src );
+
+  pub fn fwait
+  (
+    self,
+  ) -> Self
+  {
+    self.wait ()
+  }
+
+  //  finit is just fninit after waiting for pending exceptions
+  pub fn finit
+  (
+    self,
+  ) -> Self
+  {
+    self.wait ().fninit ()
+  }
+}
+
+impl  Instruction
+{
+  //  the 8086 does not synchronise with the 8087 by itself, later cpus wait automatically
+  fn encodeWait
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+  )
+  {
+    self.setWait                        ( architecture == InstructionSet::i8086                               );
+  }
+
+  fn encodeFloatingPointRegister
+  (
+    &mut self,
+    opcode:                             u8,
+    regField:                           u8,
+    register:                           u8,
+  ) -> Result<(), String>
+  {
+    if register < 8
+    {
+      self.setOpcode                        ( opcode                                                          );
+      self.setModRegRM                      ( 0xc0  | regField  <<  3 | register                              );
+      Ok  ( () )
+    }
+    else
+    {
+      self.failOutOfBounds                  ( 0,            7,            register as i128                    )
+    }
+  }
+
+  pub fn compileFloatingPointLoadInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+  ) -> Result<Option<usize>, String>
+  {
+    self.encodeWait                         ( architecture                                                    );
+    match self.operands.clone().as_slice()
+    {
+      [ OperandType::FloatingPointRegister  ( register  ) ]
+      =>  self.encodeFloatingPointRegister  ( 0xd9,         0,            *register                           )?,
+      [ operand @ OperandType::Memory16     { .. } |
+        operand @ OperandType::Memory32     { .. }        ]
+      =>  {
+            let ( opcode, regField )    =   match self.size
+                                            {
+                                              4   =>  ( 0xd9, 0 ),
+                                              8   =>  ( 0xdd, 0 ),
+                                              10  =>  ( 0xdb, 5 ),
+                                              _   =>  return self.failOperandSize(),
+                                            };
+            self.setOpcode                  ( opcode                                                          );
+            self.encodeModRegRMdata         ( addressSize,  regField,     operand                             )?;
+          },
+      _
+      =>  self.fail ( "Instruction Must Take Exactly One Floating Point Register or Memory Argument".to_string() )?,
+    }
+    Ok  ( Some  ( self.getLength() ) )
+  }
+
+  pub fn compileFloatingPointStoreInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+    pop:                                bool,
+  ) -> Result<Option<usize>, String>
+  {
+    self.encodeWait                         ( architecture                                                    );
+    let regField                        =   if pop { 3 } else { 2 };
+    match self.operands.clone().as_slice()
+    {
+      [ OperandType::FloatingPointRegister  ( register  ) ]
+      =>  self.encodeFloatingPointRegister  ( 0xdd,         regField,     *register                           )?,
+      [ operand @ OperandType::Memory16     { .. } |
+        operand @ OperandType::Memory32     { .. }        ]
+      =>  {
+            //  there is only a popping store of extended precision
+            let ( opcode, regField )    =   match self.size
+                                            {
+                                              4             =>  ( 0xd9, regField  ),
+                                              8             =>  ( 0xdd, regField  ),
+                                              10  if pop    =>  ( 0xdb, 7         ),
+                                              _             =>  return self.failOperandSize(),
+                                            };
+            self.setOpcode                  ( opcode                                                          );
+            self.encodeModRegRMdata         ( addressSize,  regField,     operand                             )?;
+          },
+      _
+      =>  self.fail ( "Instruction Must Take Exactly One Floating Point Register or Memory Argument".to_string() )?,
+    }
+    Ok  ( Some  ( self.getLength() ) )
+  }
+
+  pub fn compileFloatingPointArithmeticInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+    regField:                           u8,
+  ) -> Result<Option<usize>, String>
+  {
+    self.encodeWait                         ( architecture                                                    );
+    match self.operands.clone().as_slice()
+    {
+      [
+        OperandType::FloatingPointRegister  ( 0         ),
+        OperandType::FloatingPointRegister  ( register  )
+      ]
+      =>  self.encodeFloatingPointRegister  ( 0xd8,         regField,     *register                           )?,
+      //  with st(i) as destination, the reversed and non-reversed forms swap their reg fields
+      [
+        OperandType::FloatingPointRegister  ( register  ),
+        OperandType::FloatingPointRegister  ( 0         )
+      ]
+      =>  self.encodeFloatingPointRegister
+          (
+            0xdc,
+            if regField >= 4 { regField ^ 1 } else { regField },
+            *register,
+          )?,
+      [
+        OperandType::FloatingPointRegister  ( 0         ),
+        operand @ OperandType::Memory16     { .. } |
+        operand @ OperandType::Memory32     { .. }
+      ]
+      =>  {
+            let opcode                  =   match self.size
+                                            {
+                                              4   =>  0xd8,
+                                              8   =>  0xdc,
+                                              _   =>  return self.failOperandSize(),
+                                            };
+            self.setOpcode                  ( opcode                                                          );
+            self.encodeModRegRMdata         ( addressSize,  regField,     operand                             )?;
+          },
+      _
+      =>  self.fail ( "Instruction Must Take st0 and a Floating Point Register or Memory Argument".to_string() )?,
+    }
+    Ok  ( Some  ( self.getLength() ) )
+  }
+
+  pub fn compileFloatingPointPopInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    regField:                           u8,
+  ) -> Result<Option<usize>, String>
+  {
+    self.encodeWait                         ( architecture                                                    );
+    match self.operands.as_slice()
+    {
+      [
+        OperandType::FloatingPointRegister  ( register  ),
+        OperandType::FloatingPointRegister  ( 0         )
+      ]
+      =>  {
+            let register                =   *register;
+            self.encodeFloatingPointRegister
+            (
+              0xde,
+              if regField >= 4 { regField ^ 1 } else { regField },
+              register,
+            )?
+          },
+      _
+      =>  self.fail ( "Instruction Must Take a Floating Point Register and st0".to_string() )?,
+    }
+    Ok  ( Some  ( self.getLength() ) )
+  }
+
+  pub fn compileFloatingPointCompareInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+    regField:                           u8,
+  ) -> Result<Option<usize>, String>
+  {
+    self.encodeWait                         ( architecture                                                    );
+    match self.operands.clone().as_slice()
+    {
+      [ OperandType::FloatingPointRegister  ( register  ) ]
+      =>  self.encodeFloatingPointRegister  ( 0xd8,         regField,     *register                           )?,
+      [ operand @ OperandType::Memory16     { .. } |
+        operand @ OperandType::Memory32     { .. }        ]
+      =>  {
+            let opcode                  =   match self.size
+                                            {
+                                              4   =>  0xd8,
+                                              8   =>  0xdc,
+                                              _   =>  return self.failOperandSize(),
+                                            };
+            self.setOpcode                  ( opcode                                                          );
+            self.encodeModRegRMdata         ( addressSize,  regField,     operand                             )?;
+          },
+      _
+      =>  self.fail ( "Instruction Must Take Exactly One Floating Point Register or Memory Argument".to_string() )?,
+    }
+    Ok  ( Some  ( self.getLength() ) )
+  }
+
+  pub fn compileFloatingPointControlInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    waiting:                            bool,
+    opcode:                             u8,
+    modRegRM:                           u8,
+  ) -> Result<Option<usize>, String>
+  {
+    if self.operands.is_empty()
+    {
+      if waiting
+      {
+        self.encodeWait                     ( architecture                                                    );
+      }
+      self.setOpcode                        ( opcode                                                          );
+      self.setModRegRM                      ( modRegRM                                                        );
+      Ok  ( Some  ( self.getLength() ) )
+    }
+    else
+    {
+      self.fail
+      (
+        format!
+        (
+          "Instruction Takes No Arguments, got {}",
+          self.operands.len(),
+        )
+      )
+    }
+  }
+
+  pub fn compileFloatingPointRegisterInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    opcode:                             u8,
+    regField:                           u8,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet              ( architecture, InstructionSet::Pentium2                          )?;
+    match self.operands.as_slice()
+    {
+      [
+        OperandType::FloatingPointRegister  ( 0         ),
+        OperandType::FloatingPointRegister  ( register  )
+      ]
+      =>  {
+            let register                =   *register;
+            self.encodeFloatingPointRegister  ( opcode,       regField,     register                            )?
+          },
+      _
+      =>  self.fail ( "Instruction Must Take st0 and a Floating Point Register".to_string() )?,
+    }
+    Ok  ( Some  ( self.getLength() ) )
+  }
+}
@@ -0,0 +1,69 @@
+use super::
+{
+  Instruction,
+  InstructionType,
+  super::
+  {
+    X86,
+    operands::
+    {
+      Operand,
+      OperandType,
+    },
+  },
+};
+
+impl X86
+{
+  pub fn int
+  (
+    mut self,
+    number:                             impl Operand,
+  ) -> Self
+  {
+    let ( numberThis, _ )               =   number.this();
+    self.instructions.push
+    (
+      Instruction
+      (
+        self.line,
+        self.features,
+        0,
+        InstructionType::INT,
+        vec!  ( numberThis  ),
+      )
+    );
+    self.line                           +=  1;
+    self
+  }
+}
+
+impl  Instruction
+{
+  pub fn compileInterruptInstruction
+  (
+    &mut self,
+  ) -> Result<Option<usize>, String>
+  {
+    //  int 3 is always encoded as 0xcd 0x03, use int3() for the one byte breakpoint 0xcc
+    match self.operands.as_slice()
+    {
+      [ OperandType::Constant ( number  ) ]
+      =>  {
+            let number                  =   *number;
+            if ( 0 ..= 0xff ).contains ( &number )
+            {
+              self.setOpcode                ( 0xcd                                                            );
+              self.setImmediate             ( 1,            number                                            );
+              Ok  ( Some  ( self.getLength() ) )
+            }
+            else
+            {
+              self.failOutOfBounds          ( 0,            0xff,         number                              )
+            }
+          },
+      _
+      =>  self.fail ( "Instruction Must Take Exactly One Constant Argument".to_string() ),
+    }
+  }
+}
@@ -27,14 +27,14 @@ macro_rules! theInstruction
           label:                        impl Operand,
         ) -> Self
         {
-          let ( thisLabel,  _ )         =   label.this();
+          let ( thisLabel,  size )      =   label.this();
           self.instructions.push
           (
             Instruction
             (
               self.line,
               self.features,
-              0,
+              size,
               $theInstruction,
               vec!  ( thisLabel ),
             )
@@ -42,17 +42,47 @@ macro_rules! theInstruction
           self.line                     +=  1;
           self
         }
-      }
+      };
+  (
+    $theName:ident,
+    $theInstruction:expr,
+    imm16
+  )
+  =>  {
+        pub fn $theName
+        (
+          mut self,
+          imm16:                        impl Operand,
+        ) -> Self
+        {
+          let ( thisImm16,  size )      =   imm16.this();
+          self.instructions.push
+          (
+            Instruction
+            (
+              self.line,
+              self.features,
+              size,
+              $theInstruction,
+              vec!  ( thisImm16 ),
+            )
+          );
+          self.line                     +=  1;
+          self
+        }
+      };
 }
 
 impl  X86
 {
+  theInstruction! ( call,   InstructionType::CALL   );
   theInstruction! ( jb,     InstructionType::JB     );
   theInstruction! ( jbe,    InstructionType::JBE    );
   theInstruction! ( jcxz,   InstructionType::JCXZ   );
   theInstruction! ( je,     InstructionType::JE     );
   theInstruction! ( jl,     InstructionType::JL     );
   theInstruction! ( jle,    InstructionType::JLE    );
+  theInstruction! ( jmp,    InstructionType::JMP    );
   theInstruction! ( jnb,    InstructionType::JNB    );
   theInstruction! ( jnbe,   InstructionType::JNBE   );
   theInstruction! ( jne,    InstructionType::JNE    );
@@ -68,6 +98,8 @@ impl  X86
   theInstruction! ( looop,  InstructionType::LOOP   );
   theInstruction! ( loopz,  InstructionType::LOOPZ  );
   theInstruction! ( loopnz, InstructionType::LOOPNZ );
+  theInstruction! ( retfImm, InstructionType::RETF,  imm16 );
+  theInstruction! ( retnImm, InstructionType::RETN,  imm16 );
 }
 
 impl  Instruction
@@ -77,6 +109,7 @@ impl  Instruction
     &mut self,
     architecture:                       InstructionSet,
     operandSize:                        usize,
+    branchExpansion:                    bool,
     opcode:                             u8,
   ) -> Result<Option<usize>, String>
   {
@@ -84,19 +117,65 @@ impl  Instruction
     {
       match &self.operands [ 0 ]
       {
-        OperandType::Displacement ( mut displacement  )
+        OperandType::Displacement ( displacement  )
         =>  {
-              displacement              -=  2;
-              if  displacement  >= -0x80
-              &&  displacement  <=  0x7f
+              let displacement          =   *displacement;
+              let nearSize              =   if operandSize == 16 { 2 } else { 4 };
+              let nearMask              =   ( 1 << ( 8 * nearSize ) ) - 1;
+              let ( lowerBound, upperBound )
+                                        =   if nearSize == 2 { ( -0x8000, 0x7fff ) } else { ( -0x80000000, 0x7fffffff ) };
+              if  displacement - 2  >= -0x80
+              &&  displacement - 2  <=  0x7f
               {
                 self.setOpcode    ( opcode,               );
-                self.setImmediate ( 1,      displacement, );
+                self.setImmediate ( 1,      displacement - 2, );
                 Ok  ( Some  ( 2 ) )
               }
+              else  if  architecture  >=  InstructionSet::i386
+                    &&  opcode        <   0x80
+                    &&  displacement - 2 - nearSize as i128 >= lowerBound
+                    &&  displacement - 2 - nearSize as i128 <= upperBound
+              {
+                //  0x0f 0x80+cc is the near form of 0x70+cc
+                self.setTwoByteOpcode ( true                                                              );
+                self.setOpcode        ( opcode  & 0x0f  | 0x80                                            );
+                self.setImmediate     ( nearSize, displacement - 2 - nearSize as i128                     );
+                Ok  ( Some  ( self.getLength() ) )
+              }
+              else  if  branchExpansion
+                    &&  opcode        <   0x80
+                    &&  displacement - 3 - nearSize as i128 >= lowerBound
+                    &&  displacement - 3 - nearSize as i128 <= upperBound
+              {
+                //  jcc far → jncc skip; jmp near far; skip:
+                //  the inverted condition and the near jump are packed into the immediate value
+                let displacement        =   displacement - 3 - nearSize as i128;
+                self.setOpcode        ( opcode  ^ 1                                                       );
+                self.setImmediate
+                (
+                  2 + nearSize,
+                  ( 1 + nearSize as i128 ) | 0xe9 << 8 | ( displacement & nearMask ) << 16,
+                );
+                Ok  ( Some  ( self.getLength() ) )
+              }
+              else  if  branchExpansion
+                    &&  displacement - 5 - nearSize as i128 >= lowerBound
+                    &&  displacement - 5 - nearSize as i128 <= upperBound
+              {
+                //  jcxz and loops cannot be inverted
+                //  loop far → loop taken; jmp short skip; taken: jmp near far; skip:
+                let displacement        =   displacement - 5 - nearSize as i128;
+                self.setOpcode        ( opcode                                                            );
+                self.setImmediate
+                (
+                  4 + nearSize,
+                  2 | 0xeb << 8 | ( 1 + nearSize as i128 ) << 16 | 0xe9 << 24 | ( displacement & nearMask ) << 32,
+                );
+                Ok  ( Some  ( self.getLength() ) )
+              }
               else
               {
-                Err
+                self.fail
                 (
                   format!
                   (
@@ -116,9 +195,8 @@ impl  Instruction
               (
                 format!
                 (
-                  "Invalid Combination of Arguments ›{}‹, ›{}‹",
+                  "Invalid Argument ›{}‹",
                   self.operands [ 0 ].to_string  ( self.size  ),
-                  self.operands [ 1 ].to_string  ( self.size  ),
                 )
               )
             },
@@ -136,4 +214,174 @@ impl  Instruction
       )
     }
   }
-}
\ No newline at end of file
+
+  pub fn compileBranchInstruction
+  (
+    &mut self,
+    operandSize:                        usize,
+    addressSize:                        usize,
+    shortOpcode:                        Option<u8>,
+    nearOpcode:                         u8,
+    farOpcode:                          u8,
+    regField:                           u8,
+  ) -> Result<Option<usize>, String>
+  {
+    if self.operands.len() == 1
+    {
+      let operand                       =   self.operands [ 0 ].clone();
+      //  there are no 64 bit displacements
+      let nearSize                      =   if operandSize == 16 { 2 } else { 4 };
+      //  in 64 bit mode, indirect near branches always take 64 bit pointers
+      let pointerSize                   =   if operandSize == 64 { 8 } else { nearSize };
+      let ( lowerBound, upperBound )    =   if nearSize == 2 { ( -0x8000, 0x7fff ) } else { ( -0x80000000, 0x7fffffff ) };
+      match &operand
+      {
+        OperandType::Displacement ( displacement  )
+        =>  match shortOpcode
+            {
+              //  the displacement is relative to the end of the instruction
+              Some  ( opcode  )
+              if  displacement - 2  >= -0x80
+              &&  displacement - 2  <=  0x7f
+              =>  {
+                    self.setOpcode          ( opcode                                                          );
+                    self.setImmediate       ( 1,            displacement - 2                                  );
+                  },
+              _
+              =>  {
+                    let displacement    =   displacement - 1 - nearSize as i128;
+                    if  displacement >= lowerBound
+                    &&  displacement <= upperBound
+                    {
+                      self.setOpcode        ( nearOpcode                                                      );
+                      self.setImmediate     ( nearSize,     displacement                                      );
+                    }
+                    else
+                    {
+                      self.fail
+                      (
+                        format!
+                        (
+                          "Destination of Jump to far away: {}",
+                          displacement,
+                        )
+                      )?;
+                    }
+                  },
+            },
+        OperandType::Reference    ( _             )
+        =>  {
+              //  assume the shortest encoding until the destination is known
+              if let Some ( opcode  ) = shortOpcode
+              {
+                self.setOpcode              ( opcode                                                          );
+                self.setImmediateLength     ( 1                                                               );
+              }
+              else
+              {
+                self.setOpcode              ( nearOpcode                                                      );
+                self.setImmediateLength     ( nearSize                                                        );
+              }
+            },
+        OperandType::Intersegment { .. }
+        if  operandSize == 64
+        =>  self.fail ( "Direct Far Branches are not Available in 64 Bit Mode".to_string() )?,
+        OperandType::Intersegment { offset, segment }
+        =>  {
+              if  *offset   <   0
+              ||  *offset   >=  1 << ( 8 * nearSize )
+              {
+                self.failOutOfBounds        ( 0,            ( 1 << ( 8 * nearSize ) ) - 1,  *offset           )?;
+              }
+              if  *segment  <   0
+              ||  *segment  >   0xffff
+              {
+                self.failOutOfBounds        ( 0,            0xffff,       *segment                            )?;
+              }
+              self.setOpcode                ( farOpcode                                                       );
+              self.setImmediate             ( nearSize + 2, *offset | *segment << ( 8 * nearSize )            );
+            },
+        OperandType::GeneralPurposeRegister { .. }
+        if self.size == pointerSize
+        =>  {
+              self.setOpcode                ( 0xff                                                            );
+              self.encodeModRegRMdata       ( addressSize,  regField,     &operand                            )?;
+            },
+        //  a memory operand with the size of a near pointer plus a segment is a far pointer
+        OperandType::Memory16               { .. } | OperandType::Memory32 { .. }
+        if  self.size == pointerSize
+        ||  self.size == nearSize + 2
+        =>  {
+              self.setOpcode                ( 0xff                                                            );
+              self.encodeModRegRMdata
+              (
+                addressSize,
+                if self.size == pointerSize { regField } else { regField + 1 },
+                &operand,
+              )?;
+            },
+        OperandType::GeneralPurposeRegister { .. } |
+        OperandType::Memory16               { .. } | OperandType::Memory32 { .. }
+        =>  self.failOperandSize()?,
+        _
+        =>  self.fail
+            (
+              format!
+              (
+                "Invalid Argument ›{}‹",
+                operand.to_string  ( self.size  ),
+              )
+            )?,
+      }
+      Ok  ( Some  ( self.getLength() ) )
+    }
+    else
+    {
+      self.fail
+      (
+        format!
+        (
+          "Instruction Must Take Exactly One Argument, got {}",
+          self.operands.len(),
+        )
+      )
+    }
+  }
+
+  pub fn compileReturnInstruction
+  (
+    &mut self,
+    opcode:                             u8,
+  ) -> Result<Option<usize>, String>
+  {
+    match self.operands.as_slice()
+    {
+      []
+      =>  self.setOpcode                    ( opcode                                                          ),
+      //  the opcode without the stack adjustment is the successor of the one with it
+      [ OperandType::Constant ( immediate ) ]
+      =>  {
+            let immediate               =   *immediate;
+            if ( 0 ..= 0xffff ).contains ( &immediate )
+            {
+              self.setOpcode                ( opcode - 1                                                      );
+              self.setImmediate             ( 2,            immediate                                         );
+            }
+            else
+            {
+              self.failOutOfBounds          ( 0,            0xffff,       immediate                           )?;
+            }
+          },
+      _
+      =>  self.fail
+          (
+            format!
+            (
+              "Instruction Takes At Most One Constant Argument, got {}",
+              self.operands.len(),
+            )
+          )?,
+    }
+    Ok  ( Some  ( self.getLength() ) )
+  }
+}
@@ -0,0 +1,138 @@
+use super::
+{
+  Instruction,
+  InstructionType,
+  super::
+  {
+    InstructionSet,
+    X86,
+    operands::
+    {
+      Operand,
+      OperandType,
+    },
+  },
+};
+
+macro_rules! theInstruction
+{
+  (
+    $theName:ident,
+    $theInstruction:expr
+  )
+  =>  {
+        pub fn $theName
+        (
+          mut self,
+          dst:                          impl Operand,
+          src:                          impl Operand,
+        ) -> Self
+        {
+          //  only the address of the source is used, therefore its size does not matter
+          //  and the memory expression is solved right now, to not contribute its size later
+          let ( dstThis, dstSize )      =   dst.this();
+          let ( mut srcThis, _   )      =   src.this();
+          if let OperandType::Expression ( expression ) = &srcThis
+          {
+            if let Ok ( ( Some ( _ ), solved ) ) = expression.solve()
+            {
+              srcThis                   =   solved;
+            }
+          }
+          self.instructions.push
+          (
+            Instruction
+            (
+              self.line,
+              self.features,
+              dstSize,
+              $theInstruction,
+              vec!  ( dstThis,  srcThis ),
+            )
+          );
+          self.line                     +=  1;
+          self
+        }
+      }
+}
+
+impl X86
+{
+  theInstruction! ( bound,  InstructionType::BOUND  );
+  theInstruction! ( lds,    InstructionType::LDS    );
+  theInstruction! ( lea,    InstructionType::LEA    );
+  theInstruction! ( les,    InstructionType::LES    );
+}
+
+impl  Instruction
+{
+  pub fn compileLoadAddressInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    operandSize:                        usize,
+    addressSize:                        usize,
+    minimum:                            InstructionSet,
+    opcode:                             u8,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet              ( architecture, minimum                                           )?;
+    if self.operands.len() == 2
+    {
+      let dst                           =   self.operands [ 0 ].clone();
+      let src                           =   self.operands [ 1 ].clone();
+      match ( &dst, &src )
+      {
+        (
+          OperandType::GeneralPurposeRegister { number:   dstRegister,  .. },
+          OperandType::Memory16               { .. } | OperandType::Memory32 { .. }
+        )
+        if self.size != 1
+        =>  {
+              self.encodeOperandSize        ( architecture, operandSize                                       )?;
+              self.setOpcode                ( opcode                                                          );
+              self.encodeModRegRMdata       ( addressSize,  *dstRegister, &src                                )?;
+              Ok  ( Some  ( self.getLength() ) )
+            },
+        (
+          OperandType::GeneralPurposeRegister { .. },
+          OperandType::Memory16               { .. } | OperandType::Memory32 { .. }
+        )
+        =>  self.failOperandSize(),
+        (
+          _,
+          OperandType::GeneralPurposeRegister { .. }
+        )
+        =>  self.fail
+            (
+              format!
+              (
+                "Source Must be a Memory Operand, Register ›{}‹ has no Address",
+                src.to_string  ( self.size  ),
+              )
+            ),
+        ( _, _ )
+        =>  self.fail
+            (
+              format!
+              (
+                "Invalid Combination of Arguments ›{}‹, ›{}‹",
+                dst.to_string  ( self.size  ),
+                src.to_string  ( self.size  ),
+              )
+            ),
+      }
+    }
+    else
+    {
+      self.fail
+      (
+        format!
+        (
+          "Instruction Must Take Exactly Two Arguments, got {}",
+          self.operands.len(),
+        )
+      )
+    }
+  }
+}
@@ -1,5 +1,25 @@
+mod asciiAdjust;
+mod bitTest;
+mod conditionalMove;
+mod enhancedVectorExtensions;
+mod escape;
+mod extendedOperations;
+mod floatingPoint;
+mod interrupts;
 mod jumps;
+mod loadAddress;
+mod moves;
+mod multiMedia;
+mod ports;
+mod setCondition;
+mod shifts;
 mod simpleMath;
+mod stack;
+mod streamingExtensions;
+mod system;
+mod testAndExchange;
+mod unaryMath;
+mod vectorExtensions;
 mod zeroOperands;
 
 pub use super::
@@ -9,11 +29,21 @@ pub use super::
     AssemblyFeatures,
     InstructionSet,
   },
+  memory::
+  {
+    Memory16Registers,
+    Memory32InstructionPointer,
+    Memory32NoRegister,
+  },
   operands::
   {
     Operand,
     OperandType,
   },
+  registers::
+  {
+    SegmentRegisterNumber,
+  },
   symbols::
   {
     SymbolIdentifier,
@@ -32,17 +62,31 @@ pub struct Instruction
   size:                                 usize,
   instruction:                          InstructionType,
   operands:                             Vec<OperandType>,
+  theOpmask:                            u8,
+  hazZeroing:                           bool,
+  hazBroadcast:                         bool,
+  theRounding:                          Option<RoundingControl>,
   //  for processing, initialised empty/invalid
+  references:                           Vec<( usize, OperandType )>,
   address:                              Option<InstructionAddress>,
+  hazWait:                              bool,
   hazLock:                              bool,
   theRepeat:                            u8,
   theSegmentOverride:                   u8,
   theBranchHint:                        u8,
   hazOperandSizeOverride:               bool,
   hazAddressSizeOverride:               bool,
+  theMandatoryPrefix:                   u8,
   hazThreeByteXOP:                      bool,
   hazTwoByteVEX:                        bool,
   hazThreeByteVEX:                      bool,
+  hazFourByteEVEX:                      bool,
+  theVectorMap:                         u8,
+  theVectorRegister:                    u8,
+  hazExtendedRegField:                  bool,
+  theVectorLength:                      u8,
+  hazVectorWidth:                       bool,
+  theVectorPayload:                     Vec<u8>,
   theREX:                               u8,
   hazTwoByteOpcode:                     bool,
   theOpcode:                            Option<u8>,
@@ -50,6 +94,8 @@ pub struct Instruction
   theSIBByte:                           Option<u8>,
   displacementLength:                   usize,
   displacementValue:                    i128,
+  theDisplacementScale:                 i128,
+  hazRelativeDisplacement:              bool,
   immediateLength:                      usize,
   immediateValue:                       i128,
 }
@@ -60,6 +106,7 @@ impl Instruction
   pub fn getDisplacement                ( &self )     ->  ( usize, i128 )             { ( self.displacementLength,  self.displacementValue  ) }
   pub fn getImmediate                   ( &self )     ->  ( usize, i128 )             { ( self.immediateLength,     self.immediateValue     ) }
   pub fn getLineNumber                  ( &self )     ->  usize                       { self.line                                             }
+  pub fn getMandatoryPrefix             ( &self )     ->  u8                          { self.theMandatoryPrefix                               }
   pub fn getModRegRM                    ( &self )     ->  Option<u8>                  { self.theModRegRM                                      }
   pub fn getOpcode                      ( &self )     ->  Option<u8>                  { self.theOpcode                                        }
   pub fn getOperands                    ( &self )     ->  Vec<OperandType>            { self.operands.clone()                                 }
@@ -69,10 +116,17 @@ impl Instruction
   pub fn getSegmentOverride             ( &self )     ->  u8                          { self.theSegmentOverride                               }
   pub fn getSIBByte                     ( &self )     ->  Option<u8>                  { self.theSIBByte                                       }
   pub fn getType                        ( &self )     ->  InstructionType             { self.instruction.clone()                              }
+  pub fn getVectorPayload               ( &self )     ->  Vec<u8>                     { self.theVectorPayload.clone()                         }
+
+  pub fn getOpmask                      ( &self )     ->  u8                          { self.theOpmask                                        }
+  pub fn getRounding                    ( &self )     ->  Option<RoundingControl>     { self.theRounding                                      }
 
   pub fn hazAddressSizeOverride         ( &self )     ->  bool                        { self.hazAddressSizeOverride                           }
   pub fn hazBranchHint                  ( &self )     ->  bool                        { self.theBranchHint          !=  0                     }
+  pub fn hazBroadcast                   ( &self )     ->  bool                        { self.hazBroadcast                                     }
+  pub fn hazFourByteEVEX                ( &self )     ->  bool                        { self.hazFourByteEVEX                                  }
   pub fn hazLock                        ( &self )     ->  bool                        { self.hazLock                                          }
+  pub fn hazMandatoryPrefix             ( &self )     ->  bool                        { self.theMandatoryPrefix     !=  0                     }
   pub fn hazOperandSizeOverride         ( &self )     ->  bool                        { self.hazOperandSizeOverride                           }
   pub fn hazRepeat                      ( &self )     ->  bool                        { self.theRepeat              !=  0                     }
   pub fn hazREX                         ( &self )     ->  bool                        { self.theREX                 !=  0                     }
@@ -81,12 +135,17 @@ impl Instruction
   pub fn hazThreeByteXOP                ( &self )     ->  bool                        { self.hazThreeByteXOP                                  }
   pub fn hazTwoByteOpcode               ( &self )     ->  bool                        { self.hazTwoByteOpcode                                 }
   pub fn hazTwoByteVEX                  ( &self )     ->  bool                        { self.hazTwoByteVEX                                    }
+  pub fn hazVectorExtension             ( &self )     ->  bool                        { self.theVectorMap           !=  0                     }
+  pub fn hazWait                        ( &self )     ->  bool                        { self.hazWait                                          }
+  pub fn hazZeroing                     ( &self )     ->  bool                        { self.hazZeroing                                       }
 
   pub fn orOperandSize                  ( &mut  self, size:     usize               ) { self.size                   |=  size;                 }
 
   pub fn setAddress                     ( &mut  self, address:  InstructionAddress  ) { self.address                =   Some  ( address );    }
   pub fn setAddressSizeOverride         ( &mut  self, value:    bool                ) { self.hazAddressSizeOverride =   value;                }
   pub fn setBranchHint                  ( &mut  self, value:    u8                  ) { self.theBranchHint          =   value;                }
+  pub fn setBroadcast                   ( &mut  self, value:    bool                ) { self.hazBroadcast           =   value;                }
+  pub fn setDisplacementScale          ( &mut  self, value:    i128                ) { self.theDisplacementScale   =   value;                }
   pub fn setDisplacement
   (
     &mut  self,
@@ -107,13 +166,19 @@ impl Instruction
     self.immediateLength                =   length;
     self.immediateValue                 =   value;
   }
+  pub fn setExtendedRegField           ( &mut  self, value:    bool                ) { self.hazExtendedRegField    =   value;            }
+  pub fn setFourByteEVEX                ( &mut  self, value:    bool                ) { self.hazFourByteEVEX        =   value;            }
   pub fn setImmediateLength             ( &mut  self, value:    usize               ) { self.immediateLength        =   value;            }
   pub fn setLock                        ( &mut  self, value:    bool                ) { self.hazLock                =   value;            }
+  pub fn setMandatoryPrefix             ( &mut  self, value:    u8                  ) { self.theMandatoryPrefix     =   value;            }
   pub fn setModRegRM                    ( &mut  self, value:    u8                  ) { self.theModRegRM            =   Some ( value  );  }
+  pub fn setOpmask                     ( &mut  self, value:    u8                  ) { self.theOpmask              =   value;            }
   pub fn setOpcode                      ( &mut  self, opcode:   u8                  ) { self.theOpcode              =   Some ( opcode );  }
   pub fn setOperandSizeOverride         ( &mut  self, value:    bool                ) { self.hazOperandSizeOverride =   value;            }
+  pub fn setReferences                  ( &mut  self, value:    Vec<( usize, OperandType )> ) { self.references = value;  }
   pub fn setRepeat                      ( &mut  self, value:    u8                  ) { self.theRepeat              =   value;            }
   pub fn setREX                         ( &mut  self, value:    u8                  ) { self.theREX                 =   value;            }
+  pub fn setRounding                    ( &mut  self, value:    RoundingControl     ) { self.theRounding            =   Some ( value  );  }
   pub fn setSegmentOverride             ( &mut  self, value:    u8                  ) { self.theSegmentOverride     =   value;            }
   pub fn setSIBByte                     ( &mut  self, value:    u8                  ) { self.theSIBByte             =   Some ( value  );  }
   pub fn setThreeByteVEX                ( &mut  self, value:    bool                ) { self.hazThreeByteVEX        =   value;            }
@@ -121,179 +186,655 @@ impl Instruction
   pub fn setTwoByteOpcode               ( &mut  self, value:    bool                ) { self.hazTwoByteOpcode       =   value;            }
   pub fn setTwoByteVEX                  ( &mut  self, value:    bool                ) { self.hazTwoByteVEX          =   value;            }
   pub fn setType                        ( &mut  self, value:    InstructionType     ) { self.instruction            =   value;            }
+  pub fn setVectorLength                ( &mut  self, value:    u8                  ) { self.theVectorLength        =   value;            }
+  pub fn setVectorMap                   ( &mut  self, value:    u8                  ) { self.theVectorMap           =   value;            }
+  pub fn setVectorRegister              ( &mut  self, value:    u8                  ) { self.theVectorRegister      =   value;            }
+  pub fn setVectorWidth                 ( &mut  self, value:    bool                ) { self.hazVectorWidth         =   value;            }
+  pub fn setWait                        ( &mut  self, value:    bool                ) { self.hazWait                =   value;            }
+  pub fn setZeroing                     ( &mut  self, value:    bool                ) { self.hazZeroing             =   value;            }
 
-  pub fn encodeModRegRMdata
+  pub fn clearEncoding
+  (
+    &mut self,
+  )
+  {
+    self.hazWait                        =   false;
+    self.hazOperandSizeOverride         =   false;
+    self.hazAddressSizeOverride         =   false;
+    self.theMandatoryPrefix             =   0;
+    self.hazThreeByteXOP                =   false;
+    self.hazTwoByteVEX                  =   false;
+    self.hazThreeByteVEX                =   false;
+    self.hazFourByteEVEX                =   false;
+    self.theVectorMap                   =   0;
+    self.theVectorRegister              =   0;
+    self.hazExtendedRegField            =   false;
+    self.theVectorLength                =   0;
+    self.hazVectorWidth                 =   false;
+    self.theVectorPayload.clear         ( );
+    self.theREX                         =   0;
+    self.hazTwoByteOpcode               =   false;
+    self.theOpcode                      =   None;
+    self.theModRegRM                    =   None;
+    self.theSIBByte                     =   None;
+    self.displacementLength             =   0;
+    self.displacementValue              =   0;
+    self.theDisplacementScale           =   1;
+    self.hazRelativeDisplacement        =   false;
+    self.immediateLength                =   0;
+    self.immediateValue                 =   0;
+  }
+
+  pub fn restoreReferences
+  (
+    &mut self,
+  )
+  {
+    for ( index, operand )              in  self.references.drain ( .. )
+    {
+      self.operands [ index ]           =   operand;
+    }
+  }
+
+  pub fn getLength
+  (
+    &self,
+  ) -> usize
+  {
+    if self.theOpcode.is_some()
+    {
+      [
+        self.hazWait,
+        self.hazLock(),
+        self.hazRepeat(),
+        self.hazSegmentOverride(),
+        self.hazBranchHint(),
+        self.hazOperandSizeOverride,
+        self.hazAddressSizeOverride,
+        self.hazMandatoryPrefix(),
+        self.hazThreeByteXOP,
+        self.hazTwoByteVEX,
+        self.hazThreeByteVEX,
+        self.hazFourByteEVEX,
+        self.hazREX(),
+        self.hazTwoByteOpcode,
+        true,
+        self.theModRegRM.is_some(),
+        self.theSIBByte.is_some(),
+      ].iter().filter ( |&&present| present ).count()
+      + self.theVectorPayload.len()
+      + self.displacementLength
+      + self.immediateLength
+    }
+    else
+    {
+      0
+    }
+  }
+
+  pub fn encodeOperandSize
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    operandSize:                        usize,
+  ) -> Result<(), String>
+  {
+    self.encodeOperandSizeOf  ( architecture, operandSize,  self.size )
+  }
+
+  //  for instructions with operands of different sizes, the size of the operation is not the size of the instruction
+  pub fn encodeOperandSizeOf
   (
     &mut self,
     architecture:                       InstructionSet,
     operandSize:                        usize,
+    size:                               usize,
+  ) -> Result<(), String>
+  {
+    match size
+    {
+      1
+      =>  Ok  ( () ),
+      2
+      =>  {
+            self.hazOperandSizeOverride =   operandSize !=  16;
+            Ok  ( () )
+          },
+      4 if architecture >= InstructionSet::i386
+      =>  {
+            self.hazOperandSizeOverride =   operandSize ==  16;
+            Ok  ( () )
+          },
+      //  the default operand size is 32 bit even in 64 bit mode
+      8 if operandSize == 64
+      =>  {
+            self.theREX                 |=  REX | REXW;
+            Ok  ( () )
+          },
+      _
+      =>  self.failOperandSize(),
+    }
+  }
+
+  pub fn encodeImmediate
+  (
+    &mut self,
+    size:                               usize,
+    value:                              i128,
+  ) -> Result<(), String>
+  {
+    let ( lowerBound, upperBound )      =   match size
+                                            {
+                                              1 =>  ( -0x80,        0xff        ),
+                                              2 =>  ( -0x8000,      0xffff      ),
+                                              4 =>  ( -0x80000000,  0xffffffff  ),
+                                              //  there are no 64 bit immediates, the 32 bit immediate is sign extended
+                                              8 =>  ( -0x80000000,  0x7fffffff  ),
+                                              _ =>  return self.failOperandSize(),
+                                            };
+    if  value >= lowerBound
+    &&  value <= upperBound
+    {
+      self.setImmediate ( if size == 8 { 4 } else { size }, value );
+      Ok  ( () )
+    }
+    else
+    {
+      self.failOutOfBounds
+      (
+        lowerBound,
+        upperBound,
+        value,
+      )
+    }
+  }
+
+  //  for opcodes with the register encoded in the lowest three bits
+  pub fn encodeOpcodeRegister
+  (
+    &mut self,
     opcode:                             u8,
-    mut instructionSize:                usize,
-    regRegisters:                       u8,
-    memRegisters:                       u8,
-    displacement:                       Option<i128>,
-    immediate:                          Option<i128>,
+    register:                           u8,
+  )
+  {
+    if register & 8 != 0
+    {
+      self.theREX                       |=  REX | REXB;
+    }
+    self.setOpcode  ( opcode  | ( register  & 7 ) );
+  }
+
+  //  spl, bpl, sil and dil require a REX prefix, which turns ah, ch, dh and bh into them
+  pub fn encodeRegisterExtension
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+    length:                             Option<usize>,
   ) -> Result<Option<usize>, String>
   {
-    let ( modField, dispSize  )         =   match displacement
+    if self.theOpcode.is_none()
+    {
+      return Ok ( length  );
+    }
+    //  with operands of different sizes, only the source is a byte register
+    let byteRegisters                   =   match self.size
                                             {
-                                              None                          =>  Ok  ( ( 0xc0, 0 ) ),
-                                              Some  ( 0                   ) =>  Ok  ( ( 0x00, 0 ) ),
-                                              Some  ( -0x80   ... 0x7f    ) =>  Ok  ( ( 0x40, 1 ) ),
-                                              Some  ( -0x8000 ... 0x7fff  ) =>  Ok  ( ( 0x80, 2 ) ),
-                                              _                   =>  Err
-                                                                      (
-                                                                        format!
-                                                                        (
-                                                                          "Invalid Displacement {}",
-                                                                          self.size,
-                                                                        )
-                                                                      )
-                                            }?;
-    self.theModRegRM                    =   Some  ( modField  | regRegisters  <<  3 | memRegisters  );
-    self.displacementLength             =   dispSize;
-    if let  Some  ( dispValue ) = displacement
-    {
-      self.displacementLength           =   dispSize;
-      self.displacementValue            =   dispValue;
-    }
-    instructionSize                     +=  dispSize  + 1;
-    match self.size
+                                              1                           =>  &self.operands  [ ..  ],
+                                              3 | 5 | 9 if self.operands.len() > 1
+                                                                          =>  &self.operands  [ 1.. ],
+                                              _                           =>  &self.operands  [ 0..0  ],
+                                            };
+    let mut hazLegacyHigh               =   false;
+    let mut hazExtendedLow              =   false;
+    for operand                         in  byteRegisters
     {
-      1
+      match operand
+      {
+        OperandType::GeneralPurposeRegister { rex:  true,   .. }
+        =>  hazExtendedLow              =   true,
+        OperandType::GeneralPurposeRegister { rex:  false,  number: 4 ..= 7,  .. }
+        =>  hazLegacyHigh               =   true,
+        _
+        =>  {},
+      }
+    }
+    if hazExtendedLow
+    {
+      self.theREX                       |=  REX;
+    }
+    if !self.hazFourByteEVEX
+    {
+      if  self.theOpmask != 0
+      ||  self.hazZeroing
+      ||  self.hazBroadcast
+      ||  self.theRounding.is_some()
+      {
+        return self.fail ( "Masking, Broadcast and Rounding Require an EVEX Encoded Instruction".to_string() );
+      }
+      if self.operands.iter().any ( |operand| matches! ( operand, OperandType::MulitMediaRegister ( 16 ..= 31 ) ) )
+      {
+        return self.fail ( "Registers 16 to 31 Require an EVEX Encoded Instruction".to_string() );
+      }
+    }
+    if  self.hazREX()
+    ||  self.theVectorRegister > 7
+    ||  self.hazExtendedRegField
+    {
+      if  architecture  < InstructionSet::amd64
+      ||  addressSize   != 64
+      {
+        return self.fail ( "Extended Registers and 64 Bit Operands Require 64 Bit Mode".to_string() );
+      }
+      if hazLegacyHigh
+      {
+        return self.fail ( "Cannot Encode ah, ch, dh or bh in an Instruction with REX Prefix".to_string() );
+      }
+    }
+    if self.hazVectorExtension()
+    {
+      self.encodeVectorExtension        ( );
+    }
+    Ok  ( Some  ( self.getLength() ) )
+  }
+
+  //  the VEX prefix replaces the mandatory prefix, the REX prefix and the escape bytes 0x0f, 0x0f 0x38 and 0x0f 0x3a.
+  //  R, X, B and vvvv are stored inverted, the two byte form implies X, B and W clear and the map 0x0f.
+  //  the XOP prefix is built like the three byte VEX prefix, but with 0x8f and the maps 8 to 10,
+  //  so the reg field of what would be the Mod Reg R/M byte of pop r/m (0x8f /0) is never zero.
+  //  the EVEX prefix adds R' and V' for the registers 16 to 31, the opmask, zeroing, broadcast and rounding,
+  //  X extends a register in r/m, and the rounding mode replaces the vector length.
+  fn encodeVectorExtension
+  (
+    &mut self,
+  )
+  {
+    let     wide                        =   self.hazVectorWidth ||  ( self.theREX & REXW  ) != 0;
+    let     prefix                      =   match self.theMandatoryPrefix
+                                            {
+                                              0x66                        =>  1,
+                                              0xf3                        =>  2,
+                                              0xf2                        =>  3,
+                                              _                           =>  0,
+                                            };
+    let     payload                     =   if wide                 { 0x80 } else { 0x00 }
+                                        |   ( !self.theVectorRegister & 15 ) << 3
+                                        |   if self.theVectorLength != 0 { 0x04 } else { 0x00 }
+                                        |   prefix;
+    let     inverted                    =   !( self.theREX  <<  5 ) & 0xe0;
+    if self.hazFourByteEVEX
+    {
+      let vectorLength                  =   match self.theRounding
+                                            {
+                                              Some  ( rounding  )         =>  rounding as u8,
+                                              None                        =>  self.theVectorLength,
+                                            };
+      self.theVectorPayload             =   vec!
+                                            (
+                                              inverted
+                                              | if self.hazExtendedRegField { 0x00 } else { 0x10 }
+                                              | self.theVectorMap,
+                                              payload & 0xfb | 0x04,
+                                              if self.hazZeroing { 0x80 } else { 0x00 }
+                                              | vectorLength << 5
+                                              | if self.hazBroadcast || self.theRounding.is_some() { 0x10 } else { 0x00 }
+                                              | if self.theVectorRegister & 16 != 0 { 0x00 } else { 0x08 }
+                                              | self.theOpmask,
+                                            );
+    }
+    else if self.theVectorMap >= 8
+    {
+      self.hazThreeByteXOP              =   true;
+      self.theVectorPayload             =   vec!  ( inverted | self.theVectorMap,  payload );
+    }
+    else if self.theVectorMap == 1
+    &&  !wide
+    &&  ( self.theREX & ( REXX | REXB ) ) == 0
+    &&  !self.features.hazFeature ( AssemblyFeatures::X86ThreeByteVEX )
+    &&  !( self.features.hazFeature ( AssemblyFeatures::RandomOpcode ) && rand::random() )
+    {
+      self.hazTwoByteVEX                =   true;
+      self.theVectorPayload             =   vec!  ( inverted & 0x80 | payload & 0x7f  );
+    }
+    else
+    {
+      self.hazThreeByteVEX              =   true;
+      self.theVectorPayload             =   vec!  ( inverted | self.theVectorMap,  payload );
+    }
+    self.theMandatoryPrefix             =   0;
+    self.theREX                         =   0;
+    self.hazTwoByteOpcode               =   false;
+  }
+
+  pub fn encodeRelativeDisplacement
+  (
+    &mut self,
+  )
+  {
+    if self.hazRelativeDisplacement
+    {
+      self.displacementValue            -=  self.getLength() as i128;
+    }
+  }
+
+  pub fn segmentOverride
+  (
+    segment:                            SegmentRegisterNumber,
+  ) -> u8
+  {
+    match segment
+    {
+      SegmentRegisterNumber::ES         =>  SegmentOverrideES,
+      SegmentRegisterNumber::CS         =>  SegmentOverrideCS,
+      SegmentRegisterNumber::SS         =>  SegmentOverrideSS,
+      SegmentRegisterNumber::DS         =>  SegmentOverrideDS,
+      SegmentRegisterNumber::FS         =>  SegmentOverrideFS,
+      SegmentRegisterNumber::GS         =>  SegmentOverrideGS,
+      SegmentRegisterNumber::Default    =>  0,
+    }
+  }
+
+  //  a segment override attached with the builder must not contradict the segment of the memory operand
+  pub fn encodeSegment
+  (
+    &mut self,
+    segment:                            SegmentRegisterNumber,
+    default:                            SegmentRegisterNumber,
+  ) -> Result<(), String>
+  {
+    if  segment !=  default
+    &&  segment !=  SegmentRegisterNumber::Default
+    {
+      let prefix                        =   Self::segmentOverride ( segment );
+      if  self.hazSegmentOverride()
+      &&  self.theSegmentOverride != prefix
+      {
+        return self.fail ( "Conflicting Segment Overrides".to_string() );
+      }
+      self.theSegmentOverride           =   prefix;
+    }
+    Ok  ( () )
+  }
+
+  //  prefixes attached with the builder are checked, before the instruction is encoded:
+  //  lock only for read-modify-write instructions on memory, repeat only for string instructions,
+  //  and segment overrides only for memory operands and string instructions with a source in memory
+  pub fn validatePrefixes
+  (
+    &self,
+    architecture:                       InstructionSet,
+  ) -> Result<(), String>
+  {
+    let isMemory                        =   | operand: &OperandType | matches!
+                                            (
+                                              operand,
+                                              OperandType::Memory16     { .. } |
+                                              OperandType::Memory32     { .. } |
+                                              OperandType::RipRelative  { .. }
+                                            );
+    if self.hazLock()
+    {
+      match self.instruction
+      {
+        InstructionType::ADC        | InstructionType::ADD        | InstructionType::AND
+      | InstructionType::BTC        | InstructionType::BTR        | InstructionType::BTS
+      | InstructionType::CMPXCHG    | InstructionType::CMPXCHG8B  | InstructionType::DEC
+      | InstructionType::INC        | InstructionType::NEG        | InstructionType::NOT
+      | InstructionType::OR         | InstructionType::SBB        | InstructionType::SUB
+      | InstructionType::XADD       | InstructionType::XOR
+        =>  if !self.operands.first().is_some_and ( isMemory )
+            {
+              return self.fail ( "Lock Prefix Requires a Memory Destination".to_string() );
+            },
+        //  xchg encodes the memory operand the same way in either position, so lock is valid with a memory
+        //  source or destination, but never with two registers
+        InstructionType::XCHG
+        =>  if !self.operands.iter().any ( isMemory )
+            {
+              return self.fail ( "Lock Prefix Requires a Memory Destination".to_string() );
+            },
+        _
+        =>  return self.fail ( "Lock Prefix Requires a Read-Modify-Write Instruction".to_string() ),
+      }
+    }
+    if self.hazRepeat()
+    {
+      match ( &self.instruction, self.theRepeat )
+      {
+        ( InstructionType::CMPSB,   _       ) | ( InstructionType::CMPSW,   _       ) |
+        ( InstructionType::SCASB,   _       ) | ( InstructionType::SCASW,   _       ) |
+        ( InstructionType::INSB,    Repeat  ) | ( InstructionType::INSW,    Repeat  ) |
+        ( InstructionType::LODSB,   Repeat  ) | ( InstructionType::LODSW,   Repeat  ) |
+        ( InstructionType::MOVSB,   Repeat  ) | ( InstructionType::MOVSW,   Repeat  ) |
+        ( InstructionType::OUTSB,   Repeat  ) | ( InstructionType::OUTSW,   Repeat  ) |
+        ( InstructionType::STOSB,   Repeat  ) | ( InstructionType::STOSW,   Repeat  )
+        =>  {},
+        ( InstructionType::INSB,    _       ) | ( InstructionType::INSW,    _       ) |
+        ( InstructionType::LODSB,   _       ) | ( InstructionType::LODSW,   _       ) |
+        ( InstructionType::MOVSB,   _       ) | ( InstructionType::MOVSW,   _       ) |
+        ( InstructionType::OUTSB,   _       ) | ( InstructionType::OUTSW,   _       ) |
+        ( InstructionType::STOSB,   _       ) | ( InstructionType::STOSW,   _       )
+        =>  return self.fail ( "Only Compare and Scan Strings Repeat While Not Equal".to_string() ),
+        _
+        =>  return self.fail ( "Repeat Prefix Requires a String Instruction".to_string() ),
+      }
+    }
+    if self.hazSegmentOverride()
+    {
+      if  self.theSegmentOverride == SegmentOverrideFS
+      ||  self.theSegmentOverride == SegmentOverrideGS
+      {
+        self.requireInstructionSet  ( architecture, InstructionSet::i386  )?;
+      }
+      match self.instruction
+      {
+        InstructionType::CMPSB      | InstructionType::CMPSW
+      | InstructionType::LODSB      | InstructionType::LODSW
+      | InstructionType::MOVSB      | InstructionType::MOVSW
+      | InstructionType::OUTSB      | InstructionType::OUTSW
+      | InstructionType::XLAT
+        =>  {},
+        _
+        if self.operands.iter().any ( isMemory )
+        =>  {},
+        _
+        =>  return self.fail ( "Segment Override Requires a Memory Operand".to_string() ),
+      }
+    }
+    Ok  ( () )
+  }
+
+  //  EVEX scales eight bit displacements by the size of the memory access (disp8*N),
+  //  displacements, which are not a multiple of it, need the long form
+  fn compressDisplacement
+  (
+    &self,
+    displacement:                       i128,
+  ) -> i128
+  {
+    if displacement % self.theDisplacementScale == 0
+    {
+      displacement / self.theDisplacementScale
+    }
+    else
+    {
+      0x80
+    }
+  }
+
+  //  encodes the register or memory operand into the Mod Reg R/M byte, the SIB byte and the displacement
+  pub fn encodeModRegRMdata
+  (
+    &mut self,
+    addressSize:                        usize,
+    regField:                           u8,
+    operand:                            &OperandType,
+  ) -> Result<(), String>
+  {
+    if regField & 8 != 0
+    {
+      self.theREX                       |=  REX | REXR;
+    }
+    match operand
+    {
+      OperandType::GeneralPurposeRegister { number, .. } |
+      OperandType::MulitMediaRegister     ( number      ) |
+      OperandType::MaskRegister           ( number      )
       =>  {
-            self.theOpcode              =   Some  ( opcode  | 0 );
-            if let Some ( value ) = immediate
+            if number & 8 != 0
             {
-              self.immediateValue       =   value;
-              if  value >= -0x80
-              &&  value <=  0xff
-              {
-                self.immediateLength    =   1;
-                Ok  ( Some  ( instructionSize + 1 ) )
-              }
-              else
-              {
-                self.failOutOfBounds
-                (
-                  -0x80,
-                  0xff,
-                  value,
-                )
-              }
+              self.theREX               |=  REX | REXB;
             }
-            else
+            self.theModRegRM            =   Some  ( 0xc0 | ( regField & 7 ) << 3 | ( number & 7 ) );
+            Ok  ( () )
+          },
+      OperandType::Memory16               { .. }
+      if addressSize == 64
+      =>  self.fail ( "16 Bit Addressing is not Available in 64 Bit Mode".to_string() ),
+      OperandType::Memory16               { segment,  registers,  displacement  }
+      =>  {
+            //  bp-based addressing defaults to the stack segment, everything else to the data segment
+            let default                 =   match registers
+                                            {
+                                              Memory16Registers::BPSI |
+                                              Memory16Registers::BPDI |
+                                              Memory16Registers::BP   =>  SegmentRegisterNumber::SS,
+                                              _                       =>  SegmentRegisterNumber::DS,
+                                            };
+            self.encodeSegment  ( *segment, default )?;
+            self.hazAddressSizeOverride =   addressSize ==  32;
+            let displacement            =   *displacement;
+            let compressed              =   self.compressDisplacement ( displacement  );
+            let ( modField, rmField, dispSize )
+                                        =   match ( registers, displacement )
+                                            {
+                                              ( Memory16Registers::INVALID, _                     )
+                                              =>  return self.fail ( "Invalid Memory Operand".to_string() ),
+                                              //  mod 00 r/m 110 is direct addressing, therefore [bp] needs an explicit zero displacement
+                                              ( Memory16Registers::DISP,    -0x8000 ..= 0xffff    )
+                                              =>  ( 0x00, 6,                  2 ),
+                                              ( Memory16Registers::DISP,    _                     )
+                                              =>  return self.failOutOfBounds ( -0x8000,  0xffff, displacement  ),
+                                              ( _,                          0                     )
+                                              if *registers != Memory16Registers::BP
+                                              =>  ( 0x00, *registers as u8,   0 ),
+                                              ( _,                          _                     )
+                                              if ( -0x80 ..= 0x7f ).contains ( &compressed )
+                                              =>  ( 0x40, *registers as u8,   1 ),
+                                              ( _,                          -0x8000 ..= 0xffff    )
+                                              =>  ( 0x80, *registers as u8,   2 ),
+                                              ( _,                          _                     )
+                                              =>  return self.failOutOfBounds ( -0x8000,  0xffff, displacement  ),
+                                            };
+            self.theModRegRM            =   Some  ( modField  | ( regField & 7 ) << 3 | rmField );
+            self.setDisplacement  ( dispSize, if dispSize == 1 { compressed } else { displacement } );
+            Ok  ( () )
+          },
+      OperandType::Memory32               { segment,  base: Memory32InstructionPointer, displacement, .. }
+      =>  {
+            if addressSize != 64
             {
-              Ok  ( Some  ( instructionSize + 0 ) )
+              return self.fail ( "Instruction Pointer Relative Addressing Requires 64 Bit Mode".to_string() );
             }
+            self.encodeSegment  ( *segment, SegmentRegisterNumber::DS )?;
+            //  mod 00 r/m 101 is relative to the end of the instruction in 64 bit mode,
+            //  but the displacement is relative to its start until the length is known
+            self.theModRegRM            =   Some  ( ( regField & 7 ) << 3 | 5 );
+            self.setDisplacement  ( 4,  *displacement );
+            self.hazRelativeDisplacement
+                                        =   true;
+            Ok  ( () )
           },
-      2
+      OperandType::Memory32               { width,  segment,  base, scale,  index,  displacement  }
       =>  {
-            if let Some ( value ) = immediate
+            //  esp- and ebp-based addressing defaults to the stack segment, everything else to the data segment
+            let default                 =   if *base == 4 || *base == 5 { SegmentRegisterNumber::SS } else { SegmentRegisterNumber::DS };
+            self.encodeSegment  ( *segment, default )?;
+            if  *width      ==  64
+            &&  addressSize !=  64
             {
-              self.immediateValue       =   value;
-              if operandSize == 4
-              {
-                self.hazOperandSizeOverride
-                                        =   true;
-                instructionSize         +=  1;
-              }
-              if        value >= -0x80
-              &&        value <=  0x7f
-              &&        (
-                          self.features.hazFeature  ( AssemblyFeatures::X86SignExtensionAllowed )
-                        ||
-                          architecture >= InstructionSet::i386
-                        )
-              &&       !(
-                          self.features.hazFeature ( AssemblyFeatures::RandomOpcodeSize )
-                        &&
-                          rand::random()
-                        )
-              {
-                self.theOpcode          =   Some  ( opcode  | 3 );
-                self.immediateLength    =   1;
-                Ok  ( Some  ( instructionSize + 1 ) )
-              }
-              else  if  value >= -0x8000
-                    &&  value <=  0xffff
-              {
-                self.theOpcode          =   Some  ( opcode  | 1 );
-                self.immediateLength    =   2;
-                Ok  ( Some  ( instructionSize + 2 ) )
-              }
-              else
-              {
-                self.failOutOfBounds
-                (
-                  -0x8000,
-                  0xffff,
-                  value,
-                )
-              }
+              return self.fail ( "64 Bit Addressing Requires 64 Bit Mode".to_string() );
             }
-            else
+            self.hazAddressSizeOverride =   addressSize !=  *width;
+            let ( base, index )         =   ( *base,  *index  );
+            let displacement            =   *displacement;
+            let compressed              =   self.compressDisplacement ( displacement  );
+            let ( lowerBound, upperBound )
+                                        =   if *width == 64 { ( -0x80000000,  0x7fffffff  ) } else { ( -0x80000000,  0xffffffff  ) };
+            if  displacement < lowerBound
+            ||  displacement > upperBound
             {
-              self.theOpcode            =   Some  ( opcode  | 1 );
-              Ok  ( Some  ( instructionSize + 0 ) )
+              return self.failOutOfBounds ( lowerBound, upperBound, displacement  );
             }
-          },
-      4 if architecture >= InstructionSet::i386
-      =>  {
-            if let Some ( value ) = immediate
+            if index == 4
             {
-              if operandSize == 2
-              {
-                self.hazOperandSizeOverride
-                                        =   true;
-                instructionSize         +=  1;
-              }
-              self.immediateValue       =   value;
-              if        value >= -0x80
-              &&        value <=  0x7f
-              &&       !(
-                          self.features.hazFeature ( AssemblyFeatures::RandomOpcodeSize )
-                        &&
-                          rand::random()
-                        )
-              {
-                self.theOpcode          =   Some  ( opcode  | 3 );
-                self.immediateLength    =   1;
-                Ok  ( Some  ( instructionSize + 1 ) )
-              }
-              else  if  value >= -0x80000000
-                    &&  value <=  0xffffffff
-              {
-                self.theOpcode          =   Some  ( opcode  | 1 );
-                self.immediateLength    =   4;
-                Ok  ( Some  ( instructionSize + 4 ) )
-              }
-              else
-              {
-                self.failOutOfBounds
-                (
-                  -0x80000000,
-                  0xffffffff,
-                  value,
-                )
-              }
+              return self.fail ( "Stack Pointer Cannot be an Index".to_string() );
             }
-            else
+            if base   != Memory32NoRegister && base   & 8 != 0  { self.theREX |=  REX | REXB; }
+            if index  != Memory32NoRegister && index  & 8 != 0  { self.theREX |=  REX | REXX; }
+            let scaleField              =   match scale
+                                            {
+                                              1 =>  0x00,
+                                              2 =>  0x40,
+                                              4 =>  0x80,
+                                              8 =>  0xc0,
+                                              _ =>  return self.fail ( format! ( "Invalid Scale {}", scale ) ),
+                                            };
+            //  without base, there is always a 32 bit displacement,
+            //  and mod 00 with ebp as base means exactly that, therefore [ebp] needs an explicit zero displacement
+            let ( modField, dispSize )  =   match ( base, displacement )
+                                            {
+                                              ( Memory32NoRegister, _             ) =>  ( 0x00, 4 ),
+                                              ( _,                  0             )
+                                              if base & 7 != 5                      =>  ( 0x00, 0 ),
+                                              ( _,                  _             )
+                                              if ( -0x80 ..= 0x7f ).contains ( &compressed )
+                                                                                    =>  ( 0x40, 1 ),
+                                              ( _,                  _             ) =>  ( 0x80, 4 ),
+                                            };
+            match ( base, index )
             {
-              self.theOpcode            =   Some  ( opcode  | 1 );
-              Ok  ( Some  ( instructionSize + 0 ) )
+              //  r/m 101 without sib byte is direct addressing, but instruction pointer relative in 64 bit mode
+              ( Memory32NoRegister, Memory32NoRegister  )
+              if addressSize != 64
+              =>  self.theModRegRM      =   Some  ( ( regField & 7 ) << 3 | 5 ),
+              //  r/m 100 is the escape to the sib byte, therefore [esp] needs one
+              ( _,                  Memory32NoRegister  )
+              if  base      !=  Memory32NoRegister
+              &&  base & 7  !=  4
+              =>  self.theModRegRM      =   Some  ( modField  | ( regField & 7 ) << 3 | ( base & 7 ) ),
+              //  index 100 means no index and base 101 with mod 00 means no base
+              ( _,                  _                   )
+              =>  {
+                    self.theModRegRM    =   Some  ( modField  | ( regField & 7 ) << 3 | 4 );
+                    self.theSIBByte     =   Some
+                                            (
+                                              scaleField
+                                              | ( if index == Memory32NoRegister  { 4 } else { index  & 7 } ) << 3
+                                              | ( if base   == Memory32NoRegister { 5 } else { base   & 7 } )
+                                            );
+                  },
             }
+            self.setDisplacement  ( dispSize, if dispSize == 1 { compressed } else { displacement } );
+            Ok  ( () )
           },
       _
-      =>  self.failOperandSize(),
+      =>  self.fail
+          (
+            format!
+            (
+              "Invalid Register or Memory Operand ›{}‹",
+              operand.to_string ( self.size ),
+            )
+          ),
     }
   }
 
-  pub fn fail
+  pub fn fail<T>
   (
     &self,
     message:                            String,
-  ) -> Result<Option<usize>, String>
+  ) -> Result<T, String>
   {
     print!  ( "Line {}: ", self.line );
     //Self::printType ( &self.instruction );
@@ -365,10 +906,62 @@ impl Instruction
     }
   }
 
-  pub fn failOperandSize
+  pub fn requireInstructionSet
   (
     &self,
-  ) -> Result<Option<usize>, String>
+    architecture:                       InstructionSet,
+    minimum:                            InstructionSet,
+  ) -> Result<(), String>
+  {
+    if architecture < minimum
+    {
+      self.fail
+      (
+        format!
+        (
+          "Instruction Requires at least {}, but Target is {}",
+          InstructionSet  ( minimum       ),
+          InstructionSet  ( architecture  ),
+        )
+      )
+    }
+    else
+    {
+      Ok  ( () )
+    }
+  }
+
+  //  vendor specific extensions are not part of the linear order of instruction sets,
+  //  therefore the target has to be one of the listed instruction sets
+  pub fn requireInstructionSetOf
+  (
+    &self,
+    architecture:                       InstructionSet,
+    allowed:                            &[InstructionSet],
+  ) -> Result<(), String>
+  {
+    if allowed.contains ( &architecture )
+    {
+      Ok  ( () )
+    }
+    else
+    {
+      self.fail
+      (
+        format!
+        (
+          "Instruction Requires {}, but Target is {}",
+          allowed.iter().map ( |&instructionSet| InstructionSet ( instructionSet ) ).collect::<Vec<_>>().join ( " or " ),
+          InstructionSet  ( architecture  ),
+        )
+      )
+    }
+  }
+
+  pub fn failOperandSize<T>
+  (
+    &self,
+  ) -> Result<T, String>
   {
     self.fail
     (
@@ -390,13 +983,13 @@ impl Instruction
     )
   }
 
-  pub fn failOutOfBounds
+  pub fn failOutOfBounds<T>
   (
     &self,
     lowerBound:                         i128,
     upperBound:                         i128,
     immediate:                          i128,
-  ) -> Result<Option<usize>, String>
+  ) -> Result<T, String>
   {
     self.fail
     (
@@ -429,17 +1022,31 @@ pub fn Instruction
     size:                               size,
     instruction:                        instruction,
     operands:                           operands,
+    theOpmask:                          0,
+    hazZeroing:                         false,
+    hazBroadcast:                       false,
+    theRounding:                        None,
     //  for processing, initialised empty/invalid
+    references:                         vec!(),
     address:                            None,
+    hazWait:                            false,
     hazLock:                            false,
     theRepeat:                          0,
     theSegmentOverride:                 0,
     theBranchHint:                      0,
     hazOperandSizeOverride:             false,
     hazAddressSizeOverride:             false,
+    theMandatoryPrefix:                 0,
     hazThreeByteXOP:                    false,
     hazTwoByteVEX:                      false,
     hazThreeByteVEX:                    false,
+    hazFourByteEVEX:                    false,
+    theVectorMap:                       0,
+    theVectorRegister:                  0,
+    hazExtendedRegField:                false,
+    theVectorLength:                    0,
+    hazVectorWidth:                     false,
+    theVectorPayload:                   vec!(),
     theREX:                             0,
     hazTwoByteOpcode:                   false,
     theOpcode:                          None,
@@ -447,6 +1054,8 @@ pub fn Instruction
     theSIBByte:                         None,
     displacementLength:                 0,
     displacementValue:                  0,
+    theDisplacementScale:               1,
+    hazRelativeDisplacement:            false,
     immediateLength:                    0,
     immediateValue:                     0,
   }
@@ -502,6 +1111,31 @@ impl InstructionAddress
   }
 }
 
+//  instructions with the same opcode are distinguished by the mandatory prefix and the opcode map,
+//  the maps 1, 2 and 3 stand for the escape sequences 0x0f, 0x0f 0x38 and 0x0f 0x3a, XOP haz the maps 8, 9 and 10
+#[derive(Clone,Copy,PartialEq)]
+pub struct VectorOpcode
+{
+  pub prefix:                           u8,
+  pub map:                              u8,
+  pub opcode:                           u8,
+}
+
+pub fn VectorOpcode
+(
+  prefix:                               u8,
+  map:                                  u8,
+  opcode:                               u8,
+) -> VectorOpcode
+{
+  VectorOpcode
+  {
+    prefix,
+    map,
+    opcode,
+  }
+}
+
 #[derive(Clone,Debug,PartialEq,PartialOrd)]
 pub enum InstructionType
 {
@@ -514,30 +1148,125 @@ pub enum InstructionType
   AAM,
   ADC,
   ADD,
+  ADDPD,
+  ADDPS,
+  ADDSD,
+  ADDSS,
   AND,
+  ANDNPD,
+  ANDNPS,
+  ANDPD,
+  ANDPS,
+  ARPL,
+  BOUND,
+  BSWAP,
+  BSF,
+  BSR,
+  BT,
+  BTC,
+  BTR,
+  BTS,
   CALL,
   CBW,
   CLC,
   CLD,
   CLI,
+  CLTS,
   CMC,
+  CMOVB,
+  CMOVBE,
+  CMOVE,
+  CMOVL,
+  CMOVLE,
+  CMOVNB,
+  CMOVNBE,
+  CMOVNE,
+  CMOVNL,
+  CMOVNLE,
+  CMOVNO,
+  CMOVNP,
+  CMOVNS,
+  CMOVO,
+  CMOVP,
+  CMOVS,
   CMP,
+  CMPPD,
+  CMPPS,
   CMPSB,
+  CMPSD,
+  CMPSS,
   CMPSW,
+  CMPXCHG,
+  CMPXCHG8B,
+  COMISD,
+  COMISS,
+  CPUID,
+  CVTDQ2PS,
+  CVTPD2PS,
+  CVTPS2DQ,
+  CVTPS2PD,
+  CVTSD2SI,
+  CVTSD2SS,
+  CVTSI2SD,
+  CVTSI2SS,
+  CVTSS2SD,
+  CVTSS2SI,
+  CVTTSD2SI,
+  CVTTSS2SI,
   CWD,
   DAA,
   DAS,
   DEC,
   DIV,
+  DIVPD,
+  DIVPS,
+  DIVSD,
+  DIVSS,
+  EMMS,
+  ENTER,
   ESC,
+  FADD,
+  FADDP,
+  FCMOVB,
+  FCMOVBE,
+  FCMOVE,
+  FCMOVNB,
+  FCMOVNBE,
+  FCMOVNE,
+  FCMOVNU,
+  FCMOVU,
+  FCOM,
+  FCOMI,
+  FCOMIP,
+  FCOMP,
+  FCOMPP,
+  FDIV,
+  FDIVP,
+  FDIVR,
+  FDIVRP,
+  FLD,
+  FMUL,
+  FMULP,
+  FNINIT,
+  FST,
+  FSTP,
+  FSUB,
+  FSUBP,
+  FSUBR,
+  FSUBRP,
+  FUCOMI,
+  FUCOMIP,
   HLT,
   IDIV,
   IMUL,
   IN,
   INC,
+  INSB,
+  INSW,
   INT,
   INT3,
   INTO,
+  INVLPG,
   IRET,
   JB,
   JBE,
@@ -557,27 +1286,120 @@ pub enum InstructionType
   JO,
   JP,
   JS,
+  KANDNW,
+  KANDW,
+  KMOVW,
+  KNOTW,
+  KORTESTW,
+  KORW,
+  KXORW,
   LAHF,
+  LAR,
   LDS,
   LEA,
+  LEAVE,
   LES,
+  LGDT,
+  LIDT,
+  LLDT,
+  LMSW,
   LODSB,
   LODSW,
   LOOP,
   LOOPZ,
   LOOPNZ,
+  LSL,
+  LTR,
+  MAXPD,
+  MAXPS,
+  MAXSD,
+  MAXSS,
+  MINPD,
+  MINPS,
+  MINSD,
+  MINSS,
   MOV,
+  MOVAPD,
+  MOVAPS,
+  MOVD,
+  MOVDQA,
+  MOVDQU,
+  MOVQ,
   MOVSB,
+  MOVSD,
+  MOVSS,
   MOVSW,
+  MOVSX,
+  MOVUPD,
+  MOVUPS,
+  MOVZX,
   MUL,
+  MULPD,
+  MULPS,
+  MULSD,
+  MULSS,
   NEG,
+  NOP,
   NOT,
   OR,
+  ORPD,
+  ORPS,
   OUT,
+  OUTSB,
+  OUTSW,
+  PACKSSDW,
+  PACKSSWB,
+  PACKUSWB,
+  PADDB,
+  PADDD,
+  PADDSB,
+  PADDSW,
+  PADDUSB,
+  PADDUSW,
+  PADDW,
+  PAND,
+  PANDN,
+  PCMPEQB,
+  PCMPEQD,
+  PCMPEQW,
+  PCMPGTB,
+  PCMPGTD,
+  PCMPGTW,
+  PMADDWD,
+  PMULHW,
+  PMULLW,
+  POR,
+  PSLLD,
+  PSLLQ,
+  PSLLW,
+  PSRAD,
+  PSRAW,
+  PSRLD,
+  PSRLQ,
+  PSRLW,
+  PSUBB,
+  PSUBD,
+  PSUBSB,
+  PSUBSW,
+  PSUBUSB,
+  PSUBUSW,
+  PSUBW,
+  PUNPCKHBW,
+  PUNPCKHDQ,
+  PUNPCKHWD,
+  PUNPCKLBW,
+  PUNPCKLDQ,
+  PUNPCKLWD,
+  PXOR,
   POP,
+  POPA,
   POPF,
   PUSH,
+  PUSHA,
   PUSHF,
+  RDMSR,
+  RDPMC,
+  RDTSC,
   RCL,
   RCR,
   RETF,
@@ -588,22 +1410,214 @@ pub enum InstructionType
   SAL,
   SALC,
   SAR,
+  SGDT,
   SHL,
+  SHLD,
   SHR,
+  SHRD,
   SBB,
   SCASB,
   SCASW,
+  SETB,
+  SETBE,
+  SETE,
+  SETL,
+  SETLE,
+  SETNB,
+  SETNBE,
+  SETNE,
+  SETNL,
+  SETNLE,
+  SETNO,
+  SETNP,
+  SETNS,
+  SETO,
+  SETP,
+  SETS,
+  SIDT,
+  SLDT,
+  SMSW,
+  SQRTPD,
+  SQRTPS,
+  SQRTSD,
+  SQRTSS,
   STC,
   STD,
   STI,
   STOSB,
   STOSW,
+  STR,
+  SUBPD,
+  SUBPS,
+  SUBSD,
+  SUBSS,
+  SYSENTER,
+  SYSEXIT,
   SUB,
   TEST,
+  UCOMISD,
+  UCOMISS,
+  UD2,
+  VADDPD,
+  VADDPS,
+  VADDSD,
+  VADDSS,
+  VANDNPD,
+  VANDNPS,
+  VANDPD,
+  VANDPS,
+  VBROADCASTSS,
+  VCMPPD,
+  VCMPPS,
+  VCMPSD,
+  VCMPSS,
+  VDIVPD,
+  VDIVPS,
+  VDIVSD,
+  VDIVSS,
+  VERR,
+  VERW,
+  VEXTRACTF128,
+  VEXTRACTI128,
+  VINSERTF128,
+  VINSERTI128,
+  VMAXPD,
+  VMAXPS,
+  VMINPD,
+  VMINPS,
+  VMOVAPD,
+  VMOVAPS,
+  VMOVDQA,
+  VMOVDQA32,
+  VMOVDQA64,
+  VMOVDQU,
+  VMOVDQU32,
+  VMOVDQU64,
+  VMOVUPD,
+  VMOVUPS,
+  VMULPD,
+  VMULPS,
+  VMULSD,
+  VMULSS,
+  VORPD,
+  VORPS,
+  VPADDB,
+  VPADDD,
+  VPADDQ,
+  VPADDW,
+  VPAND,
+  VPANDD,
+  VPANDN,
+  VPANDND,
+  VPANDNQ,
+  VPANDQ,
+  VPBROADCASTD,
+  VPBROADCASTQ,
+  VPCMOV,
+  VPCMPEQB,
+  VPCMPEQD,
+  VPCMPEQQ,
+  VPCMPEQW,
+  VPERM2F128,
+  VPERM2I128,
+  VPERMPD,
+  VPERMQ,
+  VPMACSDD,
+  VPMACSDQH,
+  VPMACSDQL,
+  VPMACSSDD,
+  VPMACSSDQH,
+  VPMACSSDQL,
+  VPMACSSWD,
+  VPMACSSWW,
+  VPMACSWD,
+  VPMACSWW,
+  VPMULLD,
+  VPOR,
+  VPORD,
+  VPORQ,
+  VPPERM,
+  VPROTB,
+  VPROTD,
+  VPROTQ,
+  VPROTW,
+  VPSHAB,
+  VPSHAD,
+  VPSHAQ,
+  VPSHAW,
+  VPSLLVD,
+  VPSLLVQ,
+  VPSRAVD,
+  VPSRLVD,
+  VPSRLVQ,
+  VPSUBB,
+  VPSUBD,
+  VPSUBQ,
+  VPSUBW,
+  VPTERNLOGD,
+  VPTERNLOGQ,
+  VPXOR,
+  VPXORD,
+  VPXORQ,
+  VSQRTPD,
+  VSQRTPS,
+  VSUBPD,
+  VSUBPS,
+  VSUBSD,
+  VSUBSS,
+  VXORPD,
+  VXORPS,
+  VZEROALL,
+  VZEROUPPER,
   WAIT,
+  WRMSR,
   XCHG,
+  XADD,
   XLAT,
   XOR,
+  XORPD,
+  XORPS,
+}
+
+//  the embedded rounding overrides the rounding mode of the MXCSR register and suppresses all exceptions
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum RoundingControl
+{
+  Nearest,
+  Down,
+  Up,
+  Zero,
+}
+
+//  every EVEX encoded instruction takes an opmask, but not every instruction takes the other decorations
+#[derive(Clone,Copy,PartialEq)]
+pub struct EnhancedVectorOptions
+{
+  //  EVEX.W selects quad word instead of double word elements
+  pub wide:                             bool,
+  //  the size of a scalar memory operand, zero for packed instructions
+  pub memorySize:                       usize,
+  pub zeroing:                          bool,
+  pub broadcast:                        bool,
+  pub rounding:                         bool,
+}
+
+//  only packed instructions can broadcast a single element
+pub fn EnhancedVectorOptions
+(
+  wide:                                 bool,
+  memorySize:                           usize,
+  rounding:                             bool,
+) -> EnhancedVectorOptions
+{
+  EnhancedVectorOptions
+  {
+    wide,
+    memorySize,
+    zeroing:                            true,
+    broadcast:                          memorySize == 0,
+    rounding,
+  }
 }
 
 pub const AddressSizeOverride:      u8  =   0x67;
@@ -616,13 +1630,20 @@ pub const RepeatEqual:              u8  =   Repeat;
 pub const RepeatZero:               u8  =   Repeat;
 pub const RepeatNotEqual:           u8  =   0xf2;
 pub const RepeatNotZero:            u8  =   RepeatNotEqual;
-pub const SegmentOverrideCS:        u8  =   0x26;
-pub const SegmentOverrideSS:        u8  =   0x2e;
-pub const SegmentOverrideDS:        u8  =   0x36;
-pub const SegmentOverrideES:        u8  =   0x3e;
+pub const SegmentOverrideCS:        u8  =   0x2e;
+pub const SegmentOverrideSS:        u8  =   0x36;
+pub const SegmentOverrideDS:        u8  =   0x3e;
+pub const SegmentOverrideES:        u8  =   0x26;
 pub const SegmentOverrideFS:        u8  =   0x64;
 pub const SegmentOverrideGS:        u8  =   0x65;
+pub const REX:                      u8  =   0x40;
+pub const REXW:                     u8  =   0x08;
+pub const REXR:                     u8  =   0x04;
+pub const REXX:                     u8  =   0x02;
+pub const REXB:                     u8  =   0x01;
 pub const ThreeByteXOP:             u8  =   0x8f;
 pub const TwoByteVEX:               u8  =   0xc5;
 pub const ThreeByteVEX:             u8  =   0xc4;
-pub const TwoByteOpcode:            u8  =   0x0f;
\ No newline at end of file
+pub const FourByteEVEX:             u8  =   0x62;
+pub const TwoByteOpcode:            u8  =   0x0f;
+pub const Wait:                     u8  =   0x9b;
\ No newline at end of file
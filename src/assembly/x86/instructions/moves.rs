@@ -0,0 +1,435 @@
+use super::
+{
+  Instruction,
+  InstructionType,
+  super::
+  {
+    AssemblyFeatures,
+    InstructionSet,
+    X86,
+    memory::
+    {
+      Memory16Registers,
+    },
+    operands::
+    {
+      Operand,
+      OperandType,
+    },
+    registers::
+    {
+      SegmentRegisterNumber,
+    },
+  },
+};
+
+use rand;
+
+macro_rules! theInstruction
+{
+  (
+    $theName:ident,
+    $theInstruction:expr
+  )
+  =>  {
+        pub fn $theName
+        (
+          mut self,
+          dst:                          impl Operand,
+          src:                          impl Operand,
+        ) -> Self
+        {
+          let ( dstThis, dstSize )      =   dst.this();
+          let ( srcThis, srcSize )      =   src.this();
+          let size                      =   ( dstSize | srcSize ) as usize;
+          self.instructions.push
+          (
+            Instruction
+            (
+              self.line,
+              self.features,
+              size,
+              $theInstruction,
+              vec!  ( dstThis,  srcThis ),
+            )
+          );
+          self.line                     +=  1;
+          self
+        }
+      }
+}
+
+impl X86
+{
+  theInstruction! ( mov,    InstructionType::MOV    );
+  theInstruction! ( movsx,  InstructionType::MOVSX  );
+  theInstruction! ( movzx,  InstructionType::MOVZX  );
+}
+
+impl  Instruction
+{
+  fn encodeSegmentRegister
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    register:                           SegmentRegisterNumber,
+    memory:                             &OperandType,
+  ) -> Result<u8, String>
+  {
+    if  ( register == SegmentRegisterNumber::FS || register == SegmentRegisterNumber::GS )
+    &&  architecture < InstructionSet::i386
+    {
+      self.fail
+      (
+        format!
+        (
+          "Segment Register ›{}‹ Requires at least i386",
+          register.to_string(),
+        )
+      )
+    }
+    else
+    {
+      match ( memory, self.size )
+      {
+        ( OperandType::Memory16               { .. }, 2 ) |
+        ( OperandType::Memory32               { .. }, 2 ) |
+        ( OperandType::GeneralPurposeRegister { .. }, 2 )
+        =>  Ok  ( register as u8  ),
+        ( OperandType::GeneralPurposeRegister { .. }, 4 )
+        if architecture >= InstructionSet::i386
+        =>  Ok  ( register as u8  ),
+        ( _,                                          _ )
+        =>  self.failOperandSize(),
+      }
+    }
+  }
+
+  fn encodeSpecialRegister
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    operandSize:                        usize,
+    register:                           &OperandType,
+  ) -> Result<( u8, u8 ), String>
+  {
+    //  opcode for moving from the special register, | 2 for moving into it, and the reg field
+    let ( opcode, number, available )   =   match register
+                                            {
+                                              OperandType::ControlRegister  ( number  )
+                                              =>  ( 0x20, *number,  match number
+                                                                    {
+                                                                      0 | 2 | 3 =>  architecture >= InstructionSet::i386,
+                                                                      4         =>  architecture >= InstructionSet::Pentium,
+                                                                      _         =>  false,
+                                                                    } ),
+                                              OperandType::DebugRegister    ( number  )
+                                              =>  ( 0x21, *number,  match number
+                                                                    {
+                                                                      0 ..= 3 | 6 | 7
+                                                                                =>  architecture >= InstructionSet::i386,
+                                                                      _         =>  false,
+                                                                    } ),
+                                              OperandType::TestRegister     ( number  )
+                                              =>  ( 0x24, *number,  match number
+                                                                    {
+                                                                      //  test registers were removed with the Pentium
+                                                                      3 ..= 5   =>  architecture == InstructionSet::i486,
+                                                                      6 | 7     =>  architecture >= InstructionSet::i386
+                                                                                &&  architecture <= InstructionSet::i486,
+                                                                      _         =>  false,
+                                                                    } ),
+                                              _
+                                              =>  return self.fail ( "Control, Debug or Test Register Expected".to_string() ),
+                                            };
+    if !available
+    {
+      self.fail
+      (
+        format!
+        (
+          "Register ›{}‹ is not Available on {}",
+          register.to_string  ( 4 ),
+          InstructionSet  ( architecture  ),
+        )
+      )
+    }
+    //  in 64 bit mode, the special registers are moved as quad words without REX.W
+    else if self.size != if operandSize == 64 { 8 } else { 4 }
+    {
+      self.failOperandSize()
+    }
+    else
+    {
+      Ok  ( ( opcode, number ) )
+    }
+  }
+
+  fn encodeDirectAddress
+  (
+    &mut self,
+    addressSize:                        usize,
+    segment:                            SegmentRegisterNumber,
+    displacement:                       i128,
+  ) -> Result<(), String>
+  {
+    //  the memory offset would be 64 bit wide
+    if addressSize == 64
+    {
+      return self.fail ( "16 Bit Addressing is not Available in 64 Bit Mode".to_string() );
+    }
+    self.encodeSegment  ( segment,  SegmentRegisterNumber::DS )?;
+    self.hazAddressSizeOverride         =   addressSize ==  32;
+    if ( -0x8000 ..= 0xffff ).contains ( &displacement )
+    {
+      self.setDisplacement  ( 2,  displacement  );
+      Ok  ( () )
+    }
+    else
+    {
+      self.failOutOfBounds
+      (
+        -0x8000,
+        0xffff,
+        displacement,
+      )
+    }
+  }
+
+  pub fn compileMoveInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    operandSize:                        usize,
+    addressSize:                        usize,
+  ) -> Result<Option<usize>, String>
+  {
+    if self.operands.len() == 2
+    {
+      let dst                           =   self.operands [ 0 ].clone();
+      let src                           =   self.operands [ 1 ].clone();
+      match ( &dst, &src )
+      {
+        (
+          OperandType::SegmentRegister        ( SegmentRegisterNumber::CS                                                           ),
+          _
+        )
+        if !( architecture == InstructionSet::i8086 && self.features.hazFeature ( AssemblyFeatures::X86UndocumentedOpcodes ) )
+        =>  self.fail ( "Cannot Move into Code Segment Register".to_string() )?,
+        (
+          OperandType::SegmentRegister        ( dstRegister                                                                         ),
+          OperandType::GeneralPurposeRegister { .. } | OperandType::Memory16 { .. } | OperandType::Memory32 { .. }
+        )
+        =>  {
+              let regField              =   self.encodeSegmentRegister ( architecture, *dstRegister, &src  )?;
+              self.setOpcode                ( 0x8e                                                            );
+              self.encodeModRegRMdata       ( addressSize,  regField, &src                                    )?;
+            },
+        (
+          OperandType::GeneralPurposeRegister { .. } | OperandType::Memory16 { .. } | OperandType::Memory32 { .. },
+          OperandType::SegmentRegister        ( srcRegister                                                                         )
+        )
+        =>  {
+              let regField              =   self.encodeSegmentRegister ( architecture, *srcRegister, &dst  )?;
+              if let OperandType::GeneralPurposeRegister { .. } = dst
+              {
+                self.encodeOperandSize      ( architecture, operandSize                                       )?;
+              }
+              self.setOpcode                ( 0x8c                                                            );
+              self.encodeModRegRMdata       ( addressSize,  regField, &dst                                    )?;
+            },
+        (
+          OperandType::GeneralPurposeRegister { .. },
+          OperandType::ControlRegister ( _ ) | OperandType::DebugRegister ( _ ) | OperandType::TestRegister ( _ )
+        )
+        =>  {
+              let ( opcode, regField )  =   self.encodeSpecialRegister ( architecture, operandSize,  &src      )?;
+              self.setTwoByteOpcode         ( true                                                            );
+              self.setOpcode                ( opcode                                                          );
+              self.encodeModRegRMdata       ( addressSize,  regField, &dst                                    )?;
+            },
+        (
+          OperandType::ControlRegister ( _ ) | OperandType::DebugRegister ( _ ) | OperandType::TestRegister ( _ ),
+          OperandType::GeneralPurposeRegister { .. }
+        )
+        =>  {
+              let ( opcode, regField )  =   self.encodeSpecialRegister ( architecture, operandSize,  &dst      )?;
+              self.setTwoByteOpcode         ( true                                                            );
+              self.setOpcode                ( opcode  | 2                                                     );
+              self.encodeModRegRMdata       ( addressSize,  regField, &src                                    )?;
+            },
+        (
+          OperandType::GeneralPurposeRegister { number:   0,                                                    .. },
+          OperandType::Memory16               { segment,  registers:  Memory16Registers::DISP,  displacement       }
+        )
+        if !( self.features.hazFeature ( AssemblyFeatures::RandomOpcodeSize ) && rand::random() )
+        =>  {
+              self.encodeOperandSize        ( architecture, operandSize                                       )?;
+              self.setOpcode                ( if self.size == 1 { 0xa0 } else { 0xa1 }                        );
+              self.encodeDirectAddress      ( addressSize,  *segment, *displacement                           )?;
+            },
+        (
+          OperandType::Memory16               { segment,  registers:  Memory16Registers::DISP,  displacement       },
+          OperandType::GeneralPurposeRegister { number:   0,                                                    .. }
+        )
+        if !( self.features.hazFeature ( AssemblyFeatures::RandomOpcodeSize ) && rand::random() )
+        =>  {
+              self.encodeOperandSize        ( architecture, operandSize                                       )?;
+              self.setOpcode                ( if self.size == 1 { 0xa2 } else { 0xa3 }                        );
+              self.encodeDirectAddress      ( addressSize,  *segment, *displacement                           )?;
+            },
+        (
+          OperandType::GeneralPurposeRegister { number:   dstRegister,                                          .. },
+          OperandType::GeneralPurposeRegister { number:   srcRegister,                                          .. }
+        )
+        =>  {
+              self.encodeOperandSize        ( architecture, operandSize                                       )?;
+              let width                 =   if self.size == 1 { 0 } else { 1 };
+              if  self.features.hazFeature ( AssemblyFeatures::RandomOpcode )
+              &&  rand::random()
+              {
+                self.setOpcode              ( 0x8a | width                                                    );
+                self.encodeModRegRMdata     ( addressSize,  *dstRegister, &src                                )?;
+              }
+              else
+              {
+                self.setOpcode              ( 0x88 | width                                                    );
+                self.encodeModRegRMdata     ( addressSize,  *srcRegister, &dst                                )?;
+              }
+            },
+        (
+          OperandType::Memory16               { .. } | OperandType::Memory32 { .. },
+          OperandType::GeneralPurposeRegister { number:   srcRegister,                                          .. }
+        )
+        =>  {
+              self.encodeOperandSize        ( architecture, operandSize                                       )?;
+              self.setOpcode                ( if self.size == 1 { 0x88 } else { 0x89 }                        );
+              self.encodeModRegRMdata       ( addressSize,  *srcRegister, &dst                                )?;
+            },
+        (
+          OperandType::GeneralPurposeRegister { number:   dstRegister,                                          .. },
+          OperandType::Memory16               { .. } | OperandType::Memory32 { .. }
+        )
+        =>  {
+              self.encodeOperandSize        ( architecture, operandSize                                       )?;
+              self.setOpcode                ( if self.size == 1 { 0x8a } else { 0x8b }                        );
+              self.encodeModRegRMdata       ( addressSize,  *dstRegister, &src                                )?;
+            },
+        (
+          OperandType::GeneralPurposeRegister { number:   dstRegister,                                          .. },
+          OperandType::Constant               ( immediate                                                                           )
+        )
+        =>  {
+              self.encodeOperandSize        ( architecture, operandSize                                       )?;
+              if  self.size   ==  8
+              &&  (
+                    *immediate < -0x80000000
+                  ||
+                    *immediate >  0x7fffffff
+                  )
+              {
+                //  only 0xb8+r takes a full 64 bit immediate, everything else is sign extended from 32 bit
+                if  *immediate < -0x8000000000000000
+                ||  *immediate >  0xffffffffffffffff
+                {
+                  self.failOutOfBounds      ( -0x8000000000000000,  0xffffffffffffffff, *immediate            )?;
+                }
+                self.encodeOpcodeRegister   ( 0xb8,         *dstRegister                                      );
+                self.setImmediate           ( 8,            *immediate                                        );
+              }
+              else
+              {
+                if  self.size ==  8
+                ||  (
+                      self.features.hazFeature ( AssemblyFeatures::RandomOpcodeSize )
+                    &&
+                      rand::random()
+                    )
+                {
+                  //  0xc6/0xc7 /0 is one byte longer, but equivalent, and sign extends to 64 bit
+                  self.setOpcode            ( if self.size == 1 { 0xc6 } else { 0xc7 }                        );
+                  self.encodeModRegRMdata   ( addressSize,  0,            &dst                                )?;
+                }
+                else
+                {
+                  self.encodeOpcodeRegister ( if self.size == 1 { 0xb0 } else { 0xb8 },     *dstRegister      );
+                }
+                self.encodeImmediate        ( self.size,    *immediate                                        )?;
+              }
+            },
+        (
+          OperandType::Memory16               { .. } | OperandType::Memory32 { .. },
+          OperandType::Constant               ( immediate                                                                           )
+        )
+        =>  {
+              self.encodeOperandSize        ( architecture, operandSize                                       )?;
+              self.setOpcode                ( if self.size == 1 { 0xc6 } else { 0xc7 }                        );
+              self.encodeModRegRMdata       ( addressSize,  0,            &dst                                )?;
+              self.encodeImmediate          ( self.size,    *immediate                                        )?;
+            },
+        ( _, _ )
+        =>  self.fail
+            (
+              format!
+              (
+                "Invalid Combination of Arguments ›{}‹, ›{}‹",
+                dst.to_string  ( self.size  ),
+                src.to_string  ( self.size  ),
+              )
+            )?,
+      }
+      Ok  ( Some  ( self.getLength() ) )
+    }
+    else
+    {
+      self.fail
+      (
+        format!
+        (
+          "Instruction Must Take Exactly Two Arguments, got {}",
+          self.operands.len(),
+        )
+      )
+    }
+  }
+
+  pub fn compileMoveExtendedInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    operandSize:                        usize,
+    addressSize:                        usize,
+    opcode:                             u8,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet              ( architecture, InstructionSet::i386                              )?;
+    match self.operands.clone().as_slice()
+    {
+      [
+        OperandType::GeneralPurposeRegister { number:   dstRegister,  .. },
+        src @ OperandType::GeneralPurposeRegister { .. } |
+        src @ OperandType::Memory16               { .. } |
+        src @ OperandType::Memory32               { .. }
+      ]
+      =>  {
+            //  the size is the combination of both operand sizes, the destination is always larger
+            let ( dstSize,  width )     =   match self.size
+                                            {
+                                              3 =>  ( 2,  0 ),
+                                              5 =>  ( 4,  0 ),
+                                              6 =>  ( 4,  1 ),
+                                              9 =>  ( 8,  0 ),
+                                              10  =>  ( 8,  1 ),
+                                              _ =>  return self.failOperandSize(),
+                                            };
+            self.encodeOperandSizeOf        ( architecture, operandSize,  dstSize                             )?;
+            self.setTwoByteOpcode           ( true                                                            );
+            self.setOpcode                  ( opcode  | width                                                 );
+            self.encodeModRegRMdata         ( addressSize,  *dstRegister, src                                 )?;
+            Ok  ( Some  ( self.getLength() ) )
+          },
+      _
+      =>  self.fail ( "Instruction Must Take a Register and a Register or Memory Argument".to_string() ),
+    }
+  }
+}
@@ -0,0 +1,352 @@
+use super::
+{
+  Instruction,
+  InstructionType,
+  super::
+  {
+    AssemblyFeatures,
+    InstructionSet,
+    X86,
+    operands::
+    {
+      Operand,
+      OperandType,
+    },
+  },
+};
+
+use rand;
+
+macro_rules! theInstruction
+{
+  (
+    $theName:ident,
+    $theInstruction:expr
+  )
+  =>  {
+        pub fn $theName
+        (
+          mut self,
+          dst:                          impl Operand,
+          src:                          impl Operand,
+        ) -> Self
+        {
+          let ( dstThis, dstSize )      =   dst.this();
+          let ( srcThis, srcSize )      =   src.this();
+          let size                      =   ( dstSize | srcSize ) as usize;
+          self.instructions.push
+          (
+            Instruction
+            (
+              self.line,
+              self.features,
+              size,
+              $theInstruction,
+              vec!  ( dstThis,  srcThis ),
+            )
+          );
+          self.line                     +=  1;
+          self
+        }
+      }
+}
+
+impl X86
+{
+  theInstruction! ( movd,      InstructionType::MOVD      );
+  theInstruction! ( movq,      InstructionType::MOVQ      );
+  theInstruction! ( packssdw,  InstructionType::PACKSSDW  );
+  theInstruction! ( packsswb,  InstructionType::PACKSSWB  );
+  theInstruction! ( packuswb,  InstructionType::PACKUSWB  );
+  theInstruction! ( paddb,     InstructionType::PADDB     );
+  theInstruction! ( paddd,     InstructionType::PADDD     );
+  theInstruction! ( paddsb,    InstructionType::PADDSB    );
+  theInstruction! ( paddsw,    InstructionType::PADDSW    );
+  theInstruction! ( paddusb,   InstructionType::PADDUSB   );
+  theInstruction! ( paddusw,   InstructionType::PADDUSW   );
+  theInstruction! ( paddw,     InstructionType::PADDW     );
+  theInstruction! ( pand,      InstructionType::PAND      );
+  theInstruction! ( pandn,     InstructionType::PANDN     );
+  theInstruction! ( pcmpeqb,   InstructionType::PCMPEQB   );
+  theInstruction! ( pcmpeqd,   InstructionType::PCMPEQD   );
+  theInstruction! ( pcmpeqw,   InstructionType::PCMPEQW   );
+  theInstruction! ( pcmpgtb,   InstructionType::PCMPGTB   );
+  theInstruction! ( pcmpgtd,   InstructionType::PCMPGTD   );
+  theInstruction! ( pcmpgtw,   InstructionType::PCMPGTW   );
+  theInstruction! ( pmaddwd,   InstructionType::PMADDWD   );
+  theInstruction! ( pmulhw,    InstructionType::PMULHW    );
+  theInstruction! ( pmullw,    InstructionType::PMULLW    );
+  theInstruction! ( por,       InstructionType::POR       );
+  theInstruction! ( pslld,     InstructionType::PSLLD     );
+  theInstruction! ( psllq,     InstructionType::PSLLQ     );
+  theInstruction! ( psllw,     InstructionType::PSLLW     );
+  theInstruction! ( psrad,     InstructionType::PSRAD     );
+  theInstruction! ( psraw,     InstructionType::PSRAW     );
+  theInstruction! ( psrld,     InstructionType::PSRLD     );
+  theInstruction! ( psrlq,     InstructionType::PSRLQ     );
+  theInstruction! ( psrlw,     InstructionType::PSRLW     );
+  theInstruction! ( psubb,     InstructionType::PSUBB     );
+  theInstruction! ( psubd,     InstructionType::PSUBD     );
+  theInstruction! ( psubsb,    InstructionType::PSUBSB    );
+  theInstruction! ( psubsw,    InstructionType::PSUBSW    );
+  theInstruction! ( psubusb,   InstructionType::PSUBUSB   );
+  theInstruction! ( psubusw,   InstructionType::PSUBUSW   );
+  theInstruction! ( psubw,     InstructionType::PSUBW     );
+  theInstruction! ( punpckhbw, InstructionType::PUNPCKHBW );
+  theInstruction! ( punpckhdq, InstructionType::PUNPCKHDQ );
+  theInstruction! ( punpckhwd, InstructionType::PUNPCKHWD );
+  theInstruction! ( punpcklbw, InstructionType::PUNPCKLBW );
+  theInstruction! ( punpckldq, InstructionType::PUNPCKLDQ );
+  theInstruction! ( punpcklwd, InstructionType::PUNPCKLWD );
+  theInstruction! ( pxor,      InstructionType::PXOR      );
+}
+
+impl  Instruction
+{
+  //  SSE2 extended the MMX instructions to XMM registers, selected by the mandatory prefix 0x66
+  fn encodeMultiMediaSize
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+  ) -> Result<(), String>
+  {
+    match self.size
+    {
+      8
+      =>  Ok  ( () ),
+      16
+      =>  {
+            self.requireInstructionSet      ( architecture, InstructionSet::Pentium4                          )?;
+            self.setMandatoryPrefix         ( 0x66                                                            );
+            Ok  ( () )
+          },
+      _
+      =>  self.failOperandSize(),
+    }
+  }
+
+  //  the Pentium with MMX is not distinguished from the Pentium, therefore MMX requires at least the Pentium II
+  pub fn compileMultiMediaInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+    opcode:                             u8,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet              ( architecture, InstructionSet::Pentium2                          )?;
+    match self.operands.clone().as_slice()
+    {
+      [
+        OperandType::MulitMediaRegister     ( dstRegister ),
+        src @ OperandType::MulitMediaRegister     ( _   ) |
+        src @ OperandType::Memory16               { .. } |
+        src @ OperandType::Memory32               { .. }
+      ]
+      =>  {
+            self.encodeMultiMediaSize       ( architecture                                                    )?;
+            self.setTwoByteOpcode           ( true                                                            );
+            self.setOpcode                  ( opcode                                                          );
+            self.encodeModRegRMdata         ( addressSize,  *dstRegister, src                                 )?;
+            Ok  ( Some  ( self.getLength() ) )
+          },
+      _
+      =>  self.fail ( "Instruction Must Take a MMX or XMM Register and a Register or Memory Argument".to_string() ),
+    }
+  }
+
+  pub fn compileMultiMediaShiftInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+    opcode:                             u8,
+    immediateOpcode:                    u8,
+    regField:                           u8,
+  ) -> Result<Option<usize>, String>
+  {
+    match self.operands.clone().as_slice()
+    {
+      [
+        dst @ OperandType::MulitMediaRegister     ( _   ),
+        OperandType::Constant               ( immediate )
+      ]
+      =>  {
+            self.requireInstructionSet      ( architecture, InstructionSet::Pentium2                          )?;
+            self.encodeMultiMediaSize       ( architecture                                                    )?;
+            self.setTwoByteOpcode           ( true                                                            );
+            self.setOpcode                  ( immediateOpcode                                                 );
+            self.encodeModRegRMdata         ( addressSize,  regField,     dst                                 )?;
+            if  *immediate >= 0
+            &&  *immediate <= 0xff
+            {
+              self.setImmediate             ( 1,            *immediate                                        );
+              Ok  ( Some  ( self.getLength() ) )
+            }
+            else
+            {
+              self.failOutOfBounds          ( 0,            0xff,         *immediate                          )
+            }
+          },
+      _
+      =>  self.compileMultiMediaInstruction ( architecture, addressSize,  opcode                              ),
+    }
+  }
+
+  pub fn compileMoveDoublewordInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet              ( architecture, InstructionSet::Pentium2                          )?;
+    //  one operand is a MMX register, the other a double word
+    let ( opcode, regField, operand )   =   match self.operands.clone().as_slice()
+                                            {
+                                              [
+                                                OperandType::MulitMediaRegister     ( dstRegister ),
+                                                src @ OperandType::GeneralPurposeRegister { .. } |
+                                                src @ OperandType::Memory16               { .. } |
+                                                src @ OperandType::Memory32               { .. }
+                                              ]
+                                              =>  ( 0x6e, *dstRegister, src.clone() ),
+                                              [
+                                                dst @ OperandType::GeneralPurposeRegister { .. } |
+                                                dst @ OperandType::Memory16               { .. } |
+                                                dst @ OperandType::Memory32               { .. },
+                                                OperandType::MulitMediaRegister     ( srcRegister )
+                                              ]
+                                              =>  ( 0x7e, *srcRegister, dst.clone() ),
+                                              _
+                                              =>  return self.fail ( "Instruction Must Take a MMX or XMM Register and a Register or Memory Argument".to_string() ),
+                                            };
+    if self.size == 16 | 4
+    {
+      self.requireInstructionSet            ( architecture, InstructionSet::Pentium4                          )?;
+      self.setMandatoryPrefix               ( 0x66                                                            );
+    }
+    if  self.size == 8  | 4
+    ||  self.size == 16 | 4
+    {
+      self.setTwoByteOpcode                 ( true                                                            );
+      self.setOpcode                        ( opcode                                                          );
+      self.encodeModRegRMdata               ( addressSize,  regField,     &operand                            )?;
+      Ok  ( Some  ( self.getLength() ) )
+    }
+    else
+    {
+      self.failOperandSize()
+    }
+  }
+
+  pub fn compileMoveQuadwordInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+  ) -> Result<Option<usize>, String>
+  {
+    match self.operands.clone().as_slice()
+    {
+      //  for XMM registers, 0xf3 0x0f 0x7e loads and 0x66 0x0f 0xd6 stores, the upper half is cleared
+      [
+        dst @ OperandType::MulitMediaRegister     ( dstRegister ),
+        src @ OperandType::MulitMediaRegister     ( srcRegister )
+      ]
+      if self.size & 16 != 0
+      =>  {
+            self.requireInstructionSet      ( architecture, InstructionSet::Pentium4                          )?;
+            if self.size != 16
+            {
+              self.failOperandSize          (                                                                 )?;
+            }
+            self.setTwoByteOpcode           ( true                                                            );
+            if  self.features.hazFeature ( AssemblyFeatures::RandomOpcode )
+            &&  rand::random()
+            {
+              self.setMandatoryPrefix       ( 0x66                                                            );
+              self.setOpcode                ( 0xd6                                                            );
+              self.encodeModRegRMdata       ( addressSize,  *srcRegister, dst                                 )?;
+            }
+            else
+            {
+              self.setMandatoryPrefix       ( 0xf3                                                            );
+              self.setOpcode                ( 0x7e                                                            );
+              self.encodeModRegRMdata       ( addressSize,  *dstRegister, src                                 )?;
+            }
+            Ok  ( Some  ( self.getLength() ) )
+          },
+      [
+        OperandType::MulitMediaRegister     ( dstRegister ),
+        src @ OperandType::Memory16               { .. } |
+        src @ OperandType::Memory32               { .. }
+      ]
+      if self.size & 16 != 0
+      =>  {
+            self.requireInstructionSet      ( architecture, InstructionSet::Pentium4                          )?;
+            if  self.size != 16
+            &&  self.size != 16 | 8
+            {
+              self.failOperandSize          (                                                                 )?;
+            }
+            self.setMandatoryPrefix         ( 0xf3                                                            );
+            self.setTwoByteOpcode           ( true                                                            );
+            self.setOpcode                  ( 0x7e                                                            );
+            self.encodeModRegRMdata         ( addressSize,  *dstRegister, src                                 )?;
+            Ok  ( Some  ( self.getLength() ) )
+          },
+      [
+        dst @ OperandType::Memory16               { .. } |
+        dst @ OperandType::Memory32               { .. },
+        OperandType::MulitMediaRegister     ( srcRegister )
+      ]
+      if self.size & 16 != 0
+      =>  {
+            self.requireInstructionSet      ( architecture, InstructionSet::Pentium4                          )?;
+            if  self.size != 16
+            &&  self.size != 16 | 8
+            {
+              self.failOperandSize          (                                                                 )?;
+            }
+            self.setMandatoryPrefix         ( 0x66                                                            );
+            self.setTwoByteOpcode           ( true                                                            );
+            self.setOpcode                  ( 0xd6                                                            );
+            self.encodeModRegRMdata         ( addressSize,  *srcRegister, dst                                 )?;
+            Ok  ( Some  ( self.getLength() ) )
+          },
+      [
+        dst @ OperandType::MulitMediaRegister     ( _   ),
+        OperandType::MulitMediaRegister     ( srcRegister )
+      ]
+      if  self.features.hazFeature ( AssemblyFeatures::RandomOpcode )
+      &&  rand::random()
+      =>  {
+            //  0x0f 0x7f is the store form, but works with two registers as well
+            self.requireInstructionSet      ( architecture, InstructionSet::Pentium2                          )?;
+            self.setTwoByteOpcode           ( true                                                            );
+            self.setOpcode                  ( 0x7f                                                            );
+            self.encodeModRegRMdata         ( addressSize,  *srcRegister, dst                                 )?;
+            Ok  ( Some  ( self.getLength() ) )
+          },
+      [
+        dst @ OperandType::Memory16               { .. } |
+        dst @ OperandType::Memory32               { .. },
+        OperandType::MulitMediaRegister     ( srcRegister )
+      ]
+      =>  {
+            self.requireInstructionSet      ( architecture, InstructionSet::Pentium2                          )?;
+            if self.size != 8
+            {
+              self.failOperandSize          (                                                                 )?;
+            }
+            self.setTwoByteOpcode           ( true                                                            );
+            self.setOpcode                  ( 0x7f                                                            );
+            self.encodeModRegRMdata         ( addressSize,  *srcRegister, dst                                 )?;
+            Ok  ( Some  ( self.getLength() ) )
+          },
+      _
+      =>  self.compileMultiMediaInstruction ( architecture, addressSize,  0x6f                                ),
+    }
+  }
+}
@@ -0,0 +1,146 @@
+use super::
+{
+  Instruction,
+  InstructionType,
+  super::
+  {
+    InstructionSet,
+    X86,
+    operands::
+    {
+      Operand,
+      OperandType,
+    },
+  },
+};
+
+impl X86
+{
+  //  in is a keyword
+  pub fn inn
+  (
+    mut self,
+    accumulator:                        impl Operand,
+    port:                               impl Operand,
+  ) -> Self
+  {
+    let ( accumulatorThis,  size  )     =   accumulator.this();
+    let ( portThis,         _     )     =   port.this();
+    self.instructions.push
+    (
+      Instruction
+      (
+        self.line,
+        self.features,
+        size,
+        InstructionType::IN,
+        vec!  ( accumulatorThis,  portThis  ),
+      )
+    );
+    self.line                           +=  1;
+    self
+  }
+
+  pub fn out
+  (
+    mut self,
+    port:                               impl Operand,
+    accumulator:                        impl Operand,
+  ) -> Self
+  {
+    let ( portThis,         _     )     =   port.this();
+    let ( accumulatorThis,  size  )     =   accumulator.this();
+    self.instructions.push
+    (
+      Instruction
+      (
+        self.line,
+        self.features,
+        size,
+        InstructionType::OUT,
+        vec!  ( portThis,         accumulatorThis ),
+      )
+    );
+    self.line                           +=  1;
+    self
+  }
+}
+
+impl  Instruction
+{
+  pub fn compilePortInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    operandSize:                        usize,
+    opcode:                             u8,
+  ) -> Result<Option<usize>, String>
+  {
+    if self.operands.len() == 2
+    {
+      //  in takes the accumulator first, out takes the port first
+      let ( accumulator, port )         =   if opcode == 0xe4
+                                            {
+                                              ( self.operands [ 0 ].clone(),  self.operands [ 1 ].clone() )
+                                            }
+                                            else
+                                            {
+                                              ( self.operands [ 1 ].clone(),  self.operands [ 0 ].clone() )
+                                            };
+      if let OperandType::GeneralPurposeRegister { rex: false, number: 0, .. } = accumulator
+      {
+        self.encodeOperandSize              ( architecture, operandSize                                       )?;
+        let width                       =   if self.size == 1 { 0 } else { 1 };
+        match port
+        {
+          OperandType::Constant               ( number    )
+          =>  {
+                if ( 0 ..= 0xff ).contains ( &number )
+                {
+                  self.setOpcode            ( opcode  | width                                                 );
+                  self.setImmediate         ( 1,            number                                            );
+                }
+                else
+                {
+                  self.failOutOfBounds      ( 0,            0xff,         number                              )?;
+                }
+              },
+          OperandType::GeneralPurposeRegister { rex:  false,  number: 2,  size: 2 }
+          =>  self.setOpcode                ( opcode  | 0x08  | width                                         ),
+          _
+          =>  self.fail
+              (
+                format!
+                (
+                  "Port Must be dx or a Constant, got ›{}‹",
+                  port.to_string  ( 2 ),
+                )
+              )?,
+        }
+        Ok  ( Some  ( self.getLength() ) )
+      }
+      else
+      {
+        self.fail
+        (
+          format!
+          (
+            "Expected Accumulator, got ›{}‹",
+            accumulator.to_string ( self.size ),
+          )
+        )
+      }
+    }
+    else
+    {
+      self.fail
+      (
+        format!
+        (
+          "Instruction Must Take Exactly Two Arguments, got {}",
+          self.operands.len(),
+        )
+      )
+    }
+  }
+}
@@ -0,0 +1,103 @@
+use super::
+{
+  Instruction,
+  InstructionType,
+  super::
+  {
+    InstructionSet,
+    X86,
+    operands::
+    {
+      Operand,
+      OperandType,
+    },
+  },
+};
+
+macro_rules! theInstruction
+{
+  (
+    $theName:ident,
+    $theInstruction:expr
+  )
+  =>  {
+        pub fn $theName
+        (
+          mut self,
+          operand:                      impl Operand,
+        ) -> Self
+        {
+          let ( operandThis, operandSize )
+                                        =   operand.this();
+          self.instructions.push
+          (
+            Instruction
+            (
+              self.line,
+              self.features,
+              operandSize,
+              $theInstruction,
+              vec!  ( operandThis ),
+            )
+          );
+          self.line                     +=  1;
+          self
+        }
+      }
+}
+
+impl X86
+{
+  theInstruction! ( setb,   InstructionType::SETB   );
+  theInstruction! ( setbe,  InstructionType::SETBE  );
+  theInstruction! ( sete,   InstructionType::SETE   );
+  theInstruction! ( setl,   InstructionType::SETL   );
+  theInstruction! ( setle,  InstructionType::SETLE  );
+  theInstruction! ( setnb,  InstructionType::SETNB  );
+  theInstruction! ( setnbe, InstructionType::SETNBE );
+  theInstruction! ( setne,  InstructionType::SETNE  );
+  theInstruction! ( setnl,  InstructionType::SETNL  );
+  theInstruction! ( setnle, InstructionType::SETNLE );
+  theInstruction! ( setno,  InstructionType::SETNO  );
+  theInstruction! ( setnp,  InstructionType::SETNP  );
+  theInstruction! ( setns,  InstructionType::SETNS  );
+  theInstruction! ( seto,   InstructionType::SETO   );
+  theInstruction! ( setp,   InstructionType::SETP   );
+  theInstruction! ( sets,   InstructionType::SETS   );
+  theInstruction! ( setz,   InstructionType::SETE   );
+}
+
+impl  Instruction
+{
+  pub fn compileSetConditionInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+    condition:                          u8,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet              ( architecture, InstructionSet::i386                              )?;
+    match self.operands.clone().as_slice()
+    {
+      [
+        dst @ OperandType::GeneralPurposeRegister { .. } |
+        dst @ OperandType::Memory16               { .. } |
+        dst @ OperandType::Memory32               { .. }
+      ]
+      =>  if self.size == 1
+          {
+            self.setTwoByteOpcode           ( true                                                            );
+            self.setOpcode                  ( 0x90  | condition                                               );
+            self.encodeModRegRMdata         ( addressSize,  0,            dst                                 )?;
+            Ok  ( Some  ( self.getLength() ) )
+          }
+          else
+          {
+            self.failOperandSize()
+          },
+      _
+      =>  self.fail ( "Instruction Must Take a Register or Memory Argument".to_string() ),
+    }
+  }
+}
@@ -0,0 +1,250 @@
+use super::
+{
+  Instruction,
+  InstructionType,
+  super::
+  {
+    AssemblyFeatures,
+    InstructionSet,
+    X86,
+    operands::
+    {
+      Operand,
+      OperandType,
+    },
+  },
+};
+
+use rand;
+
+macro_rules! theInstruction
+{
+  (
+    $theName:ident,
+    $theInstruction:expr
+  )
+  =>  {
+        pub fn $theName
+        (
+          mut self,
+          dst:                          impl Operand,
+          count:                        impl Operand,
+        ) -> Self
+        {
+          //  the count is either cl or a constant, so only the destination determines the size
+          let ( dstThis,    dstSize )   =   dst.this();
+          let ( countThis,  _       )   =   count.this();
+          self.instructions.push
+          (
+            Instruction
+            (
+              self.line,
+              self.features,
+              dstSize,
+              $theInstruction,
+              vec!  ( dstThis,  countThis ),
+            )
+          );
+          self.line                     +=  1;
+          self
+        }
+      };
+  (
+    $theName:ident,
+    $theInstruction:expr,
+    dst,
+    src,
+    count
+  )
+  =>  {
+        pub fn $theName
+        (
+          mut self,
+          dst:                          impl Operand,
+          src:                          impl Operand,
+          count:                        impl Operand,
+        ) -> Self
+        {
+          let ( dstThis,    dstSize )   =   dst.this();
+          let ( srcThis,    srcSize )   =   src.this();
+          let ( countThis,  _       )   =   count.this();
+          let size                      =   ( dstSize | srcSize ) as usize;
+          self.instructions.push
+          (
+            Instruction
+            (
+              self.line,
+              self.features,
+              size,
+              $theInstruction,
+              vec!  ( dstThis,  srcThis,  countThis ),
+            )
+          );
+          self.line                     +=  1;
+          self
+        }
+      };
+}
+
+impl X86
+{
+  theInstruction! ( rcl,  InstructionType::RCL  );
+  theInstruction! ( rcr,  InstructionType::RCR  );
+  theInstruction! ( rol,  InstructionType::ROL  );
+  theInstruction! ( ror,  InstructionType::ROR  );
+  theInstruction! ( sal,  InstructionType::SAL  );
+  theInstruction! ( sar,  InstructionType::SAR  );
+  theInstruction! ( shl,  InstructionType::SHL  );
+  theInstruction! ( shr,  InstructionType::SHR  );
+  theInstruction! ( shld, InstructionType::SHLD,  dst,  src,  count );
+  theInstruction! ( shrd, InstructionType::SHRD,  dst,  src,  count );
+}
+
+impl  Instruction
+{
+  pub fn compileShiftInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    operandSize:                        usize,
+    addressSize:                        usize,
+    regField:                           u8,
+  ) -> Result<Option<usize>, String>
+  {
+    if self.operands.len() == 2
+    {
+      let dst                           =   self.operands [ 0 ].clone();
+      let count                         =   self.operands [ 1 ].clone();
+      if let OperandType::GeneralPurposeRegister { .. } | OperandType::Memory16 { .. } | OperandType::Memory32 { .. } = dst
+      {
+        self.encodeOperandSize              ( architecture, operandSize                                       )?;
+        let width                       =   if self.size == 1 { 0 } else { 1 };
+        match count
+        {
+          OperandType::GeneralPurposeRegister { rex:  false,  number: 1,  size: 1 }
+          =>  self.setOpcode                ( 0xd2  | width                                                   ),
+          OperandType::Constant               ( 1         )
+          if  architecture < InstructionSet::i186
+          || !( self.features.hazFeature ( AssemblyFeatures::RandomOpcodeSize ) && rand::random() )
+          =>  self.setOpcode                ( 0xd0  | width                                                   ),
+          OperandType::Constant               ( immediate )
+          if architecture >= InstructionSet::i186
+          =>  {
+                if ( 0 ..= 0xff ).contains ( &immediate )
+                {
+                  self.setOpcode            ( 0xc0  | width                                                   );
+                  self.setImmediate         ( 1,            immediate                                         );
+                }
+                else
+                {
+                  self.failOutOfBounds      ( 0,            0xff,         immediate                           )?;
+                }
+              },
+          OperandType::Constant               ( immediate )
+          =>  self.fail
+              (
+                format!
+                (
+                  "Shift Count {} other than 1 Requires at least i186",
+                  immediate,
+                )
+              )?,
+          _
+          =>  self.fail
+              (
+                format!
+                (
+                  "Shift Count Must be cl or a Constant, got ›{}‹",
+                  count.to_string  ( 1  ),
+                )
+              )?,
+        }
+        self.encodeModRegRMdata             ( addressSize,  regField,     &dst                                )?;
+        Ok  ( Some  ( self.getLength() ) )
+      }
+      else
+      {
+        self.fail
+        (
+          format!
+          (
+            "Invalid Argument ›{}‹",
+            dst.to_string  ( self.size  ),
+          )
+        )
+      }
+    }
+    else
+    {
+      self.fail
+      (
+        format!
+        (
+          "Instruction Must Take Exactly Two Arguments, got {}",
+          self.operands.len(),
+        )
+      )
+    }
+  }
+
+  pub fn compileDoubleShiftInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    operandSize:                        usize,
+    addressSize:                        usize,
+    opcode:                             u8,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet              ( architecture, InstructionSet::i386                              )?;
+    match self.operands.clone().as_slice()
+    {
+      [
+        dst @ OperandType::GeneralPurposeRegister { .. } |
+        dst @ OperandType::Memory16               { .. } |
+        dst @ OperandType::Memory32               { .. },
+        OperandType::GeneralPurposeRegister { number:   srcRegister,  .. },
+        count
+      ]
+      if self.size != 1
+      =>  {
+            self.encodeOperandSize          ( architecture, operandSize                                       )?;
+            self.setTwoByteOpcode           ( true                                                            );
+            match count
+            {
+              OperandType::GeneralPurposeRegister { rex:  false,  number: 1,  size: 1 }
+              =>  self.setOpcode            ( opcode  | 1                                                     ),
+              OperandType::Constant               ( immediate )
+              =>  if ( 0 ..= 0xff ).contains ( immediate )
+                  {
+                    self.setOpcode          ( opcode                                                          );
+                    self.setImmediate       ( 1,            *immediate                                        );
+                  }
+                  else
+                  {
+                    self.failOutOfBounds    ( 0,            0xff,         *immediate                          )?;
+                  },
+              _
+              =>  self.fail
+                  (
+                    format!
+                    (
+                      "Shift Count Must be cl or a Constant, got ›{}‹",
+                      count.to_string  ( 1  ),
+                    )
+                  )?,
+            }
+            self.encodeModRegRMdata         ( addressSize,  *srcRegister, dst                                 )?;
+            Ok  ( Some  ( self.getLength() ) )
+          },
+      [
+        OperandType::GeneralPurposeRegister { .. } | OperandType::Memory16 { .. } | OperandType::Memory32 { .. },
+        OperandType::GeneralPurposeRegister { .. },
+        _
+      ]
+      =>  self.failOperandSize(),
+      _
+      =>  self.fail ( "Instruction Must Take a Register or Memory, a Register and a Count Argument".to_string() ),
+    }
+  }
+}
@@ -84,7 +84,7 @@ impl  Instruction
       )
       {
         (
-          OperandType::GeneralPurposeRegister { rex:      dstREX,       number:     dstRegister                                     },
+          OperandType::GeneralPurposeRegister { rex:      dstREX,       number:     dstRegister,  ..                                },
           OperandType::Constant               (           immediate                                                                 )
         )
         =>  if  ( *dstRegister == 0 )
@@ -122,15 +122,8 @@ impl  Instruction
                       &&  immediate <=  0xffff
                       {
                         self.setOpcode                 ( opcode  | 5 );
-                        if operandSize == 4
-                        {
-                          self.setOperandSizeOverride  ( true        );
-                          Ok  ( Some  ( 4 ) )
-                        }
-                        else
-                        {
-                          Ok  ( Some  ( 3 ) )
-                        }
+                        self.encodeOperandSize         ( architecture, operandSize )?;
+                        Ok    ( Some  ( self.getLength() ) )
                       }
                       else
                       {
@@ -148,15 +141,8 @@ impl  Instruction
                       &&  immediate <=  0xffffffff
                       {
                         self.setOpcode                 ( opcode  | 5 );
-                        if operandSize == 2
-                        {
-                          self.setOperandSizeOverride  ( true        );
-                          Ok  ( Some  ( 6 ) )
-                        }
-                        else
-                        {
-                          Ok  ( Some  ( 5 ) )
-                        }
+                        self.encodeOperandSize         ( architecture, operandSize )?;
+                        Ok    ( Some  ( self.getLength() ) )
                       }
                       else
                       {
@@ -168,125 +154,97 @@ impl  Instruction
                         )
                       }
                     },
+                //  there are no 64 bit immediates, the 32 bit immediate is sign extended
+                8 if architecture >= InstructionSet::amd64
+                =>  {
+                      if ( -0x80000000 ..= 0x7fffffff ).contains ( &immediate )
+                      {
+                        self.setImmediate              ( 4,      immediate );
+                        self.setOpcode                 ( opcode  | 5 );
+                        self.encodeOperandSize         ( architecture, operandSize )?;
+                        Ok    ( Some  ( self.getLength() ) )
+                      }
+                      else
+                      {
+                        self.failOutOfBounds
+                        (
+                          -0x80000000,
+                          0x7fffffff,
+                          immediate,
+                        )
+                      }
+                    },
                 _   =>  self.failOperandSize (),
               }
             }
             else
             {
-              self.encodeModRegRMdata
+              let immediate             =   *immediate;
+              let register              =   self.operands [ 0 ].clone();
+              self.encodeModRegRMdata ( addressSize,  opcode  >>  3,  &register  )?;
+              self.encodeArithmeticOpcode
               (
                 architecture,
                 operandSize,
-                if  self.size  == 1
-                &&  architecture      < InstructionSet::amd64
-                &&  self.features.hazFeature ( AssemblyFeatures::RandomOpcode )
-                &&  rand::random()
-                {
-                  //  0x80 and 0x82 are aliases, but 0x82 is invalid for 64 bit.
-                  //  because 0x80 is the default encoding, some disassemblers fail with 0x82.
-                  0x82
-                }
-                else
-                {
-                  0x80
-                },
-                1,
-                0,
-                *dstRegister | opcode,
-                None,
-                Some  ( *immediate ),
+                self.immediateOpcode  ( architecture  ),
+                Some  ( immediate ),
               )
             },
         (
-          OperandType::Memory16               { segment:  dstSegment,   registers:      dstRegisters, displacement: dstDisplacement },
+          OperandType::Memory16               { .. } | OperandType::Memory32 { .. },
           OperandType::Constant               (           immediate                                                                 )
         )
-        =>  self.encodeModRegRMdata
-            (
-              architecture,
-              operandSize,
-              if  self.size  == 1
-              &&  architecture      < InstructionSet::amd64
-              &&  self.features.hazFeature ( AssemblyFeatures::RandomOpcode )
-              &&  rand::random()
-              {
-                //  0x80 and 0x82 are aliases, but 0x82 is invalid for 64 bit.
-                //  because 0x80 is the default encoding, some disassemblers fail with 0x82.
-                0x82
-              }
-              else
-              {
-                0x80
-              },
-              1,
-              0,
-              *dstRegisters as  u8  | opcode,
-              Some  ( *dstDisplacement  ),
-              Some  ( *immediate ),
-            ),
+        =>  {
+              let immediate             =   *immediate;
+              let memory                =   self.operands [ 0 ].clone();
+              self.encodeModRegRMdata ( addressSize,  opcode  >>  3,  &memory    )?;
+              self.encodeArithmeticOpcode
+              (
+                architecture,
+                operandSize,
+                self.immediateOpcode  ( architecture  ),
+                Some  ( immediate ),
+              )
+            },
         (
-          OperandType::GeneralPurposeRegister { rex:      dstREX,       number:     dstRegister                                     },
-          OperandType::GeneralPurposeRegister { rex:      srcREX,       number:     srcRegister                                     }
+          OperandType::GeneralPurposeRegister { number:   dstRegister,  .. },
+          OperandType::GeneralPurposeRegister { number:   srcRegister,  .. }
         )
         =>  if  self.features.hazFeature ( AssemblyFeatures::RandomOpcode )
             &&  rand::random()
             {
-              self.encodeModRegRMdata
-              (
-                architecture,
-                operandSize,
-                opcode | 2,
-                1,
-                *dstRegister,
-                *srcRegister,
-                None,
-                None,
-              )
+              let dstRegister           =   *dstRegister;
+              let src                   =   self.operands [ 1 ].clone();
+              self.encodeModRegRMdata ( addressSize,  dstRegister,    &src       )?;
+              self.encodeArithmeticOpcode ( architecture, operandSize,  opcode | 2, None  )
             }
             else
             {
-              self.encodeModRegRMdata
-              (
-                architecture,
-                operandSize,
-                opcode | 0,
-                1,
-                *srcRegister <<  3,
-                *dstRegister,
-                None,
-                None,
-              )
+              let srcRegister           =   *srcRegister;
+              let dst                   =   self.operands [ 0 ].clone();
+              self.encodeModRegRMdata ( addressSize,  srcRegister,    &dst       )?;
+              self.encodeArithmeticOpcode ( architecture, operandSize,  opcode | 0, None  )
             },
         (
-          OperandType::GeneralPurposeRegister { rex:      dstREX,       number:         dstRegister                                 },
-          OperandType::Memory16               { segment:  srcSegment,   registers:      srcRegisters, displacement: srcDisplacement }
+          OperandType::GeneralPurposeRegister { number:   dstRegister,  .. },
+          OperandType::Memory16               { .. } | OperandType::Memory32 { .. }
         )
-        =>  self.encodeModRegRMdata
-            (
-              architecture,
-              operandSize,
-              opcode | 2,
-              1,
-              *dstRegister,
-              *srcRegisters as  u8,
-              Some  ( *srcDisplacement  ),
-              None,
-            ),
+        =>  {
+              let dstRegister           =   *dstRegister;
+              let memory                =   self.operands [ 1 ].clone();
+              self.encodeModRegRMdata ( addressSize,  dstRegister,    &memory    )?;
+              self.encodeArithmeticOpcode ( architecture, operandSize,  opcode | 2, None  )
+            },
         (
-          OperandType::Memory16               { segment:  dstSegment,     registers:      dstRegisters, displacement: dstDisplacement },
-          OperandType::GeneralPurposeRegister { rex:      srcREX,         number:         srcRegister                                 }
+          OperandType::Memory16               { .. } | OperandType::Memory32 { .. },
+          OperandType::GeneralPurposeRegister { number:   srcRegister,  .. }
         )
-        =>  self.encodeModRegRMdata
-            (
-              architecture,
-              operandSize,
-              opcode | 0,
-              1,
-              *srcRegister,
-              *dstRegisters as  u8,
-              Some  ( *dstDisplacement  ),
-              None,
-            ),
+        =>  {
+              let srcRegister           =   *srcRegister;
+              let memory                =   self.operands [ 0 ].clone();
+              self.encodeModRegRMdata ( addressSize,  srcRegister,    &memory    )?;
+              self.encodeArithmeticOpcode ( architecture, operandSize,  opcode | 0, None  )
+            },
         ( _, _ )
         =>  {
               self.fail
@@ -313,4 +271,70 @@ impl  Instruction
       )
     }
   }
+
+  //  0x80 and 0x82 are aliases, but 0x82 is invalid for 64 bit.
+  //  because 0x80 is the default encoding, some disassemblers fail with 0x82.
+  fn immediateOpcode
+  (
+    &self,
+    architecture:                       InstructionSet,
+  ) -> u8
+  {
+    if  self.size  == 1
+    &&  architecture      < InstructionSet::amd64
+    &&  self.features.hazFeature ( AssemblyFeatures::RandomOpcode )
+    &&  rand::random()
+    {
+      0x82
+    }
+    else
+    {
+      0x80
+    }
+  }
+
+  //  bit 0 of the opcode selects between byte and full size operands,
+  //  bit 1 of the immediate opcode selects the immediate sign extended from a byte
+  fn encodeArithmeticOpcode
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    operandSize:                        usize,
+    opcode:                             u8,
+    immediate:                          Option<i128>,
+  ) -> Result<Option<usize>, String>
+  {
+    self.encodeOperandSize  ( architecture, operandSize )?;
+    let fullSize                        =   if self.size == 1 { 0 } else { 1 };
+    match immediate
+    {
+      Some  ( value )
+      if        self.size != 1
+      &&        ( -0x80 ..= 0x7f ).contains ( &value )
+      &&        (
+                  self.size != 2
+                ||
+                  self.features.hazFeature  ( AssemblyFeatures::X86SignExtensionAllowed )
+                ||
+                  architecture >= InstructionSet::i386
+                )
+      &&       !(
+                  self.features.hazFeature ( AssemblyFeatures::RandomOpcodeSize )
+                &&
+                  rand::random()
+                )
+      =>  {
+            self.setOpcode      ( opcode  | 3         );
+            self.setImmediate   ( 1,        value     );
+          },
+      Some  ( value )
+      =>  {
+            self.setOpcode      ( opcode  | fullSize  );
+            self.encodeImmediate  ( self.size,  value )?;
+          },
+      None
+      =>  self.setOpcode        ( opcode  | fullSize  ),
+    }
+    Ok  ( Some  ( self.getLength() ) )
+  }
 }
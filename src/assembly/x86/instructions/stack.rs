@@ -0,0 +1,320 @@
+use super::
+{
+  Instruction,
+  InstructionType,
+  super::
+  {
+    AssemblyFeatures,
+    InstructionSet,
+    X86,
+    operands::
+    {
+      Operand,
+      OperandType,
+    },
+    registers::
+    {
+      SegmentRegisterNumber,
+    },
+  },
+};
+
+use rand;
+
+macro_rules! theInstruction
+{
+  (
+    $theName:ident,
+    $theInstruction:expr
+  )
+  =>  {
+        pub fn $theName
+        (
+          mut self,
+          operand:                      impl Operand,
+        ) -> Self
+        {
+          let ( operandThis, operandSize )
+                                        =   operand.this();
+          self.instructions.push
+          (
+            Instruction
+            (
+              self.line,
+              self.features,
+              operandSize,
+              $theInstruction,
+              vec!  ( operandThis ),
+            )
+          );
+          self.line                     +=  1;
+          self
+        }
+      }
+}
+
+impl X86
+{
+  theInstruction! ( pop,  InstructionType::POP  );
+  theInstruction! ( push, InstructionType::PUSH );
+
+  pub fn enter
+  (
+    mut self,
+    size:                               impl Operand,
+    level:                              impl Operand,
+  ) -> Self
+  {
+    let ( sizeThis,   _ )               =   size.this();
+    let ( levelThis,  _ )               =   level.this();
+    self.instructions.push
+    (
+      Instruction
+      (
+        self.line,
+        self.features,
+        0,
+        InstructionType::ENTER,
+        vec!  ( sizeThis, levelThis ),
+      )
+    );
+    self.line                           +=  1;
+    self
+  }
+}
+
+impl  Instruction
+{
+  fn encodeStackSize
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    operandSize:                        usize,
+  ) -> Result<(), String>
+  {
+    match self.size
+    {
+      1
+      =>  self.failOperandSize(),
+      //  in 64 bit mode, the stack is always 64 bit wide, but 16 bit operands are still possible
+      4 if operandSize == 64
+      =>  self.failOperandSize(),
+      8 if operandSize == 64
+      =>  Ok  ( () ),
+      _
+      =>  self.encodeOperandSize  ( architecture, operandSize ),
+    }
+  }
+
+  pub fn compilePushInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    operandSize:                        usize,
+    addressSize:                        usize,
+  ) -> Result<Option<usize>, String>
+  {
+    if self.operands.len() == 1
+    {
+      let operand                       =   self.operands [ 0 ].clone();
+      match &operand
+      {
+        OperandType::GeneralPurposeRegister { number, .. }
+        =>  {
+              self.encodeStackSize          ( architecture, operandSize                                       )?;
+              self.encodeOpcodeRegister     ( 0x50,         *number                                           );
+            },
+        OperandType::SegmentRegister        ( register    )
+        =>  match register
+            {
+              SegmentRegisterNumber::ES     =>  self.setOpcode  ( 0x06  ),
+              SegmentRegisterNumber::CS     =>  self.setOpcode  ( 0x0e  ),
+              SegmentRegisterNumber::SS     =>  self.setOpcode  ( 0x16  ),
+              SegmentRegisterNumber::DS     =>  self.setOpcode  ( 0x1e  ),
+              SegmentRegisterNumber::FS     |
+              SegmentRegisterNumber::GS
+              if architecture >= InstructionSet::i386
+              =>  {
+                    self.setTwoByteOpcode   ( true                                                            );
+                    self.setOpcode          ( if *register == SegmentRegisterNumber::FS { 0xa0 } else { 0xa8 } );
+                  },
+              _
+              =>  self.fail
+                  (
+                    format!
+                    (
+                      "Cannot Push Segment Register ›{}‹ on {}",
+                      register.to_string(),
+                      InstructionSet  ( architecture  ),
+                    )
+                  )?,
+            },
+        OperandType::Memory16               { .. } | OperandType::Memory32 { .. }
+        =>  {
+              self.encodeStackSize          ( architecture, operandSize                                       )?;
+              self.setOpcode                ( 0xff                                                            );
+              self.encodeModRegRMdata       ( addressSize,  6,            &operand                            )?;
+            },
+        OperandType::Constant               ( immediate   )
+        if architecture >= InstructionSet::i186
+        =>  {
+              if self.size == 0
+              {
+                self.size               =   operandSize / 8;
+              }
+              self.encodeStackSize          ( architecture, operandSize                                       )?;
+              if  *immediate >= -0x80
+              &&  *immediate <=  0x7f
+              && !( self.features.hazFeature ( AssemblyFeatures::RandomOpcodeSize ) && rand::random() )
+              {
+                self.setOpcode              ( 0x6a                                                            );
+                self.encodeImmediate        ( 1,            *immediate                                        )?;
+              }
+              else
+              {
+                self.setOpcode              ( 0x68                                                            );
+                self.encodeImmediate        ( self.size,    *immediate                                        )?;
+              }
+            },
+        OperandType::Constant               ( _           )
+        =>  self.fail
+            (
+              format!
+              (
+                "Cannot Push Immediate Values on {}",
+                InstructionSet  ( architecture  ),
+              )
+            )?,
+        _
+        =>  self.fail
+            (
+              format!
+              (
+                "Invalid Argument ›{}‹",
+                operand.to_string  ( self.size  ),
+              )
+            )?,
+      }
+      Ok  ( Some  ( self.getLength() ) )
+    }
+    else
+    {
+      self.fail
+      (
+        format!
+        (
+          "Instruction Must Take Exactly One Argument, got {}",
+          self.operands.len(),
+        )
+      )
+    }
+  }
+
+  pub fn compilePopInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    operandSize:                        usize,
+    addressSize:                        usize,
+  ) -> Result<Option<usize>, String>
+  {
+    if self.operands.len() == 1
+    {
+      let operand                       =   self.operands [ 0 ].clone();
+      match &operand
+      {
+        OperandType::GeneralPurposeRegister { number, .. }
+        =>  {
+              self.encodeStackSize          ( architecture, operandSize                                       )?;
+              self.encodeOpcodeRegister     ( 0x58,         *number                                           );
+            },
+        OperandType::SegmentRegister        ( register    )
+        =>  match register
+            {
+              SegmentRegisterNumber::ES     =>  self.setOpcode  ( 0x07  ),
+              SegmentRegisterNumber::SS     =>  self.setOpcode  ( 0x17  ),
+              SegmentRegisterNumber::DS     =>  self.setOpcode  ( 0x1f  ),
+              //  0x0f became the two byte opcode escape with the i186
+              SegmentRegisterNumber::CS
+              if  architecture == InstructionSet::i8086
+              &&  self.features.hazFeature ( AssemblyFeatures::X86UndocumentedOpcodes )
+              =>  self.setOpcode  ( 0x0f  ),
+              SegmentRegisterNumber::FS     |
+              SegmentRegisterNumber::GS
+              if architecture >= InstructionSet::i386
+              =>  {
+                    self.setTwoByteOpcode   ( true                                                            );
+                    self.setOpcode          ( if *register == SegmentRegisterNumber::FS { 0xa1 } else { 0xa9 } );
+                  },
+              _
+              =>  self.fail
+                  (
+                    format!
+                    (
+                      "Cannot Pop Segment Register ›{}‹ on {}",
+                      register.to_string(),
+                      InstructionSet  ( architecture  ),
+                    )
+                  )?,
+            },
+        OperandType::Memory16               { .. } | OperandType::Memory32 { .. }
+        =>  {
+              self.encodeStackSize          ( architecture, operandSize                                       )?;
+              self.setOpcode                ( 0x8f                                                            );
+              self.encodeModRegRMdata       ( addressSize,  0,            &operand                            )?;
+            },
+        _
+        =>  self.fail
+            (
+              format!
+              (
+                "Invalid Argument ›{}‹",
+                operand.to_string  ( self.size  ),
+              )
+            )?,
+      }
+      Ok  ( Some  ( self.getLength() ) )
+    }
+    else
+    {
+      self.fail
+      (
+        format!
+        (
+          "Instruction Must Take Exactly One Argument, got {}",
+          self.operands.len(),
+        )
+      )
+    }
+  }
+
+  pub fn compileEnterInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet              ( architecture, InstructionSet::i186                              )?;
+    match self.operands.as_slice()
+    {
+      [ OperandType::Constant ( size ), OperandType::Constant ( level ) ]
+      =>  {
+            let ( size, level )         =   ( *size, *level );
+            if !( 0 ..= 0xffff ).contains ( &size )
+            {
+              self.failOutOfBounds          ( 0,            0xffff,       size                                )?;
+            }
+            if !( 0 ..= 0xff ).contains ( &level )
+            {
+              self.failOutOfBounds          ( 0,            0xff,         level                               )?;
+            }
+            //  the size of the stack frame is a word, followed by the nesting level as a byte
+            self.setOpcode                  ( 0xc8                                                            );
+            self.setImmediate               ( 3,            size  | level << 16                               );
+            Ok  ( Some  ( self.getLength() ) )
+          },
+      _
+      =>  self.fail ( "Instruction Must Take Exactly Two Constant Arguments".to_string() ),
+    }
+  }
+}
@@ -0,0 +1,414 @@
+use super::
+{
+  Instruction,
+  InstructionType,
+  VectorOpcode,
+  super::
+  {
+    AssemblyFeatures,
+    InstructionSet,
+    X86,
+    operands::
+    {
+      Operand,
+      OperandType,
+    },
+  },
+};
+
+use rand;
+
+macro_rules! theInstruction
+{
+  (
+    $theName:ident,
+    $theInstruction:expr
+  )
+  =>  {
+        pub fn $theName
+        (
+          mut self,
+          dst:                          impl Operand,
+          src:                          impl Operand,
+        ) -> Self
+        {
+          let ( dstThis, dstSize )      =   dst.this();
+          let ( srcThis, srcSize )      =   src.this();
+          let size                      =   ( dstSize | srcSize ) as usize;
+          self.instructions.push
+          (
+            Instruction
+            (
+              self.line,
+              self.features,
+              size,
+              $theInstruction,
+              vec!  ( dstThis,  srcThis ),
+            )
+          );
+          self.line                     +=  1;
+          self
+        }
+      };
+  (
+    $theName:ident,
+    $theInstruction:expr,
+    dst,
+    src,
+    predicate
+  )
+  =>  {
+        pub fn $theName
+        (
+          mut self,
+          dst:                          impl Operand,
+          src:                          impl Operand,
+          predicate:                    impl Operand,
+        ) -> Self
+        {
+          let ( dstThis,        dstSize )   =   dst.this();
+          let ( srcThis,        srcSize )   =   src.this();
+          let ( predicateThis,  _       )   =   predicate.this();
+          let size                      =   ( dstSize | srcSize ) as usize;
+          self.instructions.push
+          (
+            Instruction
+            (
+              self.line,
+              self.features,
+              size,
+              $theInstruction,
+              vec!  ( dstThis,  srcThis,  predicateThis ),
+            )
+          );
+          self.line                     +=  1;
+          self
+        }
+      };
+}
+
+impl X86
+{
+  theInstruction! ( addpd,     InstructionType::ADDPD     );
+  theInstruction! ( addps,     InstructionType::ADDPS     );
+  theInstruction! ( addsd,     InstructionType::ADDSD     );
+  theInstruction! ( addss,     InstructionType::ADDSS     );
+  theInstruction! ( andnpd,    InstructionType::ANDNPD    );
+  theInstruction! ( andnps,    InstructionType::ANDNPS    );
+  theInstruction! ( andpd,     InstructionType::ANDPD     );
+  theInstruction! ( andps,     InstructionType::ANDPS     );
+  theInstruction! ( cmppd,     InstructionType::CMPPD,     dst,  src,  predicate );
+  theInstruction! ( cmpps,     InstructionType::CMPPS,     dst,  src,  predicate );
+  theInstruction! ( cmpsd,     InstructionType::CMPSD,     dst,  src,  predicate );
+  theInstruction! ( cmpss,     InstructionType::CMPSS,     dst,  src,  predicate );
+  theInstruction! ( comisd,    InstructionType::COMISD    );
+  theInstruction! ( comiss,    InstructionType::COMISS    );
+  theInstruction! ( cvtdq2ps,  InstructionType::CVTDQ2PS  );
+  theInstruction! ( cvtpd2ps,  InstructionType::CVTPD2PS  );
+  theInstruction! ( cvtps2dq,  InstructionType::CVTPS2DQ  );
+  theInstruction! ( cvtps2pd,  InstructionType::CVTPS2PD  );
+  theInstruction! ( cvtsd2si,  InstructionType::CVTSD2SI  );
+  theInstruction! ( cvtsd2ss,  InstructionType::CVTSD2SS  );
+  theInstruction! ( cvtsi2sd,  InstructionType::CVTSI2SD  );
+  theInstruction! ( cvtsi2ss,  InstructionType::CVTSI2SS  );
+  theInstruction! ( cvtss2sd,  InstructionType::CVTSS2SD  );
+  theInstruction! ( cvtss2si,  InstructionType::CVTSS2SI  );
+  theInstruction! ( cvttsd2si, InstructionType::CVTTSD2SI );
+  theInstruction! ( cvttss2si, InstructionType::CVTTSS2SI );
+  theInstruction! ( divpd,     InstructionType::DIVPD     );
+  theInstruction! ( divps,     InstructionType::DIVPS     );
+  theInstruction! ( divsd,     InstructionType::DIVSD     );
+  theInstruction! ( divss,     InstructionType::DIVSS     );
+  theInstruction! ( maxpd,     InstructionType::MAXPD     );
+  theInstruction! ( maxps,     InstructionType::MAXPS     );
+  theInstruction! ( maxsd,     InstructionType::MAXSD     );
+  theInstruction! ( maxss,     InstructionType::MAXSS     );
+  theInstruction! ( minpd,     InstructionType::MINPD     );
+  theInstruction! ( minps,     InstructionType::MINPS     );
+  theInstruction! ( minsd,     InstructionType::MINSD     );
+  theInstruction! ( minss,     InstructionType::MINSS     );
+  theInstruction! ( movapd,    InstructionType::MOVAPD    );
+  theInstruction! ( movaps,    InstructionType::MOVAPS    );
+  theInstruction! ( movdqa,    InstructionType::MOVDQA    );
+  theInstruction! ( movdqu,    InstructionType::MOVDQU    );
+  theInstruction! ( movsd,     InstructionType::MOVSD     );
+  theInstruction! ( movss,     InstructionType::MOVSS     );
+  theInstruction! ( movupd,    InstructionType::MOVUPD    );
+  theInstruction! ( movups,    InstructionType::MOVUPS    );
+  theInstruction! ( mulpd,     InstructionType::MULPD     );
+  theInstruction! ( mulps,     InstructionType::MULPS     );
+  theInstruction! ( mulsd,     InstructionType::MULSD     );
+  theInstruction! ( mulss,     InstructionType::MULSS     );
+  theInstruction! ( orpd,      InstructionType::ORPD      );
+  theInstruction! ( orps,      InstructionType::ORPS      );
+  theInstruction! ( sqrtpd,    InstructionType::SQRTPD    );
+  theInstruction! ( sqrtps,    InstructionType::SQRTPS    );
+  theInstruction! ( sqrtsd,    InstructionType::SQRTSD    );
+  theInstruction! ( sqrtss,    InstructionType::SQRTSS    );
+  theInstruction! ( subpd,     InstructionType::SUBPD     );
+  theInstruction! ( subps,     InstructionType::SUBPS     );
+  theInstruction! ( subsd,     InstructionType::SUBSD     );
+  theInstruction! ( subss,     InstructionType::SUBSS     );
+  theInstruction! ( ucomisd,   InstructionType::UCOMISD   );
+  theInstruction! ( ucomiss,   InstructionType::UCOMISS   );
+  theInstruction! ( xorpd,     InstructionType::XORPD     );
+  theInstruction! ( xorps,     InstructionType::XORPS     );
+}
+
+impl  Instruction
+{
+  //  scalar instructions only read as much memory as the scalar is wide, but the register is always a XMM register
+  fn encodeStreamingInstruction
+  (
+    &mut self,
+    addressSize:                        usize,
+    opcode:                             VectorOpcode,
+    memorySize:                         usize,
+    regField:                           u8,
+    operand:                            &OperandType,
+  ) -> Result<(), String>
+  {
+    match operand
+    {
+      OperandType::MulitMediaRegister       ( _   )
+      if self.size == 16
+      =>  {},
+      OperandType::Memory16                 { .. } |
+      OperandType::Memory32                 { .. }
+      if  self.size == 16
+      ||  self.size == 16 | memorySize
+      =>  {},
+      OperandType::MulitMediaRegister       ( _   ) |
+      OperandType::Memory16                 { .. } |
+      OperandType::Memory32                 { .. }
+      =>  self.failOperandSize              (                                                                 )?,
+      _
+      =>  self.fail ( "Instruction Must Take a XMM Register and a XMM Register or Memory Argument".to_string() )?,
+    }
+    self.encodeStreamingOperands            ( addressSize,  opcode,       regField,     operand               )
+  }
+
+  //  all SSE and SSE2 instructions are in the map 1, which is the escape 0x0f
+  fn encodeStreamingOperands
+  (
+    &mut self,
+    addressSize:                        usize,
+    opcode:                             VectorOpcode,
+    regField:                           u8,
+    operand:                            &OperandType,
+  ) -> Result<(), String>
+  {
+    self.setMandatoryPrefix                 ( opcode.prefix                                                   );
+    self.setTwoByteOpcode                   ( true                                                            );
+    self.setOpcode                          ( opcode.opcode                                                   );
+    self.encodeModRegRMdata                 ( addressSize,  regField,     operand                             )
+  }
+
+  //  SSE requires at least the Pentium III, SSE2 at least the Pentium 4
+  pub fn compileStreamingInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+    minimum:                            InstructionSet,
+    opcode:                             VectorOpcode,
+    memorySize:                         usize,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet              ( architecture, minimum                                           )?;
+    match self.operands.clone().as_slice()
+    {
+      [
+        OperandType::MulitMediaRegister     ( dstRegister ),
+        src
+      ]
+      =>  {
+            self.encodeStreamingInstruction ( addressSize,  opcode,       memorySize,   *dstRegister, src     )?;
+            Ok  ( Some  ( self.getLength() ) )
+          },
+      _
+      =>  self.fail ( "Instruction Must Take a XMM Register and a XMM Register or Memory Argument".to_string() ),
+    }
+  }
+
+  //  the store opcode shares the mandatory prefix and the map with the load opcode
+  pub fn compileStreamingMoveInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+    minimum:                            InstructionSet,
+    loadOpcode:                         VectorOpcode,
+    storeOpcode:                        u8,
+    memorySize:                         usize,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet              ( architecture, minimum                                           )?;
+    let   storeOpcode                   =   VectorOpcode  ( loadOpcode.prefix,  loadOpcode.map, storeOpcode   );
+    match self.operands.clone().as_slice()
+    {
+      //  the store form works with two registers as well
+      [
+        dst @ OperandType::MulitMediaRegister     ( _   ),
+        OperandType::MulitMediaRegister     ( srcRegister )
+      ]
+      if  self.features.hazFeature ( AssemblyFeatures::RandomOpcode )
+      &&  rand::random()
+      =>  self.encodeStreamingInstruction   ( addressSize,  storeOpcode,  memorySize,   *srcRegister, dst     )?,
+      [
+        OperandType::MulitMediaRegister     ( dstRegister ),
+        src
+      ]
+      =>  self.encodeStreamingInstruction   ( addressSize,  loadOpcode,   memorySize,   *dstRegister, src     )?,
+      [
+        dst @ OperandType::Memory16               { .. } |
+        dst @ OperandType::Memory32               { .. },
+        OperandType::MulitMediaRegister     ( srcRegister )
+      ]
+      =>  self.encodeStreamingInstruction   ( addressSize,  storeOpcode,  memorySize,   *srcRegister, dst     )?,
+      _
+      =>  return self.fail ( "Instruction Must Take a XMM Register and a XMM Register or Memory Argument".to_string() ),
+    }
+    Ok  ( Some  ( self.getLength() ) )
+  }
+
+  //  the predicate is an immediate, but only the predicates 0 to 7 are defined before AVX
+  pub fn compileStreamingCompareInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+    minimum:                            InstructionSet,
+    prefix:                             u8,
+    memorySize:                         usize,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet              ( architecture, minimum                                           )?;
+    match self.operands.clone().as_slice()
+    {
+      [
+        OperandType::MulitMediaRegister     ( dstRegister ),
+        src,
+        OperandType::Constant               ( predicate   )
+      ]
+      =>  {
+            self.encodeStreamingInstruction ( addressSize,  VectorOpcode  ( prefix, 1,  0xc2  ),  memorySize, *dstRegister, src )?;
+            if  *predicate >= 0
+            &&  *predicate <= 7
+            {
+              self.setImmediate             ( 1,            *predicate                                        );
+              Ok  ( Some  ( self.getLength() ) )
+            }
+            else
+            {
+              self.failOutOfBounds          ( 0,            7,            *predicate                          )
+            }
+          },
+      _
+      =>  self.fail ( "Instruction Must Take a XMM Register, a XMM Register or Memory and an Immediate Argument".to_string() ),
+    }
+  }
+
+  //  the size of the instruction is the size of the integer combined with the size of the register or memory
+  pub fn compileConvertToIntegerInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    operandSize:                        usize,
+    addressSize:                        usize,
+    minimum:                            InstructionSet,
+    opcode:                             VectorOpcode,
+    memorySize:                         usize,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet              ( architecture, minimum                                           )?;
+    let   rest                          =   self.size & !16;
+    let   integerSize                   =   if rest == 4 | 8  { rest & !memorySize  } else { rest };
+    match self.operands.clone().as_slice()
+    {
+      [
+        OperandType::GeneralPurposeRegister { number: dstRegister,  .. },
+        src @ OperandType::MulitMediaRegister     ( _   )
+      ]
+      if self.size & 16 != 0
+      =>  {
+            self.encodeIntegerSize          ( architecture, operandSize,  integerSize                         )?;
+            self.encodeStreamingOperands    ( addressSize,  opcode,       *dstRegister, src                   )?;
+          },
+      [
+        OperandType::GeneralPurposeRegister { number: dstRegister,  .. },
+        src @ OperandType::Memory16               { .. } |
+        src @ OperandType::Memory32               { .. }
+      ]
+      if self.size & 16 == 0
+      =>  {
+            self.encodeIntegerSize          ( architecture, operandSize,  integerSize                         )?;
+            self.encodeStreamingOperands    ( addressSize,  opcode,       *dstRegister, src                   )?;
+          },
+      [
+        OperandType::GeneralPurposeRegister { .. },
+        OperandType::MulitMediaRegister     ( _   ) |
+        OperandType::Memory16               { .. } |
+        OperandType::Memory32               { .. }
+      ]
+      =>  return self.failOperandSize(),
+      _
+      =>  return self.fail ( "Instruction Must Take a General Purpose Register and a XMM Register or Memory Argument".to_string() ),
+    }
+    Ok  ( Some  ( self.getLength() ) )
+  }
+
+  pub fn compileConvertFromIntegerInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    operandSize:                        usize,
+    addressSize:                        usize,
+    minimum:                            InstructionSet,
+    prefix:                             u8,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet              ( architecture, minimum                                           )?;
+    if self.size & 16 == 0
+    {
+      return self.failOperandSize();
+    }
+    let   integerSize                   =   self.size & !16;
+    match self.operands.clone().as_slice()
+    {
+      [
+        OperandType::MulitMediaRegister     ( dstRegister ),
+        src @ OperandType::GeneralPurposeRegister { .. } |
+        src @ OperandType::Memory16               { .. } |
+        src @ OperandType::Memory32               { .. }
+      ]
+      =>  {
+            self.encodeIntegerSize          ( architecture, operandSize,  integerSize                         )?;
+            self.encodeStreamingOperands    ( addressSize,  VectorOpcode  ( prefix, 1,  0x2a  ),  *dstRegister, src )?;
+            Ok  ( Some  ( self.getLength() ) )
+          },
+      _
+      =>  self.fail ( "Instruction Must Take a XMM Register and a General Purpose Register or Memory Argument".to_string() ),
+    }
+  }
+
+  //  the integer is either a double word or, with REX.W, a quad word
+  fn encodeIntegerSize
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    operandSize:                        usize,
+    integerSize:                        usize,
+  ) -> Result<(), String>
+  {
+    match integerSize
+    {
+      4
+      =>  Ok  ( () ),
+      8
+      =>  self.encodeOperandSizeOf          ( architecture, operandSize,  8                                   ),
+      _
+      =>  self.failOperandSize              (                                                                 ),
+    }
+  }
+}
@@ -0,0 +1,261 @@
+use super::
+{
+  Instruction,
+  InstructionType,
+  super::
+  {
+    InstructionSet,
+    X86,
+    operands::
+    {
+      Operand,
+      OperandType,
+    },
+  },
+};
+
+macro_rules! theInstruction
+{
+  (
+    $theName:ident,
+    $theInstruction:expr
+  )
+  =>  {
+        pub fn $theName
+        (
+          mut self,
+          operand:                      impl Operand,
+        ) -> Self
+        {
+          let ( operandThis, operandSize )
+                                        =   operand.this();
+          self.instructions.push
+          (
+            Instruction
+            (
+              self.line,
+              self.features,
+              operandSize,
+              $theInstruction,
+              vec!  ( operandThis ),
+            )
+          );
+          self.line                     +=  1;
+          self
+        }
+      };
+  (
+    $theName:ident,
+    $theInstruction:expr,
+    address
+  )
+  =>  {
+        pub fn $theName
+        (
+          mut self,
+          operand:                      impl Operand,
+        ) -> Self
+        {
+          //  the pseudo descriptor has six bytes, so only its address is of interest
+          let ( mut operandThis, _ )    =   operand.this();
+          if let OperandType::Expression ( expression ) = &operandThis
+          {
+            if let Ok ( ( Some ( _ ), solved ) ) = expression.solve()
+            {
+              operandThis               =   solved;
+            }
+          }
+          self.instructions.push
+          (
+            Instruction
+            (
+              self.line,
+              self.features,
+              0,
+              $theInstruction,
+              vec!  ( operandThis ),
+            )
+          );
+          self.line                     +=  1;
+          self
+        }
+      };
+  (
+    $theName:ident,
+    $theInstruction:expr,
+    dst,
+    src
+  )
+  =>  {
+        pub fn $theName
+        (
+          mut self,
+          dst:                          impl Operand,
+          src:                          impl Operand,
+        ) -> Self
+        {
+          //  the selector is always a word, only the other operand determines the size
+          let ( dstThis, dstSize )      =   dst.this();
+          let ( srcThis, _       )      =   src.this();
+          self.instructions.push
+          (
+            Instruction
+            (
+              self.line,
+              self.features,
+              dstSize,
+              $theInstruction,
+              vec!  ( dstThis,  srcThis ),
+            )
+          );
+          self.line                     +=  1;
+          self
+        }
+      };
+}
+
+impl X86
+{
+  theInstruction! ( arpl,   InstructionType::ARPL,   dst,  src );
+  theInstruction! ( invlpg, InstructionType::INVLPG, address   );
+  theInstruction! ( lar,    InstructionType::LAR,    dst,  src );
+  theInstruction! ( lgdt,   InstructionType::LGDT,   address   );
+  theInstruction! ( lidt,   InstructionType::LIDT,   address   );
+  theInstruction! ( lldt,   InstructionType::LLDT              );
+  theInstruction! ( lmsw,   InstructionType::LMSW              );
+  theInstruction! ( lsl,    InstructionType::LSL,    dst,  src );
+  theInstruction! ( ltr,    InstructionType::LTR               );
+  theInstruction! ( sgdt,   InstructionType::SGDT,   address   );
+  theInstruction! ( sidt,   InstructionType::SIDT,   address   );
+  theInstruction! ( sldt,   InstructionType::SLDT              );
+  theInstruction! ( smsw,   InstructionType::SMSW              );
+  theInstruction! ( str,    InstructionType::STR               );
+  theInstruction! ( verr,   InstructionType::VERR              );
+  theInstruction! ( verw,   InstructionType::VERW              );
+}
+
+impl  Instruction
+{
+  pub fn compileDescriptorTableInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+    minimum:                            InstructionSet,
+    regField:                           u8,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet              ( architecture, minimum                                           )?;
+    match self.operands.clone().as_slice()
+    {
+      [ operand @ OperandType::Memory16 { .. } |
+        operand @ OperandType::Memory32 { .. } ]
+      =>  {
+            self.setTwoByteOpcode           ( true                                                            );
+            self.setOpcode                  ( 0x01                                                            );
+            self.encodeModRegRMdata         ( addressSize,  regField,     operand                             )?;
+            Ok  ( Some  ( self.getLength() ) )
+          },
+      _
+      =>  self.fail ( "Instruction Must Take Exactly One Memory Argument".to_string() ),
+    }
+  }
+
+  pub fn compileSystemInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+    opcode:                             u8,
+    regField:                           u8,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet              ( architecture, InstructionSet::i286                              )?;
+    match self.operands.clone().as_slice()
+    {
+      [ operand @ OperandType::GeneralPurposeRegister { .. } ] |
+      [ operand @ OperandType::Memory16               { .. } |
+        operand @ OperandType::Memory32               { .. } ]
+      =>  {
+            if self.size != 2
+            {
+              self.failOperandSize()?;
+            }
+            self.setTwoByteOpcode           ( true                                                            );
+            self.setOpcode                  ( opcode                                                          );
+            self.encodeModRegRMdata         ( addressSize,  regField,     operand                             )?;
+            Ok  ( Some  ( self.getLength() ) )
+          },
+      _
+      =>  self.fail ( "Instruction Must Take Exactly One Register or Memory Argument".to_string() ),
+    }
+  }
+
+  pub fn compileSelectorInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    operandSize:                        usize,
+    addressSize:                        usize,
+    opcode:                             u8,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet              ( architecture, InstructionSet::i286                              )?;
+    match self.operands.clone().as_slice()
+    {
+      [
+        OperandType::GeneralPurposeRegister { number:   dstRegister,  .. },
+        src @ OperandType::GeneralPurposeRegister { .. } |
+        src @ OperandType::Memory16               { .. } |
+        src @ OperandType::Memory32               { .. }
+      ]
+      if self.size != 1
+      =>  {
+            self.encodeOperandSize          ( architecture, operandSize                                       )?;
+            self.setTwoByteOpcode           ( true                                                            );
+            self.setOpcode                  ( opcode                                                          );
+            self.encodeModRegRMdata         ( addressSize,  *dstRegister, src                                 )?;
+            Ok  ( Some  ( self.getLength() ) )
+          },
+      [
+        OperandType::GeneralPurposeRegister { .. },
+        OperandType::GeneralPurposeRegister { .. } | OperandType::Memory16 { .. } | OperandType::Memory32 { .. }
+      ]
+      =>  self.failOperandSize(),
+      _
+      =>  self.fail ( "Instruction Must Take a Register and a Register or Memory Argument".to_string() ),
+    }
+  }
+
+  pub fn compileAdjustPrivilegeInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet              ( architecture, InstructionSet::i286                              )?;
+    match self.operands.clone().as_slice()
+    {
+      [
+        dst @ OperandType::GeneralPurposeRegister { .. } |
+        dst @ OperandType::Memory16               { .. } |
+        dst @ OperandType::Memory32               { .. },
+        OperandType::GeneralPurposeRegister { number:   srcRegister,  .. }
+      ]
+      if self.size == 2
+      =>  {
+            self.setOpcode                  ( 0x63                                                            );
+            self.encodeModRegRMdata         ( addressSize,  *srcRegister, dst                                 )?;
+            Ok  ( Some  ( self.getLength() ) )
+          },
+      [
+        OperandType::GeneralPurposeRegister { .. } | OperandType::Memory16 { .. } | OperandType::Memory32 { .. },
+        OperandType::GeneralPurposeRegister { .. }
+      ]
+      =>  self.failOperandSize(),
+      _
+      =>  self.fail ( "Instruction Must Take a Register or Memory and a Register Argument".to_string() ),
+    }
+  }
+}
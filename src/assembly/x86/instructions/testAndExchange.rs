@@ -0,0 +1,344 @@
+use super::
+{
+  Instruction,
+  InstructionType,
+  super::
+  {
+    AssemblyFeatures,
+    InstructionSet,
+    X86,
+    operands::
+    {
+      Operand,
+      OperandType,
+    },
+  },
+};
+
+use rand;
+
+macro_rules! theInstruction
+{
+  (
+    $theName:ident,
+    $theInstruction:expr
+  )
+  =>  {
+        pub fn $theName
+        (
+          mut self,
+          dst:                          impl Operand,
+          src:                          impl Operand,
+        ) -> Self
+        {
+          let ( dstThis, dstSize )      =   dst.this();
+          let ( srcThis, srcSize )      =   src.this();
+          let size                      =   ( dstSize | srcSize ) as usize;
+          self.instructions.push
+          (
+            Instruction
+            (
+              self.line,
+              self.features,
+              size,
+              $theInstruction,
+              vec!  ( dstThis,  srcThis ),
+            )
+          );
+          self.line                     +=  1;
+          self
+        }
+      };
+  (
+    $theName:ident,
+    $theInstruction:expr,
+    operand
+  )
+  =>  {
+        pub fn $theName
+        (
+          mut self,
+          operand:                      impl Operand,
+        ) -> Self
+        {
+          let ( operandThis, operandSize )
+                                        =   operand.this();
+          self.instructions.push
+          (
+            Instruction
+            (
+              self.line,
+              self.features,
+              operandSize,
+              $theInstruction,
+              vec!  ( operandThis ),
+            )
+          );
+          self.line                     +=  1;
+          self
+        }
+      };
+}
+
+impl X86
+{
+  theInstruction! ( bswap,      InstructionType::BSWAP,     operand );
+  theInstruction! ( cmpxchg,    InstructionType::CMPXCHG            );
+  theInstruction! ( cmpxchg8b,  InstructionType::CMPXCHG8B, operand );
+  theInstruction! ( test,       InstructionType::TEST               );
+  theInstruction! ( xadd,       InstructionType::XADD               );
+  theInstruction! ( xchg,       InstructionType::XCHG               );
+}
+
+impl  Instruction
+{
+  pub fn compileTestInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    operandSize:                        usize,
+    addressSize:                        usize,
+  ) -> Result<Option<usize>, String>
+  {
+    if self.operands.len() == 2
+    {
+      let dst                           =   self.operands [ 0 ].clone();
+      let src                           =   self.operands [ 1 ].clone();
+      self.encodeOperandSize                ( architecture, operandSize                                       )?;
+      let width                         =   if self.size == 1 { 0 } else { 1 };
+      match ( &dst, &src )
+      {
+        (
+          OperandType::GeneralPurposeRegister { number:   0,            .. },
+          OperandType::Constant               ( immediate                  )
+        )
+        if !( self.features.hazFeature ( AssemblyFeatures::RandomOpcodeSize ) && rand::random() )
+        =>  {
+              self.setOpcode                ( 0xa8  | width                                                   );
+              self.encodeImmediate          ( self.size,    *immediate                                        )?;
+            },
+        (
+          OperandType::GeneralPurposeRegister { .. } | OperandType::Memory16 { .. } | OperandType::Memory32 { .. },
+          OperandType::Constant               ( immediate                  )
+        )
+        =>  {
+              self.setOpcode                ( 0xf6  | width                                                   );
+              self.encodeModRegRMdata       ( addressSize,  0,            &dst                                )?;
+              self.encodeImmediate          ( self.size,    *immediate                                        )?;
+            },
+        (
+          OperandType::GeneralPurposeRegister { .. } | OperandType::Memory16 { .. } | OperandType::Memory32 { .. },
+          OperandType::GeneralPurposeRegister { number:   srcRegister,  .. }
+        )
+        =>  {
+              self.setOpcode                ( 0x84  | width                                                   );
+              self.encodeModRegRMdata       ( addressSize,  *srcRegister, &dst                                )?;
+            },
+        (
+          OperandType::GeneralPurposeRegister { number:   dstRegister,  .. },
+          OperandType::Memory16               { .. } | OperandType::Memory32 { .. }
+        )
+        =>  {
+              self.setOpcode                ( 0x84  | width                                                   );
+              self.encodeModRegRMdata       ( addressSize,  *dstRegister, &src                                )?;
+            },
+        ( _, _ )
+        =>  self.fail
+            (
+              format!
+              (
+                "Invalid Combination of Arguments ›{}‹, ›{}‹",
+                dst.to_string  ( self.size  ),
+                src.to_string  ( self.size  ),
+              )
+            )?,
+      }
+      Ok  ( Some  ( self.getLength() ) )
+    }
+    else
+    {
+      self.fail
+      (
+        format!
+        (
+          "Instruction Must Take Exactly Two Arguments, got {}",
+          self.operands.len(),
+        )
+      )
+    }
+  }
+
+  pub fn compileExchangeInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    operandSize:                        usize,
+    addressSize:                        usize,
+  ) -> Result<Option<usize>, String>
+  {
+    if self.operands.len() == 2
+    {
+      let dst                           =   self.operands [ 0 ].clone();
+      let src                           =   self.operands [ 1 ].clone();
+      self.encodeOperandSize                ( architecture, operandSize                                       )?;
+      let width                         =   if self.size == 1 { 0 } else { 1 };
+      match ( &dst, &src )
+      {
+        //  xchg ax, ax is nop
+        (
+          OperandType::GeneralPurposeRegister { number:   0,            .. },
+          OperandType::GeneralPurposeRegister { number:   register,     .. }
+        ) |
+        (
+          OperandType::GeneralPurposeRegister { number:   register,     .. },
+          OperandType::GeneralPurposeRegister { number:   0,            .. }
+        )
+        if  self.size != 1
+        && !( self.features.hazFeature ( AssemblyFeatures::RandomOpcodeSize ) && rand::random() )
+        =>  self.encodeOpcodeRegister       ( 0x90,         *register                                         ),
+        (
+          OperandType::GeneralPurposeRegister { number:   dstRegister,  .. },
+          OperandType::GeneralPurposeRegister { number:   srcRegister,  .. }
+        )
+        =>  {
+              //  both operands can be in either field
+              self.setOpcode                ( 0x86  | width                                                   );
+              if  self.features.hazFeature ( AssemblyFeatures::RandomOpcode )
+              &&  rand::random()
+              {
+                self.encodeModRegRMdata     ( addressSize,  *dstRegister, &src                                )?;
+              }
+              else
+              {
+                self.encodeModRegRMdata     ( addressSize,  *srcRegister, &dst                                )?;
+              }
+            },
+        (
+          OperandType::Memory16               { .. } | OperandType::Memory32 { .. },
+          OperandType::GeneralPurposeRegister { number:   register,     .. }
+        )
+        =>  {
+              self.setOpcode                ( 0x86  | width                                                   );
+              self.encodeModRegRMdata       ( addressSize,  *register,    &dst                                )?;
+            },
+        (
+          OperandType::GeneralPurposeRegister { number:   register,     .. },
+          OperandType::Memory16               { .. } | OperandType::Memory32 { .. }
+        )
+        =>  {
+              self.setOpcode                ( 0x86  | width                                                   );
+              self.encodeModRegRMdata       ( addressSize,  *register,    &src                                )?;
+            },
+        ( _, _ )
+        =>  self.fail
+            (
+              format!
+              (
+                "Invalid Combination of Arguments ›{}‹, ›{}‹",
+                dst.to_string  ( self.size  ),
+                src.to_string  ( self.size  ),
+              )
+            )?,
+      }
+      Ok  ( Some  ( self.getLength() ) )
+    }
+    else
+    {
+      self.fail
+      (
+        format!
+        (
+          "Instruction Must Take Exactly Two Arguments, got {}",
+          self.operands.len(),
+        )
+      )
+    }
+  }
+
+  pub fn compileAtomicExchangeInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    operandSize:                        usize,
+    addressSize:                        usize,
+    opcode:                             u8,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet              ( architecture, InstructionSet::i486                              )?;
+    match self.operands.clone().as_slice()
+    {
+      [
+        dst @ OperandType::GeneralPurposeRegister { .. } |
+        dst @ OperandType::Memory16               { .. } |
+        dst @ OperandType::Memory32               { .. },
+        OperandType::GeneralPurposeRegister { number:   srcRegister,  .. }
+      ]
+      =>  {
+            self.encodeOperandSize          ( architecture, operandSize                                       )?;
+            let width                   =   if self.size == 1 { 0 } else { 1 };
+            self.setTwoByteOpcode           ( true                                                            );
+            self.setOpcode                  ( opcode  | width                                                 );
+            self.encodeModRegRMdata         ( addressSize,  *srcRegister, dst                                 )?;
+            Ok  ( Some  ( self.getLength() ) )
+          },
+      _
+      =>  self.fail ( "Instruction Must Take a Register or Memory and a Register Argument".to_string() ),
+    }
+  }
+
+  pub fn compileCompareExchange8Instruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet              ( architecture, InstructionSet::Pentium                           )?;
+    match self.operands.clone().as_slice()
+    {
+      [ operand @ OperandType::Memory16 { .. } |
+        operand @ OperandType::Memory32 { .. } ]
+      =>  if self.size == 8
+          {
+            self.setTwoByteOpcode           ( true                                                            );
+            self.setOpcode                  ( 0xc7                                                            );
+            self.encodeModRegRMdata         ( addressSize,  1,            operand                             )?;
+            Ok  ( Some  ( self.getLength() ) )
+          }
+          else
+          {
+            self.failOperandSize()
+          },
+      _
+      =>  self.fail ( "Instruction Must Take Exactly One Memory Argument".to_string() ),
+    }
+  }
+
+  pub fn compileByteSwapInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    operandSize:                        usize,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet              ( architecture, InstructionSet::i486                              )?;
+    match self.operands.clone().as_slice()
+    {
+      //  with a word register, the result is undefined
+      [ OperandType::GeneralPurposeRegister { number:   register,     .. } ]
+      =>  if self.size == 4 || self.size == 8
+          {
+            self.encodeOperandSize          ( architecture, operandSize                                       )?;
+            self.setTwoByteOpcode           ( true                                                            );
+            self.encodeOpcodeRegister       ( 0xc8,         *register                                         );
+            Ok  ( Some  ( self.getLength() ) )
+          }
+          else
+          {
+            self.failOperandSize()
+          },
+      _
+      =>  self.fail ( "Instruction Must Take Exactly One Register Argument".to_string() ),
+    }
+  }
+}
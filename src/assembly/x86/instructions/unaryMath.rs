@@ -0,0 +1,213 @@
+use super::
+{
+  Instruction,
+  InstructionType,
+  super::
+  {
+    AssemblyFeatures,
+    InstructionSet,
+    X86,
+    operands::
+    {
+      Operand,
+      OperandType,
+    },
+  },
+};
+
+use rand;
+
+macro_rules! theInstruction
+{
+  (
+    $theName:ident,
+    $theInstruction:expr
+  )
+  =>  {
+        //  mul and div are mnemonics, not the arithmetic operators of the builder
+        #[allow(clippy::should_implement_trait)]
+        pub fn $theName
+        (
+          mut self,
+          operand:                      impl Operand,
+        ) -> Self
+        {
+          let ( operandThis, operandSize )
+                                        =   operand.this();
+          self.instructions.push
+          (
+            Instruction
+            (
+              self.line,
+              self.features,
+              operandSize,
+              $theInstruction,
+              vec!  ( operandThis ),
+            )
+          );
+          self.line                     +=  1;
+          self
+        }
+      }
+}
+
+impl X86
+{
+  theInstruction! ( dec,  InstructionType::DEC  );
+  theInstruction! ( div,  InstructionType::DIV  );
+  theInstruction! ( idiv, InstructionType::IDIV );
+  theInstruction! ( imul, InstructionType::IMUL );
+
+  pub fn imulImm
+  (
+    mut self,
+    dst:                                impl Operand,
+    src:                                impl Operand,
+    factor:                             impl Operand,
+  ) -> Self
+  {
+    let ( dstThis,    dstSize )         =   dst.this();
+    let ( srcThis,    srcSize )         =   src.this();
+    let ( factorThis, _       )         =   factor.this();
+    self.instructions.push
+    (
+      Instruction
+      (
+        self.line,
+        self.features,
+        dstSize | srcSize,
+        InstructionType::IMUL,
+        vec!  ( dstThis,  srcThis,  factorThis  ),
+      )
+    );
+    self.line                           +=  1;
+    self
+  }
+  theInstruction! ( inc,  InstructionType::INC  );
+  theInstruction! ( mul,  InstructionType::MUL  );
+  theInstruction! ( neg,  InstructionType::NEG  );
+  theInstruction! ( not,  InstructionType::NOT  );
+}
+
+impl  Instruction
+{
+  pub fn compileUnaryMathInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    operandSize:                        usize,
+    addressSize:                        usize,
+    opcode:                             u8,
+    regField:                           u8,
+  ) -> Result<Option<usize>, String>
+  {
+    if self.operands.len() == 1
+    {
+      let operand                       =   self.operands [ 0 ].clone();
+      match &operand
+      {
+        //  inc and dec have one byte encodings for word and dword registers, which became the REX prefix in 64 bit mode
+        OperandType::GeneralPurposeRegister { number, .. }
+        if  opcode    ==  0xfe
+        &&  self.size !=  1
+        &&  operandSize !=  64
+        && !( self.features.hazFeature ( AssemblyFeatures::RandomOpcodeSize ) && rand::random() )
+        =>  {
+              self.encodeOperandSize        ( architecture, operandSize                                       )?;
+              self.setOpcode                ( 0x40  | regField  <<  3 | ( number & 7 )                        );
+            },
+        OperandType::GeneralPurposeRegister { .. } |
+        OperandType::Memory16               { .. } | OperandType::Memory32 { .. }
+        =>  {
+              self.encodeOperandSize        ( architecture, operandSize                                       )?;
+              self.setOpcode                ( if self.size == 1 { opcode } else { opcode | 1 }                );
+              self.encodeModRegRMdata       ( addressSize,  regField,     &operand                            )?;
+            },
+        _
+        =>  self.fail
+            (
+              format!
+              (
+                "Invalid Argument ›{}‹",
+                operand.to_string  ( self.size  ),
+              )
+            )?,
+      }
+      Ok  ( Some  ( self.getLength() ) )
+    }
+    else if self.operands.len() ==  3
+         && regField            ==  5
+    {
+      self.compileMultiplyImmediateInstruction  ( architecture, operandSize,  addressSize                     )
+    }
+    else
+    {
+      self.fail
+      (
+        format!
+        (
+          "Instruction Must Take Exactly One Argument, got {}",
+          self.operands.len(),
+        )
+      )
+    }
+  }
+
+  fn compileMultiplyImmediateInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    operandSize:                        usize,
+    addressSize:                        usize,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet              ( architecture, InstructionSet::i186                              )?;
+    let dst                             =   self.operands [ 0 ].clone();
+    let src                             =   self.operands [ 1 ].clone();
+    let factor                          =   self.operands [ 2 ].clone();
+    match ( &dst, &src, &factor )
+    {
+      (
+        OperandType::GeneralPurposeRegister { number:   dstRegister,  .. },
+        OperandType::GeneralPurposeRegister { .. } | OperandType::Memory16 { .. } | OperandType::Memory32 { .. },
+        OperandType::Constant               ( immediate                  )
+      )
+      if self.size != 1
+      =>  {
+            self.encodeOperandSize          ( architecture, operandSize                                       )?;
+            self.encodeModRegRMdata         ( addressSize,  *dstRegister, &src                                )?;
+            //  0x6b sign extends a byte, 0x69 takes an immediate of full size
+            if  *immediate >= -0x80
+            &&  *immediate <=  0x7f
+            && !( self.features.hazFeature ( AssemblyFeatures::RandomOpcodeSize ) && rand::random() )
+            {
+              self.setOpcode                ( 0x6b                                                            );
+              self.setImmediate             ( 1,            *immediate                                        );
+            }
+            else
+            {
+              self.setOpcode                ( 0x69                                                            );
+              self.encodeImmediate          ( self.size,    *immediate                                        )?;
+            }
+            Ok  ( Some  ( self.getLength() ) )
+          },
+      (
+        OperandType::GeneralPurposeRegister { .. },
+        OperandType::GeneralPurposeRegister { .. } | OperandType::Memory16 { .. } | OperandType::Memory32 { .. },
+        OperandType::Constant               ( _                          )
+      )
+      =>  self.failOperandSize(),
+      ( _, _, _ )
+      =>  self.fail
+          (
+            format!
+            (
+              "Invalid Combination of Arguments ›{}‹, ›{}‹, ›{}‹",
+              dst.to_string     ( self.size  ),
+              src.to_string     ( self.size  ),
+              factor.to_string  ( self.size  ),
+            )
+          ),
+    }
+  }
+}
@@ -0,0 +1,674 @@
+use super::
+{
+  Instruction,
+  InstructionType,
+  VectorOpcode,
+  super::
+  {
+    AssemblyFeatures,
+    InstructionSet,
+    X86,
+    operands::
+    {
+      Operand,
+      OperandType,
+    },
+  },
+};
+
+use rand;
+
+macro_rules! theInstruction
+{
+  (
+    $theName:ident,
+    $theInstruction:expr
+  )
+  =>  {
+        pub fn $theName
+        (
+          mut self,
+        ) -> Self
+        {
+          self.instructions.push
+          (
+            Instruction
+            (
+              self.line,
+              self.features,
+              0,
+              $theInstruction,
+              vec!  ( ),
+            )
+          );
+          self.line                     +=  1;
+          self
+        }
+      };
+  (
+    $theName:ident,
+    $theInstruction:expr,
+    dst,
+    src
+  )
+  =>  {
+        pub fn $theName
+        (
+          mut self,
+          dst:                          impl Operand,
+          src:                          impl Operand,
+        ) -> Self
+        {
+          let ( dstThis, dstSize )      =   dst.this();
+          let ( srcThis, srcSize )      =   src.this();
+          let size                      =   ( dstSize | srcSize ) as usize;
+          self.instructions.push
+          (
+            Instruction
+            (
+              self.line,
+              self.features,
+              size,
+              $theInstruction,
+              vec!  ( dstThis,  srcThis ),
+            )
+          );
+          self.line                     +=  1;
+          self
+        }
+      };
+  (
+    $theName:ident,
+    $theInstruction:expr,
+    dst,
+    src,
+    immediate
+  )
+  =>  {
+        pub fn $theName
+        (
+          mut self,
+          dst:                          impl Operand,
+          src:                          impl Operand,
+          immediate:                    impl Operand,
+        ) -> Self
+        {
+          let ( dstThis,        dstSize )   =   dst.this();
+          let ( srcThis,        srcSize )   =   src.this();
+          let ( immediateThis,  _       )   =   immediate.this();
+          let size                      =   ( dstSize | srcSize ) as usize;
+          self.instructions.push
+          (
+            Instruction
+            (
+              self.line,
+              self.features,
+              size,
+              $theInstruction,
+              vec!  ( dstThis,  srcThis,  immediateThis ),
+            )
+          );
+          self.line                     +=  1;
+          self
+        }
+      };
+  (
+    $theName:ident,
+    $theInstruction:expr,
+    dst,
+    src1,
+    src2
+  )
+  =>  {
+        pub fn $theName
+        (
+          mut self,
+          dst:                          impl Operand,
+          src1:                         impl Operand,
+          src2:                         impl Operand,
+        ) -> Self
+        {
+          let ( dstThis,  dstSize  )    =   dst.this();
+          let ( src1This, src1Size )    =   src1.this();
+          let ( src2This, src2Size )    =   src2.this();
+          let size                      =   ( dstSize | src1Size | src2Size ) as usize;
+          self.instructions.push
+          (
+            Instruction
+            (
+              self.line,
+              self.features,
+              size,
+              $theInstruction,
+              vec!  ( dstThis,  src1This, src2This  ),
+            )
+          );
+          self.line                     +=  1;
+          self
+        }
+      };
+  (
+    $theName:ident,
+    $theInstruction:expr,
+    dst,
+    src1,
+    src2,
+    immediate
+  )
+  =>  {
+        pub fn $theName
+        (
+          mut self,
+          dst:                          impl Operand,
+          src1:                         impl Operand,
+          src2:                         impl Operand,
+          immediate:                    impl Operand,
+        ) -> Self
+        {
+          let ( dstThis,        dstSize  )  =   dst.this();
+          let ( src1This,       src1Size )  =   src1.this();
+          let ( src2This,       src2Size )  =   src2.this();
+          let ( immediateThis,  _        )  =   immediate.this();
+          let size                      =   ( dstSize | src1Size | src2Size ) as usize;
+          self.instructions.push
+          (
+            Instruction
+            (
+              self.line,
+              self.features,
+              size,
+              $theInstruction,
+              vec!  ( dstThis,  src1This, src2This, immediateThis ),
+            )
+          );
+          self.line                     +=  1;
+          self
+        }
+      };
+}
+
+impl X86
+{
+  theInstruction! ( vaddpd,       InstructionType::VADDPD,        dst,  src1, src2            );
+  theInstruction! ( vaddps,       InstructionType::VADDPS,        dst,  src1, src2            );
+  theInstruction! ( vaddsd,       InstructionType::VADDSD,        dst,  src1, src2            );
+  theInstruction! ( vaddss,       InstructionType::VADDSS,        dst,  src1, src2            );
+  theInstruction! ( vandnpd,      InstructionType::VANDNPD,       dst,  src1, src2            );
+  theInstruction! ( vandnps,      InstructionType::VANDNPS,       dst,  src1, src2            );
+  theInstruction! ( vandpd,       InstructionType::VANDPD,        dst,  src1, src2            );
+  theInstruction! ( vandps,       InstructionType::VANDPS,        dst,  src1, src2            );
+  theInstruction! ( vbroadcastss, InstructionType::VBROADCASTSS,  dst,  src                   );
+  theInstruction! ( vcmppd,       InstructionType::VCMPPD,        dst,  src1, src2, immediate );
+  theInstruction! ( vcmpps,       InstructionType::VCMPPS,        dst,  src1, src2, immediate );
+  theInstruction! ( vcmpsd,       InstructionType::VCMPSD,        dst,  src1, src2, immediate );
+  theInstruction! ( vcmpss,       InstructionType::VCMPSS,        dst,  src1, src2, immediate );
+  theInstruction! ( vdivpd,       InstructionType::VDIVPD,        dst,  src1, src2            );
+  theInstruction! ( vdivps,       InstructionType::VDIVPS,        dst,  src1, src2            );
+  theInstruction! ( vdivsd,       InstructionType::VDIVSD,        dst,  src1, src2            );
+  theInstruction! ( vdivss,       InstructionType::VDIVSS,        dst,  src1, src2            );
+  theInstruction! ( vextractf128, InstructionType::VEXTRACTF128,  dst,  src,  immediate       );
+  theInstruction! ( vextracti128, InstructionType::VEXTRACTI128,  dst,  src,  immediate       );
+  theInstruction! ( vinsertf128,  InstructionType::VINSERTF128,   dst,  src1, src2, immediate );
+  theInstruction! ( vinserti128,  InstructionType::VINSERTI128,   dst,  src1, src2, immediate );
+  theInstruction! ( vmaxpd,       InstructionType::VMAXPD,        dst,  src1, src2            );
+  theInstruction! ( vmaxps,       InstructionType::VMAXPS,        dst,  src1, src2            );
+  theInstruction! ( vminpd,       InstructionType::VMINPD,        dst,  src1, src2            );
+  theInstruction! ( vminps,       InstructionType::VMINPS,        dst,  src1, src2            );
+  theInstruction! ( vmovapd,      InstructionType::VMOVAPD,       dst,  src                   );
+  theInstruction! ( vmovaps,      InstructionType::VMOVAPS,       dst,  src                   );
+  theInstruction! ( vmovdqa,      InstructionType::VMOVDQA,       dst,  src                   );
+  theInstruction! ( vmovdqu,      InstructionType::VMOVDQU,       dst,  src                   );
+  theInstruction! ( vmovupd,      InstructionType::VMOVUPD,       dst,  src                   );
+  theInstruction! ( vmovups,      InstructionType::VMOVUPS,       dst,  src                   );
+  theInstruction! ( vmulpd,       InstructionType::VMULPD,        dst,  src1, src2            );
+  theInstruction! ( vmulps,       InstructionType::VMULPS,        dst,  src1, src2            );
+  theInstruction! ( vmulsd,       InstructionType::VMULSD,        dst,  src1, src2            );
+  theInstruction! ( vmulss,       InstructionType::VMULSS,        dst,  src1, src2            );
+  theInstruction! ( vorpd,        InstructionType::VORPD,         dst,  src1, src2            );
+  theInstruction! ( vorps,        InstructionType::VORPS,         dst,  src1, src2            );
+  theInstruction! ( vpaddb,       InstructionType::VPADDB,        dst,  src1, src2            );
+  theInstruction! ( vpaddd,       InstructionType::VPADDD,        dst,  src1, src2            );
+  theInstruction! ( vpaddq,       InstructionType::VPADDQ,        dst,  src1, src2            );
+  theInstruction! ( vpaddw,       InstructionType::VPADDW,        dst,  src1, src2            );
+  theInstruction! ( vpand,        InstructionType::VPAND,         dst,  src1, src2            );
+  theInstruction! ( vpandn,       InstructionType::VPANDN,        dst,  src1, src2            );
+  theInstruction! ( vpbroadcastd, InstructionType::VPBROADCASTD,  dst,  src                   );
+  theInstruction! ( vpbroadcastq, InstructionType::VPBROADCASTQ,  dst,  src                   );
+  theInstruction! ( vpcmpeqb,     InstructionType::VPCMPEQB,      dst,  src1, src2            );
+  theInstruction! ( vpcmpeqd,     InstructionType::VPCMPEQD,      dst,  src1, src2            );
+  theInstruction! ( vpcmpeqw,     InstructionType::VPCMPEQW,      dst,  src1, src2            );
+  theInstruction! ( vperm2f128,   InstructionType::VPERM2F128,    dst,  src1, src2, immediate );
+  theInstruction! ( vperm2i128,   InstructionType::VPERM2I128,    dst,  src1, src2, immediate );
+  theInstruction! ( vpermpd,      InstructionType::VPERMPD,       dst,  src,  immediate       );
+  theInstruction! ( vpermq,       InstructionType::VPERMQ,        dst,  src,  immediate       );
+  theInstruction! ( vpmulld,      InstructionType::VPMULLD,       dst,  src1, src2            );
+  theInstruction! ( vpor,         InstructionType::VPOR,          dst,  src1, src2            );
+  theInstruction! ( vpsllvd,      InstructionType::VPSLLVD,       dst,  src1, src2            );
+  theInstruction! ( vpsllvq,      InstructionType::VPSLLVQ,       dst,  src1, src2            );
+  theInstruction! ( vpsravd,      InstructionType::VPSRAVD,       dst,  src1, src2            );
+  theInstruction! ( vpsrlvd,      InstructionType::VPSRLVD,       dst,  src1, src2            );
+  theInstruction! ( vpsrlvq,      InstructionType::VPSRLVQ,       dst,  src1, src2            );
+  theInstruction! ( vpsubb,       InstructionType::VPSUBB,        dst,  src1, src2            );
+  theInstruction! ( vpsubd,       InstructionType::VPSUBD,        dst,  src1, src2            );
+  theInstruction! ( vpsubq,       InstructionType::VPSUBQ,        dst,  src1, src2            );
+  theInstruction! ( vpsubw,       InstructionType::VPSUBW,        dst,  src1, src2            );
+  theInstruction! ( vpxor,        InstructionType::VPXOR,         dst,  src1, src2            );
+  theInstruction! ( vsqrtpd,      InstructionType::VSQRTPD,       dst,  src                   );
+  theInstruction! ( vsqrtps,      InstructionType::VSQRTPS,       dst,  src                   );
+  theInstruction! ( vsubpd,       InstructionType::VSUBPD,        dst,  src1, src2            );
+  theInstruction! ( vsubps,       InstructionType::VSUBPS,        dst,  src1, src2            );
+  theInstruction! ( vsubsd,       InstructionType::VSUBSD,        dst,  src1, src2            );
+  theInstruction! ( vsubss,       InstructionType::VSUBSS,        dst,  src1, src2            );
+  theInstruction! ( vxorpd,       InstructionType::VXORPD,        dst,  src1, src2            );
+  theInstruction! ( vxorps,       InstructionType::VXORPS,        dst,  src1, src2            );
+  theInstruction! ( vzeroall,     InstructionType::VZEROALL                                   );
+  theInstruction! ( vzeroupper,   InstructionType::VZEROUPPER                                 );
+}
+
+impl  Instruction
+{
+  //  the prefix is compiled into the VEX prefix later, when all extension bits are known
+  pub fn encodeVectorOperands
+  (
+    &mut self,
+    addressSize:                        usize,
+    opcode:                             VectorOpcode,
+    long:                               bool,
+    regField:                           u8,
+    vectorRegister:                     u8,
+    operand:                            &OperandType,
+  ) -> Result<(), String>
+  {
+    self.setMandatoryPrefix                 ( opcode.prefix                                                   );
+    self.setVectorMap                       ( opcode.map                                                      );
+    self.setVectorRegister                  ( vectorRegister                                                  );
+    self.setVectorLength                    ( long as u8                                                      );
+    self.setOpcode                          ( opcode.opcode                                                   );
+    self.encodeModRegRMdata                 ( addressSize,  regField,     operand                             )
+  }
+
+  //  all registers are either XMM or YMM registers, a memory size of zero means the memory is as wide as the vector
+  pub fn encodeVectorInstruction
+  (
+    &mut self,
+    addressSize:                        usize,
+    opcode:                             VectorOpcode,
+    memorySize:                         usize,
+    regField:                           u8,
+    vectorRegister:                     u8,
+    operand:                            &OperandType,
+  ) -> Result<(), String>
+  {
+    let   vectorSize                    =   if self.size & 32 != 0 { 32 } else { 16 };
+    if  memorySize  != 0
+    &&  vectorSize  != 16
+    {
+      return self.failOperandSize();
+    }
+    match operand
+    {
+      OperandType::MulitMediaRegister       ( _   )
+      if self.size == vectorSize
+      =>  {},
+      OperandType::Memory16                 { .. } |
+      OperandType::Memory32                 { .. }
+      if  self.size == vectorSize
+      ||  self.size == vectorSize | memorySize
+      =>  {},
+      OperandType::MulitMediaRegister       ( _   ) |
+      OperandType::Memory16                 { .. } |
+      OperandType::Memory32                 { .. }
+      =>  self.failOperandSize              (                                                                 )?,
+      _
+      =>  self.fail ( "Instruction Must Take XMM or YMM Registers and a XMM or YMM Register or Memory Argument".to_string() )?,
+    }
+    self.encodeVectorOperands               ( addressSize,  opcode,       vectorSize == 32, regField, vectorRegister, operand )
+  }
+
+  //  AVX requires at least the Sandy Bridge, AVX2 at least the Haswell
+  pub fn compileVectorInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+    minimum:                            InstructionSet,
+    opcode:                             VectorOpcode,
+    memorySize:                         usize,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet              ( architecture, minimum                                           )?;
+    match self.operands.clone().as_slice()
+    {
+      [
+        OperandType::MulitMediaRegister     ( dstRegister ),
+        OperandType::MulitMediaRegister     ( srcRegister ),
+        src
+      ]
+      =>  {
+            self.encodeVectorInstruction    ( addressSize,  opcode,       memorySize,   *dstRegister, *srcRegister, src )?;
+            Ok  ( Some  ( self.getLength() ) )
+          },
+      _
+      =>  self.fail ( "Instruction Must Take Two XMM or YMM Registers and a XMM or YMM Register or Memory Argument".to_string() ),
+    }
+  }
+
+  //  AVX only extended the integer instructions to XMM registers, AVX2 to YMM registers
+  pub fn compileVectorIntegerInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+    map:                                u8,
+    opcode:                             u8,
+  ) -> Result<Option<usize>, String>
+  {
+    if self.size & 32 != 0
+    {
+      self.requireInstructionSet            ( architecture, InstructionSet::Haswell                           )?;
+    }
+    self.compileVectorInstruction           ( architecture, addressSize,  InstructionSet::SandyBridge,  VectorOpcode  ( 0x66, map,  opcode  ),  0 )
+  }
+
+  //  the variable shifts are distinguished by VEX.W for double and quad words
+  pub fn compileVectorShiftInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+    opcode:                             u8,
+    wide:                               bool,
+  ) -> Result<Option<usize>, String>
+  {
+    let   length                        =   self.compileVectorInstruction ( architecture, addressSize,  InstructionSet::Haswell,  VectorOpcode  ( 0x66, 2,  opcode  ),  0 )?;
+    self.setVectorWidth                     ( wide                                                            );
+    Ok  ( length  )
+  }
+
+  pub fn compileVectorUnaryInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+    prefix:                             u8,
+    opcode:                             u8,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet              ( architecture, InstructionSet::SandyBridge                       )?;
+    match self.operands.clone().as_slice()
+    {
+      [
+        OperandType::MulitMediaRegister     ( dstRegister ),
+        src
+      ]
+      =>  {
+            self.encodeVectorInstruction    ( addressSize,  VectorOpcode  ( prefix, 1,  opcode  ),  0,  *dstRegister, 0,  src )?;
+            Ok  ( Some  ( self.getLength() ) )
+          },
+      _
+      =>  self.fail ( "Instruction Must Take a XMM or YMM Register and a XMM or YMM Register or Memory Argument".to_string() ),
+    }
+  }
+
+  pub fn compileVectorMoveInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+    loadOpcode:                         VectorOpcode,
+    storeOpcode:                        u8,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet              ( architecture, InstructionSet::SandyBridge                       )?;
+    let   storeOpcode                   =   VectorOpcode  ( loadOpcode.prefix,  loadOpcode.map, storeOpcode   );
+    match self.operands.clone().as_slice()
+    {
+      //  the store form works with two registers as well
+      [
+        dst @ OperandType::MulitMediaRegister     ( _   ),
+        OperandType::MulitMediaRegister     ( srcRegister )
+      ]
+      if  self.features.hazFeature ( AssemblyFeatures::RandomOpcode )
+      &&  rand::random()
+      =>  self.encodeVectorInstruction      ( addressSize,  storeOpcode,  0,            *srcRegister, 0,            dst )?,
+      [
+        OperandType::MulitMediaRegister     ( dstRegister ),
+        src
+      ]
+      =>  self.encodeVectorInstruction      ( addressSize,  loadOpcode,   0,            *dstRegister, 0,            src )?,
+      [
+        dst @ OperandType::Memory16               { .. } |
+        dst @ OperandType::Memory32               { .. },
+        OperandType::MulitMediaRegister     ( srcRegister )
+      ]
+      =>  self.encodeVectorInstruction      ( addressSize,  storeOpcode,  0,            *srcRegister, 0,            dst )?,
+      _
+      =>  return self.fail ( "Instruction Must Take a XMM or YMM Register and a XMM or YMM Register or Memory Argument".to_string() ),
+    }
+    Ok  ( Some  ( self.getLength() ) )
+  }
+
+  //  the predicates 8 to 31 were added with AVX
+  pub fn compileVectorCompareInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+    prefix:                             u8,
+    memorySize:                         usize,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet              ( architecture, InstructionSet::SandyBridge                       )?;
+    match self.operands.clone().as_slice()
+    {
+      [
+        OperandType::MulitMediaRegister     ( dstRegister ),
+        OperandType::MulitMediaRegister     ( srcRegister ),
+        src,
+        OperandType::Constant               ( predicate   )
+      ]
+      =>  {
+            self.encodeVectorInstruction    ( addressSize,  VectorOpcode  ( prefix, 1,  0xc2  ),  memorySize, *dstRegister, *srcRegister, src )?;
+            if  *predicate >= 0
+            &&  *predicate <= 31
+            {
+              self.setImmediate             ( 1,            *predicate                                        );
+              Ok  ( Some  ( self.getLength() ) )
+            }
+            else
+            {
+              self.failOutOfBounds          ( 0,            31,           *predicate                          )
+            }
+          },
+      _
+      =>  self.fail ( "Instruction Must Take Two XMM or YMM Registers, a XMM or YMM Register or Memory and an Immediate Argument".to_string() ),
+    }
+  }
+
+  //  the source is either a XMM register or memory of the size of the element, the destination might be a YMM register
+  pub fn compileVectorBroadcastInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+    minimum:                            InstructionSet,
+    opcode:                             u8,
+    memorySize:                         usize,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet              ( architecture, minimum                                           )?;
+    match self.operands.clone().as_slice()
+    {
+      [
+        OperandType::MulitMediaRegister     ( dstRegister ),
+        src @ OperandType::MulitMediaRegister     ( _   )
+      ]
+      if  self.size == 16
+      ||  self.size == 32 | 16
+      =>  {
+            self.requireInstructionSet      ( architecture, InstructionSet::Haswell                           )?;
+            self.encodeVectorOperands       ( addressSize,  VectorOpcode  ( 0x66, 2,  opcode  ),  self.size != 16,  *dstRegister, 0,  src )?;
+            Ok  ( Some  ( self.getLength() ) )
+          },
+      [
+        OperandType::MulitMediaRegister     ( dstRegister ),
+        src @ OperandType::Memory16               { .. } |
+        src @ OperandType::Memory32               { .. }
+      ]
+      if  self.size == 16
+      ||  self.size == 16 | memorySize
+      ||  self.size == 32
+      ||  self.size == 32 | memorySize
+      =>  {
+            self.encodeVectorOperands       ( addressSize,  VectorOpcode  ( 0x66, 2,  opcode  ),  self.size & 32 != 0,  *dstRegister, 0,  src )?;
+            Ok  ( Some  ( self.getLength() ) )
+          },
+      [
+        OperandType::MulitMediaRegister     ( _   ),
+        OperandType::MulitMediaRegister     ( _   ) |
+        OperandType::Memory16               { .. } |
+        OperandType::Memory32               { .. }
+      ]
+      =>  self.failOperandSize(),
+      _
+      =>  self.fail ( "Instruction Must Take a XMM or YMM Register and a XMM Register or Memory Argument".to_string() ),
+    }
+  }
+
+  //  the permutations only exist for YMM registers
+  pub fn compileVectorPermuteInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+    minimum:                            InstructionSet,
+    opcode:                             u8,
+    wide:                               bool,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet              ( architecture, minimum                                           )?;
+    if self.size & 32 == 0
+    {
+      return self.failOperandSize();
+    }
+    let   immediate                     =   match self.operands.clone().as_slice()
+                                            {
+                                              [
+                                                OperandType::MulitMediaRegister     ( dstRegister ),
+                                                OperandType::MulitMediaRegister     ( srcRegister ),
+                                                src,
+                                                OperandType::Constant               ( immediate   )
+                                              ]
+                                              if !wide
+                                              =>  {
+                                                    self.encodeVectorInstruction  ( addressSize,  VectorOpcode  ( 0x66, 3,  opcode  ),  0,  *dstRegister, *srcRegister, src )?;
+                                                    *immediate
+                                                  },
+                                              [
+                                                OperandType::MulitMediaRegister     ( dstRegister ),
+                                                src,
+                                                OperandType::Constant               ( immediate   )
+                                              ]
+                                              if wide
+                                              =>  {
+                                                    self.encodeVectorInstruction  ( addressSize,  VectorOpcode  ( 0x66, 3,  opcode  ),  0,  *dstRegister, 0,            src )?;
+                                                    *immediate
+                                                  },
+                                              _
+                                              =>  return self.fail ( "Instruction Must Take YMM Registers, a YMM Register or Memory and an Immediate Argument".to_string() ),
+                                            };
+    self.setVectorWidth                     ( wide                                                            );
+    if ( 0 ..= 0xff ).contains ( &immediate )
+    {
+      self.setImmediate                     ( 1,            immediate                                         );
+      Ok  ( Some  ( self.getLength() ) )
+    }
+    else
+    {
+      self.failOutOfBounds                  ( 0,            0xff,         immediate                           )
+    }
+  }
+
+  //  insert and extract move the upper or lower half of a YMM register, therefore the instruction haz two sizes
+  pub fn compileVectorLaneInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    addressSize:                        usize,
+    minimum:                            InstructionSet,
+    opcode:                             u8,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet              ( architecture, minimum                                           )?;
+    let   immediate                     =   match self.operands.clone().as_slice()
+                                            {
+                                              [
+                                                OperandType::MulitMediaRegister     ( dstRegister ),
+                                                OperandType::MulitMediaRegister     ( srcRegister ),
+                                                src @ OperandType::MulitMediaRegister     ( _   ) |
+                                                src @ OperandType::Memory16               { .. } |
+                                                src @ OperandType::Memory32               { .. },
+                                                OperandType::Constant               ( immediate   )
+                                              ]
+                                              if  self.size == 32 | 16
+                                              ||  self.size == 32 && !matches! ( src, OperandType::MulitMediaRegister ( _ ) )
+                                              =>  {
+                                                    self.encodeVectorOperands     ( addressSize,  VectorOpcode  ( 0x66, 3,  opcode  ),  true, *dstRegister, *srcRegister, src )?;
+                                                    *immediate
+                                                  },
+                                              [
+                                                dst @ OperandType::MulitMediaRegister     ( _   ) |
+                                                dst @ OperandType::Memory16               { .. } |
+                                                dst @ OperandType::Memory32               { .. },
+                                                OperandType::MulitMediaRegister     ( srcRegister ),
+                                                OperandType::Constant               ( immediate   )
+                                              ]
+                                              if  self.size == 32 | 16
+                                              ||  self.size == 32 && !matches! ( dst, OperandType::MulitMediaRegister ( _ ) )
+                                              =>  {
+                                                    self.encodeVectorOperands     ( addressSize,  VectorOpcode  ( 0x66, 3,  opcode  ),  true, *srcRegister, 0,            dst )?;
+                                                    *immediate
+                                                  },
+                                              _
+                                              =>  return self.fail ( "Instruction Must Take a YMM Register, a XMM Register or Memory and an Immediate Argument".to_string() ),
+                                            };
+    if ( 0 ..= 1 ).contains ( &immediate )
+    {
+      self.setImmediate                     ( 1,            immediate                                         );
+      Ok  ( Some  ( self.getLength() ) )
+    }
+    else
+    {
+      self.failOutOfBounds                  ( 0,            1,            immediate                           )
+    }
+  }
+
+  //  VEX.L selects between clearing the upper halves and clearing all registers
+  pub fn compileVectorZeroInstruction
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    long:                               bool,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet              ( architecture, InstructionSet::SandyBridge                       )?;
+    if self.operands.is_empty()
+    {
+      self.setVectorMap                     ( 1                                                               );
+      self.setVectorLength                  ( long as u8                                                      );
+      self.setOpcode                        ( 0x77                                                            );
+      Ok  ( Some  ( self.getLength() ) )
+    }
+    else
+    {
+      self.fail
+      (
+        format!
+        (
+          "Instruction Takes No Arguments, got {}",
+          self.operands.len(),
+        )
+      )
+    }
+  }
+}
@@ -4,6 +4,7 @@ use super::
   InstructionType,
   super::
   {
+    InstructionSet,
     X86,
   },
 };
@@ -39,40 +40,64 @@ macro_rules! theInstruction
 
 impl X86
 {
-  theInstruction! ( aaa,    InstructionType::AAA    );
-  theInstruction! ( aas,    InstructionType::AAS    );
-  theInstruction! ( cbw,    InstructionType::CBW    );
-  theInstruction! ( clc,    InstructionType::CLC    );
-  theInstruction! ( cld,    InstructionType::CLD    );
-  theInstruction! ( cli,    InstructionType::CLI    );
-  theInstruction! ( cmc,    InstructionType::CMC    );
-  theInstruction! ( cmpsb,  InstructionType::CMPSB  );
-  theInstruction! ( cmpsw,  InstructionType::CMPSW  );
-  theInstruction! ( cwd,    InstructionType::CWD    );
-  theInstruction! ( daa,    InstructionType::DAA    );
-  theInstruction! ( das,    InstructionType::DAS    );
-  theInstruction! ( hlt,    InstructionType::HLT    );
-  theInstruction! ( int3,   InstructionType::INT3   );
-  theInstruction! ( into,   InstructionType::INTO   );
-  theInstruction! ( iret,   InstructionType::IRET   );
-  theInstruction! ( lahf,   InstructionType::LAHF   );
-  theInstruction! ( lodsb,  InstructionType::LODSB  );
-  theInstruction! ( lodsw,  InstructionType::LODSW  );
-  theInstruction! ( movsb,  InstructionType::MOVSB  );
-  theInstruction! ( movsw,  InstructionType::MOVSW  );
-  theInstruction! ( popf,   InstructionType::POPF   );
-  theInstruction! ( pushf,  InstructionType::PUSHF  );
-  theInstruction! ( sahf,   InstructionType::SAHF   );
-  theInstruction! ( salc,   InstructionType::SALC   );
-  theInstruction! ( scasb,  InstructionType::SCASB  );
-  theInstruction! ( scasw,  InstructionType::SCASW  );
-  theInstruction! ( stc,    InstructionType::STC    );
-  theInstruction! ( std,    InstructionType::STD    );
-  theInstruction! ( sti,    InstructionType::STI    );
-  theInstruction! ( stosb,  InstructionType::STOSB  );
-  theInstruction! ( stosw,  InstructionType::STOSW  );
-  theInstruction! ( wait,   InstructionType::WAIT   );
-  theInstruction! ( xlat,   InstructionType::XLAT   );
+  theInstruction! ( aaa,      InstructionType::AAA      );
+  theInstruction! ( aad,      InstructionType::AAD      );
+  theInstruction! ( aam,      InstructionType::AAM      );
+  theInstruction! ( aas,      InstructionType::AAS      );
+  theInstruction! ( cbw,      InstructionType::CBW      );
+  theInstruction! ( clc,      InstructionType::CLC      );
+  theInstruction! ( cld,      InstructionType::CLD      );
+  theInstruction! ( cli,      InstructionType::CLI      );
+  theInstruction! ( clts,     InstructionType::CLTS     );
+  theInstruction! ( cmc,      InstructionType::CMC      );
+  theInstruction! ( cmpsb,    InstructionType::CMPSB    );
+  theInstruction! ( cmpsw,    InstructionType::CMPSW    );
+  theInstruction! ( cpuid,    InstructionType::CPUID    );
+  theInstruction! ( cwd,      InstructionType::CWD      );
+  theInstruction! ( daa,      InstructionType::DAA      );
+  theInstruction! ( das,      InstructionType::DAS      );
+  theInstruction! ( emms,     InstructionType::EMMS     );
+  theInstruction! ( fcompp,   InstructionType::FCOMPP   );
+  theInstruction! ( fninit,   InstructionType::FNINIT   );
+  theInstruction! ( hlt,      InstructionType::HLT      );
+  theInstruction! ( insb,     InstructionType::INSB     );
+  theInstruction! ( insw,     InstructionType::INSW     );
+  theInstruction! ( int3,     InstructionType::INT3     );
+  theInstruction! ( into,     InstructionType::INTO     );
+  theInstruction! ( iret,     InstructionType::IRET     );
+  theInstruction! ( lahf,     InstructionType::LAHF     );
+  theInstruction! ( leave,    InstructionType::LEAVE    );
+  theInstruction! ( lodsb,    InstructionType::LODSB    );
+  theInstruction! ( lodsw,    InstructionType::LODSW    );
+  theInstruction! ( movsb,    InstructionType::MOVSB    );
+  theInstruction! ( movsw,    InstructionType::MOVSW    );
+  theInstruction! ( nop,      InstructionType::NOP      );
+  theInstruction! ( outsb,    InstructionType::OUTSB    );
+  theInstruction! ( outsw,    InstructionType::OUTSW    );
+  theInstruction! ( popa,     InstructionType::POPA     );
+  theInstruction! ( popf,     InstructionType::POPF     );
+  theInstruction! ( pusha,    InstructionType::PUSHA    );
+  theInstruction! ( pushf,    InstructionType::PUSHF    );
+  theInstruction! ( rdmsr,    InstructionType::RDMSR    );
+  theInstruction! ( rdpmc,    InstructionType::RDPMC    );
+  theInstruction! ( rdtsc,    InstructionType::RDTSC    );
+  theInstruction! ( retf,     InstructionType::RETF     );
+  theInstruction! ( retn,     InstructionType::RETN     );
+  theInstruction! ( sahf,     InstructionType::SAHF     );
+  theInstruction! ( salc,     InstructionType::SALC     );
+  theInstruction! ( scasb,    InstructionType::SCASB    );
+  theInstruction! ( scasw,    InstructionType::SCASW    );
+  theInstruction! ( stc,      InstructionType::STC      );
+  theInstruction! ( std,      InstructionType::STD      );
+  theInstruction! ( sti,      InstructionType::STI      );
+  theInstruction! ( stosb,    InstructionType::STOSB    );
+  theInstruction! ( stosw,    InstructionType::STOSW    );
+  theInstruction! ( sysenter, InstructionType::SYSENTER );
+  theInstruction! ( sysexit,  InstructionType::SYSEXIT  );
+  theInstruction! ( ud2,      InstructionType::UD2      );
+  theInstruction! ( wait,     InstructionType::WAIT     );
+  theInstruction! ( wrmsr,    InstructionType::WRMSR    );
+  theInstruction! ( xlat,     InstructionType::XLAT     );
 }
 
 impl  Instruction
@@ -86,4 +111,30 @@ impl  Instruction
     self.setOpcode  ( opcode  );
     Ok  ( Some  ( 1 ) )
   }
-}
\ No newline at end of file
+
+  pub fn compileZeroOperandInstructionSince
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    minimum:                            InstructionSet,
+    opcode:                             u8,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet          ( architecture, minimum )?;
+    self.compileZeroOperandInstruction  ( opcode                )
+  }
+
+  pub fn compileTwoByteZeroOperandInstructionSince
+  (
+    &mut self,
+    architecture:                       InstructionSet,
+    minimum:                            InstructionSet,
+    opcode:                             u8,
+  ) -> Result<Option<usize>, String>
+  {
+    self.requireInstructionSet          ( architecture, minimum )?;
+    self.setTwoByteOpcode               ( true                  );
+    self.setOpcode                      ( opcode                );
+    Ok  ( Some  ( 2 ) )
+  }
+}
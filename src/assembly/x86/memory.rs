@@ -9,6 +9,10 @@ use super::
   {
     SegmentRegisterNumber,
   },
+  symbols::
+  {
+    SymbolIdentifier,
+  },
 };
 
 pub struct Memory16
@@ -55,6 +59,83 @@ impl Operand                            for Memory16
   }
 }
 
+pub struct Intersegment
+{
+  segment:                              i128,
+  offset:                               i128,
+}
+
+pub fn Intersegment
+(
+  segment:                              i128,
+  offset:                               i128,
+) ->  Intersegment
+{
+  Intersegment
+  {
+    segment,
+    offset,
+  }
+}
+
+impl Operand                            for Intersegment
+{
+  fn this
+  (
+    self
+  ) ->  ( OperandType, usize )
+  {
+    (
+      OperandType::Intersegment
+      {
+        offset:                         self.offset,
+        segment:                        self.segment,
+      },
+      0,
+    )
+  }
+}
+
+pub struct RipRelative
+{
+  size:                                 usize,
+  segment:                              SegmentRegisterNumber,
+  target:                               SymbolIdentifier,
+}
+
+pub fn RipRelative
+(
+  size:                                 usize,
+  segment:                              SegmentRegisterNumber,
+  target:                               SymbolIdentifier,
+) ->  RipRelative
+{
+  RipRelative
+  {
+    size,
+    segment,
+    target,
+  }
+}
+
+impl Operand                            for RipRelative
+{
+  fn this
+  (
+    self
+  ) ->  ( OperandType, usize )
+  {
+    (
+      OperandType::RipRelative
+      {
+        segment:                        self.segment,
+        target:                         self.target,
+      },
+      self.size,
+    )
+  }
+}
+
 #[derive(Clone,Copy,Debug,PartialEq,PartialOrd)]
 pub enum Memory16Registers
 {
@@ -70,6 +151,11 @@ pub enum Memory16Registers
   INVALID                               =   0xff,
 }
 
+//  base or index of 32 bit addressing, that is not present
+pub const Memory32NoRegister:           u8  =   0xff;
+//  base of 64 bit addressing relative to the instruction pointer
+pub const Memory32InstructionPointer:   u8  =   0xfe;
+
 #[macro_export]
 macro_rules! x86Mem16finally
 {
@@ -120,6 +206,91 @@ macro_rules! x86Mem16
   ( dword         $sreg:tt  : [ $(  $token:tt )+  ] ) =>  { x86Mem16segment!  ( 4,      $sreg,  $( $token )* ) };
   ( qword                     [ $(  $token:tt )+  ] ) =>  { x86Mem16segment!  ( 8,      @,      $( $token )* ) };
   ( qword         $sreg:tt  : [ $(  $token:tt )+  ] ) =>  { x86Mem16segment!  ( 8,      $sreg,  $( $token )* ) };
+  ( tword                     [ $(  $token:tt )+  ] ) =>  { x86Mem16segment!  ( 10,     @,      $( $token )* ) };
+  ( tword         $sreg:tt  : [ $(  $token:tt )+  ] ) =>  { x86Mem16segment!  ( 10,     $sreg,  $( $token )* ) };
+  ( oword                     [ $(  $token:tt )+  ] ) =>  { x86Mem16segment!  ( 16,     @,      $( $token )* ) };
+  ( oword         $sreg:tt  : [ $(  $token:tt )+  ] ) =>  { x86Mem16segment!  ( 16,     $sreg,  $( $token )* ) };
+  ( yword                     [ $(  $token:tt )+  ] ) =>  { x86Mem16segment!  ( 32,     @,      $( $token )* ) };
+  ( yword         $sreg:tt  : [ $(  $token:tt )+  ] ) =>  { x86Mem16segment!  ( 32,     $sreg,  $( $token )* ) };
+  ( zword                     [ $(  $token:tt )+  ] ) =>  { x86Mem16segment!  ( 64,     @,      $( $token )* ) };
+  ( zword         $sreg:tt  : [ $(  $token:tt )+  ] ) =>  { x86Mem16segment!  ( 64,     $sreg,  $( $token )* ) };
   ( $size:literal             [ $(  $token:tt )+  ] ) =>  { x86Mem16segment!  ( $size,  @,      $( $token )* ) };
   ( $size:literal $sreg:tt  : [ $(  $token:tt )+  ] ) =>  { x86Mem16segment!  ( $size,  $sreg,  $( $token )* ) };
-}
\ No newline at end of file
+}
+
+#[macro_export]
+macro_rules! x86Mem32finally
+{
+  ( $width:expr, $size:expr, $segment:expr, $(  $token:tt )* )
+  =>  {
+        Expression
+        (
+          vec!
+          [
+            $(
+              nextToken!
+              (
+                $token
+              ),
+            )*
+            ExpressionToken::Memory32
+            {
+              width:                    $width,
+              size:                     $size,
+              segment:                  $segment,
+              base:                     Memory32NoRegister,
+              scale:                    1,
+              index:                    Memory32NoRegister,
+              displacement:             0,
+            }
+          ]
+        )
+      };
+}
+
+#[macro_export]
+macro_rules! x86Mem32segment
+{
+  ( $width:expr, $size:expr, cs, $(  $token:tt )* )  =>  { x86Mem32finally!  ( $width,  $size,  SegmentRegisterNumber::CS,      $( $token )*  ) };
+  ( $width:expr, $size:expr, ss, $(  $token:tt )* )  =>  { x86Mem32finally!  ( $width,  $size,  SegmentRegisterNumber::SS,      $( $token )*  ) };
+  ( $width:expr, $size:expr, ds, $(  $token:tt )* )  =>  { x86Mem32finally!  ( $width,  $size,  SegmentRegisterNumber::DS,      $( $token )*  ) };
+  ( $width:expr, $size:expr, es, $(  $token:tt )* )  =>  { x86Mem32finally!  ( $width,  $size,  SegmentRegisterNumber::ES,      $( $token )*  ) };
+  ( $width:expr, $size:expr, fs, $(  $token:tt )* )  =>  { x86Mem32finally!  ( $width,  $size,  SegmentRegisterNumber::FS,      $( $token )*  ) };
+  ( $width:expr, $size:expr, gs, $(  $token:tt )* )  =>  { x86Mem32finally!  ( $width,  $size,  SegmentRegisterNumber::GS,      $( $token )*  ) };
+  ( $width:expr, $size:expr, @,  $(  $token:tt )* )  =>  { x86Mem32finally!  ( $width,  $size,  SegmentRegisterNumber::Default, $( $token )*  ) };
+}
+
+#[macro_export]
+macro_rules! x86Mem32width
+{
+  ( $width:expr, byte                      [ $(  $token:tt )+  ] ) =>  { x86Mem32segment!  ( $width, 1,      @,      $( $token )* ) };
+  ( $width:expr, byte          $sreg:tt  : [ $(  $token:tt )*  ] ) =>  { x86Mem32segment!  ( $width, 1,      $sreg,  $( $token )* ) };
+  ( $width:expr, word                      [ $(  $token:tt )+  ] ) =>  { x86Mem32segment!  ( $width, 2,      @,      $( $token )* ) };
+  ( $width:expr, word          $sreg:tt  : [ $(  $token:tt )+  ] ) =>  { x86Mem32segment!  ( $width, 2,      $sreg,  $( $token )* ) };
+  ( $width:expr, dword                     [ $(  $token:tt )+  ] ) =>  { x86Mem32segment!  ( $width, 4,      @,      $( $token )* ) };
+  ( $width:expr, dword         $sreg:tt  : [ $(  $token:tt )+  ] ) =>  { x86Mem32segment!  ( $width, 4,      $sreg,  $( $token )* ) };
+  ( $width:expr, qword                     [ $(  $token:tt )+  ] ) =>  { x86Mem32segment!  ( $width, 8,      @,      $( $token )* ) };
+  ( $width:expr, qword         $sreg:tt  : [ $(  $token:tt )+  ] ) =>  { x86Mem32segment!  ( $width, 8,      $sreg,  $( $token )* ) };
+  ( $width:expr, tword                     [ $(  $token:tt )+  ] ) =>  { x86Mem32segment!  ( $width, 10,     @,      $( $token )* ) };
+  ( $width:expr, tword         $sreg:tt  : [ $(  $token:tt )+  ] ) =>  { x86Mem32segment!  ( $width, 10,     $sreg,  $( $token )* ) };
+  ( $width:expr, oword                     [ $(  $token:tt )+  ] ) =>  { x86Mem32segment!  ( $width, 16,     @,      $( $token )* ) };
+  ( $width:expr, oword         $sreg:tt  : [ $(  $token:tt )+  ] ) =>  { x86Mem32segment!  ( $width, 16,     $sreg,  $( $token )* ) };
+  ( $width:expr, yword                     [ $(  $token:tt )+  ] ) =>  { x86Mem32segment!  ( $width, 32,     @,      $( $token )* ) };
+  ( $width:expr, yword         $sreg:tt  : [ $(  $token:tt )+  ] ) =>  { x86Mem32segment!  ( $width, 32,     $sreg,  $( $token )* ) };
+  ( $width:expr, zword                     [ $(  $token:tt )+  ] ) =>  { x86Mem32segment!  ( $width, 64,     @,      $( $token )* ) };
+  ( $width:expr, zword         $sreg:tt  : [ $(  $token:tt )+  ] ) =>  { x86Mem32segment!  ( $width, 64,     $sreg,  $( $token )* ) };
+  ( $width:expr, $size:literal             [ $(  $token:tt )+  ] ) =>  { x86Mem32segment!  ( $width, $size,  @,      $( $token )* ) };
+  ( $width:expr, $size:literal $sreg:tt  : [ $(  $token:tt )+  ] ) =>  { x86Mem32segment!  ( $width, $size,  $sreg,  $( $token )* ) };
+}
+
+#[macro_export]
+macro_rules! x86Mem32
+{
+  ( $(  $token:tt )+  ) =>  { x86Mem32width! ( 32, $( $token )* ) };
+}
+
+#[macro_export]
+macro_rules! x86Mem64
+{
+  ( $(  $token:tt )+  ) =>  { x86Mem32width! ( 64, $( $token )* ) };
+}
@@ -14,6 +14,7 @@ pub use self::
     AddressSizeOverride,
     BranchTaken,
     BranchNotTaken,
+    FourByteEVEX,
     Instruction,
     InstructionAddress,
     InstructionType,
@@ -24,6 +25,12 @@ pub use self::
     RepeatZero,
     RepeatNotEqual,
     RepeatNotZero,
+    REX,
+    REXW,
+    REXR,
+    REXX,
+    REXB,
+    RoundingControl,
     SegmentOverrideCS,
     SegmentOverrideSS,
     SegmentOverrideDS,
@@ -34,6 +41,12 @@ pub use self::
     TwoByteVEX,
     ThreeByteVEX,
     TwoByteOpcode,
+    Wait,
+  },
+  memory::
+  {
+    Memory32InstructionPointer,
+    Memory32NoRegister,
   },
   operands::
   {
@@ -52,6 +65,19 @@ pub use super::
   InstructionSet,
 };
 
+use self::
+{
+  instructions::
+  {
+    EnhancedVectorOptions,
+    VectorOpcode,
+  },
+  registers::
+  {
+    SegmentRegister,
+  },
+};
+
 use std::
 {
   string::
@@ -63,6 +89,7 @@ use std::
 pub struct X86
 {
   instructions:                         Vec<Instruction>,
+  prefixes:                             Vec<( usize, u8 )>,
   line:                                 usize,
   features:                             AssemblyFeatures,
 }
@@ -74,6 +101,7 @@ pub fn X86
   X86
   {
     instructions:                       vec!(),
+    prefixes:                           vec!(),
     line:                               0,
     features:                           AssemblyFeatures::Default,
   }
@@ -81,6 +109,52 @@ pub fn X86
 
 impl X86
 {
+  pub fn enable
+  (
+    mut self,
+    features:                           AssemblyFeatures,
+  ) -> Self
+  {
+    self.features                       |=  features;
+    self
+  }
+
+  pub fn disable
+  (
+    mut self,
+    features:                           AssemblyFeatures,
+  ) -> Self
+  {
+    self.features                       &=  !features;
+    self
+  }
+
+  //  prefixes apply to the following instruction and are validated, when it is compiled
+  fn prefix
+  (
+    mut self,
+    prefix:                             u8,
+  ) -> Self
+  {
+    self.prefixes.push  ( ( self.instructions.len(), prefix ) );
+    self
+  }
+
+  pub fn lock                           ( self  ) ->  Self                { self.prefix ( Lock            ) }
+  pub fn rep                            ( self  ) ->  Self                { self.prefix ( Repeat          ) }
+  pub fn repe                           ( self  ) ->  Self                { self.prefix ( RepeatEqual     ) }
+  pub fn repne                          ( self  ) ->  Self                { self.prefix ( RepeatNotEqual  ) }
+
+  pub fn segment
+  (
+    self,
+    register:                           SegmentRegister,
+  ) -> Self
+  {
+    let prefix                          =   Instruction::segmentOverride  ( register.number );
+    self.prefix ( prefix  )
+  }
+
   pub fn label
   (
     mut self,
@@ -122,6 +196,46 @@ impl X86
       return Err ( format!( "Instruction Set ›{}‹ is 16 Bit Only", InstructionSet( architecture ) ) );
     }
 
+    //  in 64 bit mode, the default operand size is still 32 bit, but 64 bit operands do not need a prefix
+    if  ( operandSize == 64 ) || ( addressSize == 64 )
+    {
+      if  architecture  <   InstructionSet::amd64
+      {
+        return Err ( format!( "64 Bit Mode Requires at least ›{}‹", InstructionSet( InstructionSet::amd64 ) ) );
+      }
+      if  ( operandSize != 64 ) || ( addressSize != 64 )
+      {
+        return Err ( "64 Bit Mode Requires 64 Bit Operand and Address Size".to_string ( ) );
+      }
+    }
+
+    for ( index, prefix )               in  self.prefixes.drain ( .. )
+    {
+      match self.instructions.get_mut ( index )
+      {
+        Some  ( instruction )
+        if instruction.getType() > InstructionType::ActualInstruction
+        =>  match prefix
+            {
+              Lock
+              =>  instruction.setLock             ( true    ),
+              //  repe and repz share the byte of rep, repnz the byte of repne
+              Repeat            | RepeatNotEqual
+              =>  instruction.setRepeat           ( prefix  ),
+              SegmentOverrideCS | SegmentOverrideSS | SegmentOverrideDS |
+              SegmentOverrideES | SegmentOverrideFS | SegmentOverrideGS
+              =>  instruction.setSegmentOverride  ( prefix  ),
+              _
+              =>  return Err ( format! ( "Invalid Prefix 0x{:02x}", prefix ) ),
+            },
+        _
+        =>  return Err ( "Prefix Requires a Following Instruction".to_string ( ) ),
+      }
+    }
+
+    //  unlike the features copied into every instruction, branch expansion applies to the whole program
+    let     branchExpansion             =   self.features.hazFeature ( AssemblyFeatures::X86BranchExpansion );
+
     let mut symbols                     =   SymbolList  ( );
     let mut rounds                      =   None;
     for round                           in  0 .. maxRounds
@@ -139,9 +253,11 @@ impl X86
       {
         let mut length                  =   Some ( 0 );
 
-        //  try to resolve expressions and labels
+        //  try to resolve expressions and labels, references are resolved again in every round
         let mut size                    =   0;
-        for operand                     in  instruction.getOperandRefs()
+        let mut references              =   vec!();
+        instruction.restoreReferences ( );
+        for ( index, operand )          in  instruction.getOperandRefs().iter_mut().enumerate()
         {
           if    let OperandType::Expression ( expression  ) = operand
           {
@@ -163,6 +279,35 @@ impl X86
           }
           else
           {
+            if  let OperandType::RipRelative  { segment,  target  } = operand
+            {
+              //  until the label is known, the displacement is a guess, but its length is always the same
+              let segment               =   *segment;
+              let reference             =   symbols.expect  ( target  );
+              references.push ( ( index, operand.clone ( ) ) );
+              let displacement          =   match symbols.obtain  ( reference )?
+                                            {
+                                              Some  ( OperandType::Address  ( destination ) )
+                                              =>  address.diff  ( destination ),
+                                              None
+                                              =>  None,
+                                              Some  ( _ )
+                                              =>  return Err ( "Instruction Pointer Relative Addressing Requires a Label".to_string ( ) ),
+                                            };
+              if displacement.is_none ( )
+              {
+                done                    =   false;
+              }
+              *operand                  =   OperandType::Memory32
+                                            {
+                                              width:        64,
+                                              segment,
+                                              base:         Memory32InstructionPointer,
+                                              scale:        1,
+                                              index:        Memory32NoRegister,
+                                              displacement: displacement.unwrap_or ( 0 ),
+                                            };
+            }
             if  let OperandType::Symbol     ( identifier  ) = operand
             {
               let reference             =   symbols.expect  ( identifier  );
@@ -174,9 +319,9 @@ impl X86
                   =   symbols.obtain
                       (
                         *reference,
-                        round,
                       )?
               {
+                references.push ( ( index, OperandType::Reference ( *reference ) ) );
                 match value
                 {
                   OperandType::Address  ( destination )
@@ -195,11 +340,21 @@ impl X86
             }
           }
         }
+        instruction.setReferences ( references  );
         instruction.orOperandSize ( size  );
 
+        //  32 bit addressing is only available since i386, regardless of the instruction
+        if  architecture                <   InstructionSet::i386
+        &&  instruction.getOperandRefs().iter().any ( | operand | matches!  ( operand,  OperandType::Memory32 { .. } ) )
+        {
+          return Err ( format!( "32 Bit Addressing Requires at least ›{}‹", InstructionSet( InstructionSet::i386 ) ) );
+        }
+
         //  if not possible, skip further processing of instruction
         if length != None
         {
+          instruction.clearEncoding ( );
+          instruction.validatePrefixes  ( architecture  )?;
           //  minimum length, instruction might be longer
           length
           = match instruction.getType()
@@ -210,7 +365,6 @@ impl X86
                           (
                             identifier,
                             Some  ( OperandType::Address  ( address ) ),
-                            round,
                           )
                   {
                     instruction.setType
@@ -224,118 +378,471 @@ impl X86
                     Err ( "Label already defined".to_string ( ) )
                   },
               InstructionType::Reference      ( reference   )
-              =>  if let  Some ( error )
-                      =   symbols.modify
-                          (
-                            reference,
-                            Some  ( OperandType::Address  ( address ) ),
-                            round,
-                          )
-                  {
-                    Err ( error.to_string ( ) )
-                  }
-                  else
-                  {
-                    Ok  ( Some  ( 0 ) )
+              =>  {
+                    //  not done yet, if the label moved since the last round
+                    match symbols.obtain  ( reference )?
+                    {
+                      Some  ( OperandType::Address  ( previous  ) )
+                      if  previous  ==  address
+                      =>  {},
+                      _
+                      =>  done          =   false,
+                    }
+                    if let  Some ( error )
+                        =   symbols.modify
+                            (
+                              reference,
+                              Some  ( OperandType::Address  ( address ) ),
+                            )
+                    {
+                      Err ( error.to_string ( ) )
+                    }
+                    else
+                    {
+                      Ok  ( Some  ( 0 ) )
+                    }
                   },
               InstructionType::AAA      =>  instruction.compileZeroOperandInstruction (                                           0x37, ),
-              InstructionType::AAD      =>  unimplemented!(),
-              InstructionType::AAM      =>  unimplemented!(),
+              InstructionType::AAD      =>  instruction.compileAsciiAdjustInstruction (                                           0xd5, ),
+              InstructionType::AAM      =>  instruction.compileAsciiAdjustInstruction (                                           0xd4, ),
               InstructionType::AAS      =>  instruction.compileZeroOperandInstruction (                                           0x3f, ),
               InstructionType::ADC      =>  instruction.compileSimpleMathInstruction  ( architecture, operandSize,  addressSize,  0x10, ),
               InstructionType::ADD      =>  instruction.compileSimpleMathInstruction  ( architecture, operandSize,  addressSize,  0x00, ),
+              InstructionType::ADDPD    =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium4, VectorOpcode ( 0x66, 1, 0x58 ), 16, ),
+              InstructionType::ADDPS    =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium3, VectorOpcode ( 0x00, 1, 0x58 ), 16, ),
+              InstructionType::ADDSD    =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium4, VectorOpcode ( 0xf2, 1, 0x58 ), 8, ),
+              InstructionType::ADDSS    =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium3, VectorOpcode ( 0xf3, 1, 0x58 ), 4, ),
               InstructionType::AND      =>  instruction.compileSimpleMathInstruction  ( architecture, operandSize,  addressSize,  0x20, ),
-              InstructionType::CALL     =>  unimplemented!(),
+              InstructionType::ANDNPD   =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium4, VectorOpcode ( 0x66, 1, 0x55 ), 16, ),
+              InstructionType::ANDNPS   =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium3, VectorOpcode ( 0x00, 1, 0x55 ), 16, ),
+              InstructionType::ANDPD    =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium4, VectorOpcode ( 0x66, 1, 0x54 ), 16, ),
+              InstructionType::ANDPS    =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium3, VectorOpcode ( 0x00, 1, 0x54 ), 16, ),
+              InstructionType::ARPL     =>  instruction.compileAdjustPrivilegeInstruction ( architecture,               addressSize,        ),
+              InstructionType::BOUND    =>  instruction.compileLoadAddressInstruction ( architecture, operandSize,  addressSize,  InstructionSet::i186,  0x62, ),
+              InstructionType::BSWAP    =>  instruction.compileByteSwapInstruction    ( architecture, operandSize,                      ),
+              InstructionType::BSF      =>  instruction.compileBitScanInstruction     ( architecture, operandSize,  addressSize,  0xbc, ),
+              InstructionType::BSR      =>  instruction.compileBitScanInstruction     ( architecture, operandSize,  addressSize,  0xbd, ),
+              InstructionType::BT       =>  instruction.compileBitTestInstruction     ( architecture, operandSize,  addressSize,  4,    ),
+              InstructionType::BTC      =>  instruction.compileBitTestInstruction     ( architecture, operandSize,  addressSize,  7,    ),
+              InstructionType::BTR      =>  instruction.compileBitTestInstruction     ( architecture, operandSize,  addressSize,  6,    ),
+              InstructionType::BTS      =>  instruction.compileBitTestInstruction     ( architecture, operandSize,  addressSize,  5,    ),
+              InstructionType::CALL     =>  instruction.compileBranchInstruction      (               operandSize,  addressSize,  None,           0xe8, 0x9a, 2,  ),
               InstructionType::CBW      =>  instruction.compileZeroOperandInstruction (                                           0x98, ),
               InstructionType::CLC      =>  instruction.compileZeroOperandInstruction (                                           0xf8, ),
               InstructionType::CLD      =>  instruction.compileZeroOperandInstruction (                                           0xfc, ),
               InstructionType::CLI      =>  instruction.compileZeroOperandInstruction (                                           0xfa, ),
+              InstructionType::CLTS     =>  instruction.compileTwoByteZeroOperandInstructionSince ( architecture, InstructionSet::i286, 0x06, ),
               InstructionType::CMC      =>  instruction.compileZeroOperandInstruction (                                           0xf5, ),
+              InstructionType::CMOVB    =>  instruction.compileConditionalMoveInstruction ( architecture, operandSize,  addressSize,  0x02, ),
+              InstructionType::CMOVBE   =>  instruction.compileConditionalMoveInstruction ( architecture, operandSize,  addressSize,  0x06, ),
+              InstructionType::CMOVE    =>  instruction.compileConditionalMoveInstruction ( architecture, operandSize,  addressSize,  0x04, ),
+              InstructionType::CMOVL    =>  instruction.compileConditionalMoveInstruction ( architecture, operandSize,  addressSize,  0x0c, ),
+              InstructionType::CMOVLE   =>  instruction.compileConditionalMoveInstruction ( architecture, operandSize,  addressSize,  0x0e, ),
+              InstructionType::CMOVNB   =>  instruction.compileConditionalMoveInstruction ( architecture, operandSize,  addressSize,  0x03, ),
+              InstructionType::CMOVNBE  =>  instruction.compileConditionalMoveInstruction ( architecture, operandSize,  addressSize,  0x07, ),
+              InstructionType::CMOVNE   =>  instruction.compileConditionalMoveInstruction ( architecture, operandSize,  addressSize,  0x05, ),
+              InstructionType::CMOVNL   =>  instruction.compileConditionalMoveInstruction ( architecture, operandSize,  addressSize,  0x0d, ),
+              InstructionType::CMOVNLE  =>  instruction.compileConditionalMoveInstruction ( architecture, operandSize,  addressSize,  0x0f, ),
+              InstructionType::CMOVNO   =>  instruction.compileConditionalMoveInstruction ( architecture, operandSize,  addressSize,  0x01, ),
+              InstructionType::CMOVNP   =>  instruction.compileConditionalMoveInstruction ( architecture, operandSize,  addressSize,  0x0b, ),
+              InstructionType::CMOVNS   =>  instruction.compileConditionalMoveInstruction ( architecture, operandSize,  addressSize,  0x09, ),
+              InstructionType::CMOVO    =>  instruction.compileConditionalMoveInstruction ( architecture, operandSize,  addressSize,  0x00, ),
+              InstructionType::CMOVP    =>  instruction.compileConditionalMoveInstruction ( architecture, operandSize,  addressSize,  0x0a, ),
+              InstructionType::CMOVS    =>  instruction.compileConditionalMoveInstruction ( architecture, operandSize,  addressSize,  0x08, ),
               InstructionType::CMP      =>  instruction.compileSimpleMathInstruction  ( architecture, operandSize,  addressSize,  0x38, ),
+              InstructionType::CMPPD    =>  instruction.compileStreamingCompareInstruction ( architecture,               addressSize,  InstructionSet::Pentium4, 0x66, 16, ),
+              InstructionType::CMPPS    =>  instruction.compileStreamingCompareInstruction ( architecture,               addressSize,  InstructionSet::Pentium3, 0x00, 16, ),
               InstructionType::CMPSB    =>  instruction.compileZeroOperandInstruction (                                           0xa6, ),
+              InstructionType::CMPSD    =>  instruction.compileStreamingCompareInstruction ( architecture,               addressSize,  InstructionSet::Pentium4, 0xf2, 8, ),
+              InstructionType::CMPSS    =>  instruction.compileStreamingCompareInstruction ( architecture,               addressSize,  InstructionSet::Pentium3, 0xf3, 4, ),
               InstructionType::CMPSW    =>  instruction.compileZeroOperandInstruction (                                           0xa7, ),
+              InstructionType::CMPXCHG  =>  instruction.compileAtomicExchangeInstruction ( architecture, operandSize,  addressSize,  0xb0, ),
+              InstructionType::CMPXCHG8B =>  instruction.compileCompareExchange8Instruction ( architecture,               addressSize,        ),
+              InstructionType::COMISD   =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium4, VectorOpcode ( 0x66, 1, 0x2f ), 8, ),
+              InstructionType::COMISS   =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium3, VectorOpcode ( 0x00, 1, 0x2f ), 4, ),
+              InstructionType::CPUID    =>  instruction.compileTwoByteZeroOperandInstructionSince ( architecture, InstructionSet::i486, 0xa2, ),
+              InstructionType::CVTDQ2PS =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium4, VectorOpcode ( 0x00, 1, 0x5b ), 16, ),
+              InstructionType::CVTPD2PS =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium4, VectorOpcode ( 0x66, 1, 0x5a ), 16, ),
+              InstructionType::CVTPS2DQ =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium4, VectorOpcode ( 0x66, 1, 0x5b ), 16, ),
+              InstructionType::CVTPS2PD =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium4, VectorOpcode ( 0x00, 1, 0x5a ), 8, ),
+              InstructionType::CVTSD2SI =>  instruction.compileConvertToIntegerInstruction ( architecture, operandSize,  addressSize,  InstructionSet::Pentium4, VectorOpcode ( 0xf2, 1, 0x2d ), 8, ),
+              InstructionType::CVTSD2SS =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium4, VectorOpcode ( 0xf2, 1, 0x5a ), 8, ),
+              InstructionType::CVTSI2SD =>  instruction.compileConvertFromIntegerInstruction ( architecture, operandSize,  addressSize,  InstructionSet::Pentium4, 0xf2, ),
+              InstructionType::CVTSI2SS =>  instruction.compileConvertFromIntegerInstruction ( architecture, operandSize,  addressSize,  InstructionSet::Pentium3, 0xf3, ),
+              InstructionType::CVTSS2SD =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium4, VectorOpcode ( 0xf3, 1, 0x5a ), 4, ),
+              InstructionType::CVTSS2SI =>  instruction.compileConvertToIntegerInstruction ( architecture, operandSize,  addressSize,  InstructionSet::Pentium3, VectorOpcode ( 0xf3, 1, 0x2d ), 4, ),
+              InstructionType::CVTTSD2SI =>  instruction.compileConvertToIntegerInstruction ( architecture, operandSize,  addressSize,  InstructionSet::Pentium4, VectorOpcode ( 0xf2, 1, 0x2c ), 8, ),
+              InstructionType::CVTTSS2SI =>  instruction.compileConvertToIntegerInstruction ( architecture, operandSize,  addressSize,  InstructionSet::Pentium3, VectorOpcode ( 0xf3, 1, 0x2c ), 4, ),
               InstructionType::CWD      =>  instruction.compileZeroOperandInstruction (                                           0x99, ),
               InstructionType::DAA      =>  instruction.compileZeroOperandInstruction (                                           0x27, ),
               InstructionType::DAS      =>  instruction.compileZeroOperandInstruction (                                           0x2f, ),
-              InstructionType::DEC      =>  unimplemented!(),
-              InstructionType::DIV      =>  unimplemented!(),
-              InstructionType::ESC      =>  unimplemented!(),
+              InstructionType::DEC      =>  instruction.compileUnaryMathInstruction   ( architecture, operandSize,  addressSize,  0xfe, 1,  ),
+              InstructionType::DIV      =>  instruction.compileUnaryMathInstruction   ( architecture, operandSize,  addressSize,  0xf6, 6,  ),
+              InstructionType::DIVPD    =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium4, VectorOpcode ( 0x66, 1, 0x5e ), 16, ),
+              InstructionType::DIVPS    =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium3, VectorOpcode ( 0x00, 1, 0x5e ), 16, ),
+              InstructionType::DIVSD    =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium4, VectorOpcode ( 0xf2, 1, 0x5e ), 8, ),
+              InstructionType::DIVSS    =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium3, VectorOpcode ( 0xf3, 1, 0x5e ), 4, ),
+              InstructionType::EMMS     =>  instruction.compileTwoByteZeroOperandInstructionSince ( architecture, InstructionSet::Pentium2, 0x77, ),
+              InstructionType::ENTER    =>  instruction.compileEnterInstruction       ( architecture,                                   ),
+              InstructionType::ESC      =>  instruction.compileEscapeInstruction      (                             addressSize,        ),
+              InstructionType::FADD     =>  instruction.compileFloatingPointArithmeticInstruction ( architecture, addressSize,  0,  ),
+              InstructionType::FADDP    =>  instruction.compileFloatingPointPopInstruction  ( architecture, 0,  ),
+              InstructionType::FCMOVB   =>  instruction.compileFloatingPointRegisterInstruction ( architecture, 0xda, 0, ),
+              InstructionType::FCMOVBE  =>  instruction.compileFloatingPointRegisterInstruction ( architecture, 0xda, 2, ),
+              InstructionType::FCMOVE   =>  instruction.compileFloatingPointRegisterInstruction ( architecture, 0xda, 1, ),
+              InstructionType::FCMOVNB  =>  instruction.compileFloatingPointRegisterInstruction ( architecture, 0xdb, 0, ),
+              InstructionType::FCMOVNBE =>  instruction.compileFloatingPointRegisterInstruction ( architecture, 0xdb, 2, ),
+              InstructionType::FCMOVNE  =>  instruction.compileFloatingPointRegisterInstruction ( architecture, 0xdb, 1, ),
+              InstructionType::FCMOVNU  =>  instruction.compileFloatingPointRegisterInstruction ( architecture, 0xdb, 3, ),
+              InstructionType::FCMOVU   =>  instruction.compileFloatingPointRegisterInstruction ( architecture, 0xda, 3, ),
+              InstructionType::FCOM     =>  instruction.compileFloatingPointCompareInstruction ( architecture, addressSize,  2,  ),
+              InstructionType::FCOMI    =>  instruction.compileFloatingPointRegisterInstruction ( architecture, 0xdb, 6, ),
+              InstructionType::FCOMIP   =>  instruction.compileFloatingPointRegisterInstruction ( architecture, 0xdf, 6, ),
+              InstructionType::FCOMP    =>  instruction.compileFloatingPointCompareInstruction ( architecture, addressSize,  3,  ),
+              InstructionType::FCOMPP   =>  instruction.compileFloatingPointControlInstruction ( architecture, true,   0xde, 0xd9, ),
+              InstructionType::FDIV     =>  instruction.compileFloatingPointArithmeticInstruction ( architecture, addressSize,  6,  ),
+              InstructionType::FDIVP    =>  instruction.compileFloatingPointPopInstruction  ( architecture, 6,  ),
+              InstructionType::FDIVR    =>  instruction.compileFloatingPointArithmeticInstruction ( architecture, addressSize,  7,  ),
+              InstructionType::FDIVRP   =>  instruction.compileFloatingPointPopInstruction  ( architecture, 7,  ),
+              InstructionType::FLD      =>  instruction.compileFloatingPointLoadInstruction ( architecture, addressSize,  ),
+              InstructionType::FMUL     =>  instruction.compileFloatingPointArithmeticInstruction ( architecture, addressSize,  1,  ),
+              InstructionType::FMULP    =>  instruction.compileFloatingPointPopInstruction  ( architecture, 1,  ),
+              InstructionType::FNINIT   =>  instruction.compileFloatingPointControlInstruction ( architecture, false,  0xdb, 0xe3, ),
+              InstructionType::FST      =>  instruction.compileFloatingPointStoreInstruction ( architecture, addressSize,  false,  ),
+              InstructionType::FSTP     =>  instruction.compileFloatingPointStoreInstruction ( architecture, addressSize,  true,   ),
+              InstructionType::FSUB     =>  instruction.compileFloatingPointArithmeticInstruction ( architecture, addressSize,  4,  ),
+              InstructionType::FSUBP    =>  instruction.compileFloatingPointPopInstruction  ( architecture, 4,  ),
+              InstructionType::FSUBR    =>  instruction.compileFloatingPointArithmeticInstruction ( architecture, addressSize,  5,  ),
+              InstructionType::FSUBRP   =>  instruction.compileFloatingPointPopInstruction  ( architecture, 5,  ),
+              InstructionType::FUCOMI   =>  instruction.compileFloatingPointRegisterInstruction ( architecture, 0xdb, 5, ),
+              InstructionType::FUCOMIP  =>  instruction.compileFloatingPointRegisterInstruction ( architecture, 0xdf, 5, ),
               InstructionType::HLT      =>  instruction.compileZeroOperandInstruction (                                           0xf4, ),
-              InstructionType::IDIV     =>  unimplemented!(),
-              InstructionType::IMUL     =>  unimplemented!(),
-              InstructionType::IN       =>  unimplemented!(),
-              InstructionType::INC      =>  unimplemented!(),
-              InstructionType::INT      =>  unimplemented!(),
+              InstructionType::IDIV     =>  instruction.compileUnaryMathInstruction   ( architecture, operandSize,  addressSize,  0xf6, 7,  ),
+              InstructionType::IMUL     =>  instruction.compileUnaryMathInstruction   ( architecture, operandSize,  addressSize,  0xf6, 5,  ),
+              InstructionType::IN       =>  instruction.compilePortInstruction        ( architecture, operandSize,                0xe4, ),
+              InstructionType::INC      =>  instruction.compileUnaryMathInstruction   ( architecture, operandSize,  addressSize,  0xfe, 0,  ),
+              InstructionType::INSB     =>  instruction.compileZeroOperandInstructionSince  ( architecture, InstructionSet::i186,     0x6c, ),
+              InstructionType::INSW     =>  instruction.compileZeroOperandInstructionSince  ( architecture, InstructionSet::i186,     0x6d, ),
+              InstructionType::INT      =>  instruction.compileInterruptInstruction   (                                                 ),
               InstructionType::INT3     =>  instruction.compileZeroOperandInstruction (                                           0xcc, ),
               InstructionType::INTO     =>  instruction.compileZeroOperandInstruction (                                           0xce, ),
+              InstructionType::INVLPG   =>  instruction.compileDescriptorTableInstruction ( architecture,               addressSize,  InstructionSet::i486, 7,  ),
               InstructionType::IRET     =>  instruction.compileZeroOperandInstruction (                                           0xcf, ),
-              InstructionType::JB       =>  instruction.compileJumpInstruction        ( architecture, operandSize,                0x72, ),
-              InstructionType::JBE      =>  instruction.compileJumpInstruction        ( architecture, operandSize,                0x76, ),
-              InstructionType::JCXZ     =>  instruction.compileJumpInstruction        ( architecture, operandSize,                0xe3, ),
-              InstructionType::JE       =>  instruction.compileJumpInstruction        ( architecture, operandSize,                0x74, ),
-              InstructionType::JL       =>  instruction.compileJumpInstruction        ( architecture, operandSize,                0x7c, ),
-              InstructionType::JLE      =>  instruction.compileJumpInstruction        ( architecture, operandSize,                0x7e, ),
-              InstructionType::JMP      =>  unimplemented!(),
-              InstructionType::JNB      =>  instruction.compileJumpInstruction        ( architecture, operandSize,                0x73, ),
-              InstructionType::JNBE     =>  instruction.compileJumpInstruction        ( architecture, operandSize,                0x77, ),
-              InstructionType::JNE      =>  instruction.compileJumpInstruction        ( architecture, operandSize,                0x75, ),
-              InstructionType::JNL      =>  instruction.compileJumpInstruction        ( architecture, operandSize,                0x7d, ),
-              InstructionType::JNLE     =>  instruction.compileJumpInstruction        ( architecture, operandSize,                0x7f, ),
-              InstructionType::JNO      =>  instruction.compileJumpInstruction        ( architecture, operandSize,                0x71, ),
-              InstructionType::JNP      =>  instruction.compileJumpInstruction        ( architecture, operandSize,                0x7b, ),
-              InstructionType::JNS      =>  instruction.compileJumpInstruction        ( architecture, operandSize,                0x79, ),
-              InstructionType::JO       =>  instruction.compileJumpInstruction        ( architecture, operandSize,                0x70, ),
-              InstructionType::JP       =>  instruction.compileJumpInstruction        ( architecture, operandSize,                0x7a, ),
-              InstructionType::JS       =>  instruction.compileJumpInstruction        ( architecture, operandSize,                0x78, ),
+              InstructionType::JB       =>  instruction.compileJumpInstruction        ( architecture, operandSize,  branchExpansion,  0x72, ),
+              InstructionType::JBE      =>  instruction.compileJumpInstruction        ( architecture, operandSize,  branchExpansion,  0x76, ),
+              InstructionType::JCXZ     =>  instruction.compileJumpInstruction        ( architecture, operandSize,  branchExpansion,  0xe3, ),
+              InstructionType::JE       =>  instruction.compileJumpInstruction        ( architecture, operandSize,  branchExpansion,  0x74, ),
+              InstructionType::JL       =>  instruction.compileJumpInstruction        ( architecture, operandSize,  branchExpansion,  0x7c, ),
+              InstructionType::JLE      =>  instruction.compileJumpInstruction        ( architecture, operandSize,  branchExpansion,  0x7e, ),
+              InstructionType::JMP      =>  instruction.compileBranchInstruction      (               operandSize,  addressSize,  Some  ( 0xeb ), 0xe9, 0xea, 4,  ),
+              InstructionType::JNB      =>  instruction.compileJumpInstruction        ( architecture, operandSize,  branchExpansion,  0x73, ),
+              InstructionType::JNBE     =>  instruction.compileJumpInstruction        ( architecture, operandSize,  branchExpansion,  0x77, ),
+              InstructionType::JNE      =>  instruction.compileJumpInstruction        ( architecture, operandSize,  branchExpansion,  0x75, ),
+              InstructionType::JNL      =>  instruction.compileJumpInstruction        ( architecture, operandSize,  branchExpansion,  0x7d, ),
+              InstructionType::JNLE     =>  instruction.compileJumpInstruction        ( architecture, operandSize,  branchExpansion,  0x7f, ),
+              InstructionType::JNO      =>  instruction.compileJumpInstruction        ( architecture, operandSize,  branchExpansion,  0x71, ),
+              InstructionType::JNP      =>  instruction.compileJumpInstruction        ( architecture, operandSize,  branchExpansion,  0x7b, ),
+              InstructionType::JNS      =>  instruction.compileJumpInstruction        ( architecture, operandSize,  branchExpansion,  0x79, ),
+              InstructionType::JO       =>  instruction.compileJumpInstruction        ( architecture, operandSize,  branchExpansion,  0x70, ),
+              InstructionType::JP       =>  instruction.compileJumpInstruction        ( architecture, operandSize,  branchExpansion,  0x7a, ),
+              InstructionType::JS       =>  instruction.compileJumpInstruction        ( architecture, operandSize,  branchExpansion,  0x78, ),
+              InstructionType::KANDNW   =>  instruction.compileMaskInstruction ( architecture,               addressSize,  0x42, true, ),
+              InstructionType::KANDW    =>  instruction.compileMaskInstruction ( architecture,               addressSize,  0x41, true, ),
+              InstructionType::KMOVW    =>  instruction.compileMaskMoveInstruction ( architecture,               addressSize,  ),
+              InstructionType::KNOTW    =>  instruction.compileMaskInstruction ( architecture,               addressSize,  0x44, false, ),
+              InstructionType::KORTESTW =>  instruction.compileMaskInstruction ( architecture,               addressSize,  0x98, false, ),
+              InstructionType::KORW     =>  instruction.compileMaskInstruction ( architecture,               addressSize,  0x45, true, ),
+              InstructionType::KXORW    =>  instruction.compileMaskInstruction ( architecture,               addressSize,  0x47, true, ),
               InstructionType::LAHF     =>  instruction.compileZeroOperandInstruction (                                           0x9f, ),
-              InstructionType::LDS      =>  unimplemented!(),
-              InstructionType::LEA      =>  unimplemented!(),
-              InstructionType::LES      =>  unimplemented!(),
+              InstructionType::LAR      =>  instruction.compileSelectorInstruction    ( architecture, operandSize,  addressSize,  0x02, ),
+              InstructionType::LDS      =>  instruction.compileLoadAddressInstruction ( architecture, operandSize,  addressSize,  InstructionSet::i8086, 0xc5, ),
+              InstructionType::LEA      =>  instruction.compileLoadAddressInstruction ( architecture, operandSize,  addressSize,  InstructionSet::i8086, 0x8d, ),
+              InstructionType::LEAVE    =>  instruction.compileZeroOperandInstructionSince  ( architecture, InstructionSet::i186,     0xc9, ),
+              InstructionType::LES      =>  instruction.compileLoadAddressInstruction ( architecture, operandSize,  addressSize,  InstructionSet::i8086, 0xc4, ),
+              InstructionType::LGDT     =>  instruction.compileDescriptorTableInstruction ( architecture,               addressSize,  InstructionSet::i286, 2,  ),
+              InstructionType::LIDT     =>  instruction.compileDescriptorTableInstruction ( architecture,               addressSize,  InstructionSet::i286, 3,  ),
+              InstructionType::LLDT     =>  instruction.compileSystemInstruction      ( architecture,               addressSize,  0x00, 2,  ),
+              InstructionType::LMSW     =>  instruction.compileSystemInstruction      ( architecture,               addressSize,  0x01, 6,  ),
               InstructionType::LODSB    =>  instruction.compileZeroOperandInstruction (                                           0xac, ),
               InstructionType::LODSW    =>  instruction.compileZeroOperandInstruction (                                           0xad, ),
-              InstructionType::LOOP     =>  instruction.compileJumpInstruction        ( architecture, operandSize,                0xe2, ),
-              InstructionType::LOOPZ    =>  instruction.compileJumpInstruction        ( architecture, operandSize,                0xe1, ),
-              InstructionType::LOOPNZ   =>  instruction.compileJumpInstruction        ( architecture, operandSize,                0xe0, ),
-              InstructionType::MOV      =>  unimplemented!(),
+              InstructionType::LOOP     =>  instruction.compileJumpInstruction        ( architecture, operandSize,  branchExpansion,  0xe2, ),
+              InstructionType::LOOPZ    =>  instruction.compileJumpInstruction        ( architecture, operandSize,  branchExpansion,  0xe1, ),
+              InstructionType::LOOPNZ   =>  instruction.compileJumpInstruction        ( architecture, operandSize,  branchExpansion,  0xe0, ),
+              InstructionType::LSL      =>  instruction.compileSelectorInstruction    ( architecture, operandSize,  addressSize,  0x03, ),
+              InstructionType::LTR      =>  instruction.compileSystemInstruction      ( architecture,               addressSize,  0x00, 3,  ),
+              InstructionType::MAXPD    =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium4, VectorOpcode ( 0x66, 1, 0x5f ), 16, ),
+              InstructionType::MAXPS    =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium3, VectorOpcode ( 0x00, 1, 0x5f ), 16, ),
+              InstructionType::MAXSD    =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium4, VectorOpcode ( 0xf2, 1, 0x5f ), 8, ),
+              InstructionType::MAXSS    =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium3, VectorOpcode ( 0xf3, 1, 0x5f ), 4, ),
+              InstructionType::MINPD    =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium4, VectorOpcode ( 0x66, 1, 0x5d ), 16, ),
+              InstructionType::MINPS    =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium3, VectorOpcode ( 0x00, 1, 0x5d ), 16, ),
+              InstructionType::MINSD    =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium4, VectorOpcode ( 0xf2, 1, 0x5d ), 8, ),
+              InstructionType::MINSS    =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium3, VectorOpcode ( 0xf3, 1, 0x5d ), 4, ),
+              InstructionType::MOV      =>  instruction.compileMoveInstruction        ( architecture, operandSize,  addressSize,        ),
+              InstructionType::MOVAPD   =>  instruction.compileStreamingMoveInstruction ( architecture,               addressSize,  InstructionSet::Pentium4, VectorOpcode ( 0x66, 1, 0x28 ), 0x29, 16, ),
+              InstructionType::MOVAPS   =>  instruction.compileStreamingMoveInstruction ( architecture,               addressSize,  InstructionSet::Pentium3, VectorOpcode ( 0x00, 1, 0x28 ), 0x29, 16, ),
+              InstructionType::MOVD     =>  instruction.compileMoveDoublewordInstruction ( architecture,               addressSize,        ),
+              InstructionType::MOVDQA   =>  instruction.compileStreamingMoveInstruction ( architecture,               addressSize,  InstructionSet::Pentium4, VectorOpcode ( 0x66, 1, 0x6f ), 0x7f, 16, ),
+              InstructionType::MOVDQU   =>  instruction.compileStreamingMoveInstruction ( architecture,               addressSize,  InstructionSet::Pentium4, VectorOpcode ( 0xf3, 1, 0x6f ), 0x7f, 16, ),
+              InstructionType::MOVQ     =>  instruction.compileMoveQuadwordInstruction ( architecture,               addressSize,        ),
               InstructionType::MOVSB    =>  instruction.compileZeroOperandInstruction (                                           0xa4, ),
+              InstructionType::MOVSD    =>  instruction.compileStreamingMoveInstruction ( architecture,               addressSize,  InstructionSet::Pentium4, VectorOpcode ( 0xf2, 1, 0x10 ), 0x11, 8, ),
+              InstructionType::MOVSS    =>  instruction.compileStreamingMoveInstruction ( architecture,               addressSize,  InstructionSet::Pentium3, VectorOpcode ( 0xf3, 1, 0x10 ), 0x11, 4, ),
               InstructionType::MOVSW    =>  instruction.compileZeroOperandInstruction (                                           0xa5, ),
+              InstructionType::MOVSX    =>  instruction.compileMoveExtendedInstruction ( architecture, operandSize,  addressSize,  0xbe, ),
+              InstructionType::MOVUPD   =>  instruction.compileStreamingMoveInstruction ( architecture,               addressSize,  InstructionSet::Pentium4, VectorOpcode ( 0x66, 1, 0x10 ), 0x11, 16, ),
+              InstructionType::MOVUPS   =>  instruction.compileStreamingMoveInstruction ( architecture,               addressSize,  InstructionSet::Pentium3, VectorOpcode ( 0x00, 1, 0x10 ), 0x11, 16, ),
+              InstructionType::MOVZX    =>  instruction.compileMoveExtendedInstruction ( architecture, operandSize,  addressSize,  0xb6, ),
+              InstructionType::MULPD    =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium4, VectorOpcode ( 0x66, 1, 0x59 ), 16, ),
+              InstructionType::MULPS    =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium3, VectorOpcode ( 0x00, 1, 0x59 ), 16, ),
+              InstructionType::MULSD    =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium4, VectorOpcode ( 0xf2, 1, 0x59 ), 8, ),
+              InstructionType::MULSS    =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium3, VectorOpcode ( 0xf3, 1, 0x59 ), 4, ),
               InstructionType::OR       =>  instruction.compileSimpleMathInstruction  ( architecture, operandSize,  addressSize,  0x08, ),
-              InstructionType::MUL      =>  unimplemented!(),
-              InstructionType::NEG      =>  unimplemented!(),
-              InstructionType::NOT      =>  unimplemented!(),
-              InstructionType::OUT      =>  unimplemented!(),
-              InstructionType::POP      =>  unimplemented!(),
+              InstructionType::MUL      =>  instruction.compileUnaryMathInstruction   ( architecture, operandSize,  addressSize,  0xf6, 4,  ),
+              InstructionType::NEG      =>  instruction.compileUnaryMathInstruction   ( architecture, operandSize,  addressSize,  0xf6, 3,  ),
+              InstructionType::NOP      =>  instruction.compileZeroOperandInstruction (                                           0x90, ),
+              InstructionType::NOT      =>  instruction.compileUnaryMathInstruction   ( architecture, operandSize,  addressSize,  0xf6, 2,  ),
+              InstructionType::ORPD     =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium4, VectorOpcode ( 0x66, 1, 0x56 ), 16, ),
+              InstructionType::ORPS     =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium3, VectorOpcode ( 0x00, 1, 0x56 ), 16, ),
+              InstructionType::OUT      =>  instruction.compilePortInstruction        ( architecture, operandSize,                0xe6, ),
+              InstructionType::OUTSB    =>  instruction.compileZeroOperandInstructionSince  ( architecture, InstructionSet::i186,     0x6e, ),
+              InstructionType::OUTSW    =>  instruction.compileZeroOperandInstructionSince  ( architecture, InstructionSet::i186,     0x6f, ),
+              InstructionType::PACKSSDW =>  instruction.compileMultiMediaInstruction  ( architecture,               addressSize,  0x6b, ),
+              InstructionType::PACKSSWB =>  instruction.compileMultiMediaInstruction  ( architecture,               addressSize,  0x63, ),
+              InstructionType::PACKUSWB =>  instruction.compileMultiMediaInstruction  ( architecture,               addressSize,  0x67, ),
+              InstructionType::PADDB    =>  instruction.compileMultiMediaInstruction  ( architecture,               addressSize,  0xfc, ),
+              InstructionType::PADDD    =>  instruction.compileMultiMediaInstruction  ( architecture,               addressSize,  0xfe, ),
+              InstructionType::PADDSB   =>  instruction.compileMultiMediaInstruction  ( architecture,               addressSize,  0xec, ),
+              InstructionType::PADDSW   =>  instruction.compileMultiMediaInstruction  ( architecture,               addressSize,  0xed, ),
+              InstructionType::PADDUSB  =>  instruction.compileMultiMediaInstruction  ( architecture,               addressSize,  0xdc, ),
+              InstructionType::PADDUSW  =>  instruction.compileMultiMediaInstruction  ( architecture,               addressSize,  0xdd, ),
+              InstructionType::PADDW    =>  instruction.compileMultiMediaInstruction  ( architecture,               addressSize,  0xfd, ),
+              InstructionType::PAND     =>  instruction.compileMultiMediaInstruction  ( architecture,               addressSize,  0xdb, ),
+              InstructionType::PANDN    =>  instruction.compileMultiMediaInstruction  ( architecture,               addressSize,  0xdf, ),
+              InstructionType::PCMPEQB  =>  instruction.compileMultiMediaInstruction  ( architecture,               addressSize,  0x74, ),
+              InstructionType::PCMPEQD  =>  instruction.compileMultiMediaInstruction  ( architecture,               addressSize,  0x76, ),
+              InstructionType::PCMPEQW  =>  instruction.compileMultiMediaInstruction  ( architecture,               addressSize,  0x75, ),
+              InstructionType::PCMPGTB  =>  instruction.compileMultiMediaInstruction  ( architecture,               addressSize,  0x64, ),
+              InstructionType::PCMPGTD  =>  instruction.compileMultiMediaInstruction  ( architecture,               addressSize,  0x66, ),
+              InstructionType::PCMPGTW  =>  instruction.compileMultiMediaInstruction  ( architecture,               addressSize,  0x65, ),
+              InstructionType::PMADDWD  =>  instruction.compileMultiMediaInstruction  ( architecture,               addressSize,  0xf5, ),
+              InstructionType::PMULHW   =>  instruction.compileMultiMediaInstruction  ( architecture,               addressSize,  0xe5, ),
+              InstructionType::PMULLW   =>  instruction.compileMultiMediaInstruction  ( architecture,               addressSize,  0xd5, ),
+              InstructionType::POR      =>  instruction.compileMultiMediaInstruction  ( architecture,               addressSize,  0xeb, ),
+              InstructionType::PSLLD    =>  instruction.compileMultiMediaShiftInstruction ( architecture,           addressSize,  0xf2, 0x72, 6, ),
+              InstructionType::PSLLQ    =>  instruction.compileMultiMediaShiftInstruction ( architecture,           addressSize,  0xf3, 0x73, 6, ),
+              InstructionType::PSLLW    =>  instruction.compileMultiMediaShiftInstruction ( architecture,           addressSize,  0xf1, 0x71, 6, ),
+              InstructionType::PSRAD    =>  instruction.compileMultiMediaShiftInstruction ( architecture,           addressSize,  0xe2, 0x72, 4, ),
+              InstructionType::PSRAW    =>  instruction.compileMultiMediaShiftInstruction ( architecture,           addressSize,  0xe1, 0x71, 4, ),
+              InstructionType::PSRLD    =>  instruction.compileMultiMediaShiftInstruction ( architecture,           addressSize,  0xd2, 0x72, 2, ),
+              InstructionType::PSRLQ    =>  instruction.compileMultiMediaShiftInstruction ( architecture,           addressSize,  0xd3, 0x73, 2, ),
+              InstructionType::PSRLW    =>  instruction.compileMultiMediaShiftInstruction ( architecture,           addressSize,  0xd1, 0x71, 2, ),
+              InstructionType::PSUBB    =>  instruction.compileMultiMediaInstruction  ( architecture,               addressSize,  0xf8, ),
+              InstructionType::PSUBD    =>  instruction.compileMultiMediaInstruction  ( architecture,               addressSize,  0xfa, ),
+              InstructionType::PSUBSB   =>  instruction.compileMultiMediaInstruction  ( architecture,               addressSize,  0xe8, ),
+              InstructionType::PSUBSW   =>  instruction.compileMultiMediaInstruction  ( architecture,               addressSize,  0xe9, ),
+              InstructionType::PSUBUSB  =>  instruction.compileMultiMediaInstruction  ( architecture,               addressSize,  0xd8, ),
+              InstructionType::PSUBUSW  =>  instruction.compileMultiMediaInstruction  ( architecture,               addressSize,  0xd9, ),
+              InstructionType::PSUBW    =>  instruction.compileMultiMediaInstruction  ( architecture,               addressSize,  0xf9, ),
+              InstructionType::PUNPCKHBW =>  instruction.compileMultiMediaInstruction  ( architecture,               addressSize,  0x68, ),
+              InstructionType::PUNPCKHDQ =>  instruction.compileMultiMediaInstruction  ( architecture,               addressSize,  0x6a, ),
+              InstructionType::PUNPCKHWD =>  instruction.compileMultiMediaInstruction  ( architecture,               addressSize,  0x69, ),
+              InstructionType::PUNPCKLBW =>  instruction.compileMultiMediaInstruction  ( architecture,               addressSize,  0x60, ),
+              InstructionType::PUNPCKLDQ =>  instruction.compileMultiMediaInstruction  ( architecture,               addressSize,  0x62, ),
+              InstructionType::PUNPCKLWD =>  instruction.compileMultiMediaInstruction  ( architecture,               addressSize,  0x61, ),
+              InstructionType::PXOR     =>  instruction.compileMultiMediaInstruction  ( architecture,               addressSize,  0xef, ),
+              InstructionType::POP      =>  instruction.compilePopInstruction         ( architecture, operandSize,  addressSize,        ),
+              InstructionType::POPA     =>  instruction.compileZeroOperandInstructionSince  ( architecture, InstructionSet::i186,     0x61, ),
               InstructionType::POPF     =>  instruction.compileZeroOperandInstruction (                                           0x9d, ),
-              InstructionType::PUSH     =>  unimplemented!(),
+              InstructionType::PUSH     =>  instruction.compilePushInstruction        ( architecture, operandSize,  addressSize,        ),
+              InstructionType::PUSHA    =>  instruction.compileZeroOperandInstructionSince  ( architecture, InstructionSet::i186,     0x60, ),
               InstructionType::PUSHF    =>  instruction.compileZeroOperandInstruction (                                           0x9c, ),
-              InstructionType::RCL      =>  unimplemented!(),
-              InstructionType::RCR      =>  unimplemented!(),
-              InstructionType::RETF     =>  unimplemented!(),
-              InstructionType::RETN     =>  unimplemented!(),
-              InstructionType::ROL      =>  unimplemented!(),
-              InstructionType::ROR      =>  unimplemented!(),
+              InstructionType::RDMSR    =>  instruction.compileTwoByteZeroOperandInstructionSince ( architecture, InstructionSet::Pentium, 0x32, ),
+              InstructionType::RDPMC    =>  instruction.compileTwoByteZeroOperandInstructionSince ( architecture, InstructionSet::Pentium2, 0x33, ),
+              InstructionType::RDTSC    =>  instruction.compileTwoByteZeroOperandInstructionSince ( architecture, InstructionSet::Pentium, 0x31, ),
+              InstructionType::RCL      =>  instruction.compileShiftInstruction       ( architecture, operandSize,  addressSize,  2,       ),
+              InstructionType::RCR      =>  instruction.compileShiftInstruction       ( architecture, operandSize,  addressSize,  3,       ),
+              InstructionType::RETF     =>  instruction.compileReturnInstruction      (                                           0xcb, ),
+              InstructionType::RETN     =>  instruction.compileReturnInstruction      (                                           0xc3, ),
+              InstructionType::ROL      =>  instruction.compileShiftInstruction       ( architecture, operandSize,  addressSize,  0,       ),
+              InstructionType::ROR      =>  instruction.compileShiftInstruction       ( architecture, operandSize,  addressSize,  1,       ),
               InstructionType::SAHF     =>  instruction.compileZeroOperandInstruction (                                           0x9e, ),
-              InstructionType::SAL      =>  unimplemented!(),
+              InstructionType::SAL      =>  instruction.compileShiftInstruction       ( architecture, operandSize,  addressSize,  4,       ),
               InstructionType::SALC     =>  instruction.compileZeroOperandInstruction (                                           0xd6, ),
-              InstructionType::SAR      =>  unimplemented!(),
+              InstructionType::SAR      =>  instruction.compileShiftInstruction       ( architecture, operandSize,  addressSize,  7,       ),
               InstructionType::SBB      =>  instruction.compileSimpleMathInstruction  ( architecture, operandSize,  addressSize,  0x18, ),
               InstructionType::SCASB    =>  instruction.compileZeroOperandInstruction (                                           0xae, ),
               InstructionType::SCASW    =>  instruction.compileZeroOperandInstruction (                                           0xaf, ),
-              InstructionType::SHL      =>  unimplemented!(),
-              InstructionType::SHR      =>  unimplemented!(),
+              InstructionType::SETB     =>  instruction.compileSetConditionInstruction ( architecture,               addressSize,  0x02, ),
+              InstructionType::SETBE    =>  instruction.compileSetConditionInstruction ( architecture,               addressSize,  0x06, ),
+              InstructionType::SETE     =>  instruction.compileSetConditionInstruction ( architecture,               addressSize,  0x04, ),
+              InstructionType::SETL     =>  instruction.compileSetConditionInstruction ( architecture,               addressSize,  0x0c, ),
+              InstructionType::SETLE    =>  instruction.compileSetConditionInstruction ( architecture,               addressSize,  0x0e, ),
+              InstructionType::SETNB    =>  instruction.compileSetConditionInstruction ( architecture,               addressSize,  0x03, ),
+              InstructionType::SETNBE   =>  instruction.compileSetConditionInstruction ( architecture,               addressSize,  0x07, ),
+              InstructionType::SETNE    =>  instruction.compileSetConditionInstruction ( architecture,               addressSize,  0x05, ),
+              InstructionType::SETNL    =>  instruction.compileSetConditionInstruction ( architecture,               addressSize,  0x0d, ),
+              InstructionType::SETNLE   =>  instruction.compileSetConditionInstruction ( architecture,               addressSize,  0x0f, ),
+              InstructionType::SETNO    =>  instruction.compileSetConditionInstruction ( architecture,               addressSize,  0x01, ),
+              InstructionType::SETNP    =>  instruction.compileSetConditionInstruction ( architecture,               addressSize,  0x0b, ),
+              InstructionType::SETNS    =>  instruction.compileSetConditionInstruction ( architecture,               addressSize,  0x09, ),
+              InstructionType::SETO     =>  instruction.compileSetConditionInstruction ( architecture,               addressSize,  0x00, ),
+              InstructionType::SETP     =>  instruction.compileSetConditionInstruction ( architecture,               addressSize,  0x0a, ),
+              InstructionType::SETS     =>  instruction.compileSetConditionInstruction ( architecture,               addressSize,  0x08, ),
+              InstructionType::SGDT     =>  instruction.compileDescriptorTableInstruction ( architecture,               addressSize,  InstructionSet::i286, 0,  ),
+              InstructionType::SHL      =>  instruction.compileShiftInstruction       ( architecture, operandSize,  addressSize,  4,       ),
+              InstructionType::SHLD     =>  instruction.compileDoubleShiftInstruction ( architecture, operandSize,  addressSize,  0xa4, ),
+              InstructionType::SHR      =>  instruction.compileShiftInstruction       ( architecture, operandSize,  addressSize,  5,       ),
+              InstructionType::SHRD     =>  instruction.compileDoubleShiftInstruction ( architecture, operandSize,  addressSize,  0xac, ),
+              InstructionType::SIDT     =>  instruction.compileDescriptorTableInstruction ( architecture,               addressSize,  InstructionSet::i286, 1,  ),
+              InstructionType::SLDT     =>  instruction.compileSystemInstruction      ( architecture,               addressSize,  0x00, 0,  ),
+              InstructionType::SMSW     =>  instruction.compileSystemInstruction      ( architecture,               addressSize,  0x01, 4,  ),
+              InstructionType::SQRTPD   =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium4, VectorOpcode ( 0x66, 1, 0x51 ), 16, ),
+              InstructionType::SQRTPS   =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium3, VectorOpcode ( 0x00, 1, 0x51 ), 16, ),
+              InstructionType::SQRTSD   =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium4, VectorOpcode ( 0xf2, 1, 0x51 ), 8, ),
+              InstructionType::SQRTSS   =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium3, VectorOpcode ( 0xf3, 1, 0x51 ), 4, ),
               InstructionType::STC      =>  instruction.compileZeroOperandInstruction (                                           0xf9, ),
               InstructionType::STD      =>  instruction.compileZeroOperandInstruction (                                           0xfd, ),
               InstructionType::STI      =>  instruction.compileZeroOperandInstruction (                                           0xfb, ),
               InstructionType::STOSB    =>  instruction.compileZeroOperandInstruction (                                           0xaa, ),
               InstructionType::STOSW    =>  instruction.compileZeroOperandInstruction (                                           0xab, ),
+              InstructionType::STR      =>  instruction.compileSystemInstruction      ( architecture,               addressSize,  0x00, 1,  ),
+              InstructionType::SUBPD    =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium4, VectorOpcode ( 0x66, 1, 0x5c ), 16, ),
+              InstructionType::SUBPS    =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium3, VectorOpcode ( 0x00, 1, 0x5c ), 16, ),
+              InstructionType::SUBSD    =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium4, VectorOpcode ( 0xf2, 1, 0x5c ), 8, ),
+              InstructionType::SUBSS    =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium3, VectorOpcode ( 0xf3, 1, 0x5c ), 4, ),
+              InstructionType::SYSENTER =>  instruction.compileTwoByteZeroOperandInstructionSince ( architecture, InstructionSet::Pentium2, 0x34, ),
+              InstructionType::SYSEXIT  =>  instruction.compileTwoByteZeroOperandInstructionSince ( architecture, InstructionSet::Pentium2, 0x35, ),
               InstructionType::SUB      =>  instruction.compileSimpleMathInstruction  ( architecture, operandSize,  addressSize,  0x28, ),
-              InstructionType::TEST     =>  unimplemented!(),
-              InstructionType::WAIT     =>  instruction.compileZeroOperandInstruction (                                           0xdb, ),
-              InstructionType::XCHG     =>  unimplemented!(),
+              InstructionType::TEST     =>  instruction.compileTestInstruction        ( architecture, operandSize,  addressSize,        ),
+              InstructionType::UCOMISD  =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium4, VectorOpcode ( 0x66, 1, 0x2e ), 8, ),
+              InstructionType::UCOMISS  =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium3, VectorOpcode ( 0x00, 1, 0x2e ), 4, ),
+              InstructionType::UD2      =>  instruction.compileTwoByteZeroOperandInstructionSince ( architecture, InstructionSet::Pentium2, 0x0b, ),
+              InstructionType::VADDPD   =>  instruction.compileEnhancedVectorInstruction ( architecture,               addressSize,  Some  ( InstructionSet::SandyBridge ), VectorOpcode ( 0x66, 1, 0x58 ), EnhancedVectorOptions ( true, 0, true ), ),
+              InstructionType::VADDPS   =>  instruction.compileEnhancedVectorInstruction ( architecture,               addressSize,  Some  ( InstructionSet::SandyBridge ), VectorOpcode ( 0x00, 1, 0x58 ), EnhancedVectorOptions ( false, 0, true ), ),
+              InstructionType::VADDSD   =>  instruction.compileEnhancedVectorInstruction ( architecture,               addressSize,  Some  ( InstructionSet::SandyBridge ), VectorOpcode ( 0xf2, 1, 0x58 ), EnhancedVectorOptions ( true, 8, true ), ),
+              InstructionType::VADDSS   =>  instruction.compileEnhancedVectorInstruction ( architecture,               addressSize,  Some  ( InstructionSet::SandyBridge ), VectorOpcode ( 0xf3, 1, 0x58 ), EnhancedVectorOptions ( false, 4, true ), ),
+              InstructionType::VANDNPD  =>  instruction.compileVectorInstruction ( architecture,               addressSize,  InstructionSet::SandyBridge, VectorOpcode ( 0x66, 1, 0x55 ), 0, ),
+              InstructionType::VANDNPS  =>  instruction.compileVectorInstruction ( architecture,               addressSize,  InstructionSet::SandyBridge, VectorOpcode ( 0x00, 1, 0x55 ), 0, ),
+              InstructionType::VANDPD   =>  instruction.compileVectorInstruction ( architecture,               addressSize,  InstructionSet::SandyBridge, VectorOpcode ( 0x66, 1, 0x54 ), 0, ),
+              InstructionType::VANDPS   =>  instruction.compileVectorInstruction ( architecture,               addressSize,  InstructionSet::SandyBridge, VectorOpcode ( 0x00, 1, 0x54 ), 0, ),
+              InstructionType::VBROADCASTSS =>  instruction.compileEnhancedVectorBroadcastInstruction ( architecture,               addressSize,  InstructionSet::SandyBridge, 0x18, 4, false, ),
+              InstructionType::VCMPPD   =>  instruction.compileMaskCompareInstruction ( architecture,               addressSize,  VectorOpcode ( 0x66, 1, 0xc2 ), true, true, ),
+              InstructionType::VCMPPS   =>  instruction.compileMaskCompareInstruction ( architecture,               addressSize,  VectorOpcode ( 0x00, 1, 0xc2 ), false, true, ),
+              InstructionType::VCMPSD   =>  instruction.compileVectorCompareInstruction ( architecture,               addressSize,  0xf2, 8, ),
+              InstructionType::VCMPSS   =>  instruction.compileVectorCompareInstruction ( architecture,               addressSize,  0xf3, 4, ),
+              InstructionType::VDIVPD   =>  instruction.compileEnhancedVectorInstruction ( architecture,               addressSize,  Some  ( InstructionSet::SandyBridge ), VectorOpcode ( 0x66, 1, 0x5e ), EnhancedVectorOptions ( true, 0, true ), ),
+              InstructionType::VDIVPS   =>  instruction.compileEnhancedVectorInstruction ( architecture,               addressSize,  Some  ( InstructionSet::SandyBridge ), VectorOpcode ( 0x00, 1, 0x5e ), EnhancedVectorOptions ( false, 0, true ), ),
+              InstructionType::VDIVSD   =>  instruction.compileEnhancedVectorInstruction ( architecture,               addressSize,  Some  ( InstructionSet::SandyBridge ), VectorOpcode ( 0xf2, 1, 0x5e ), EnhancedVectorOptions ( true, 8, true ), ),
+              InstructionType::VDIVSS   =>  instruction.compileEnhancedVectorInstruction ( architecture,               addressSize,  Some  ( InstructionSet::SandyBridge ), VectorOpcode ( 0xf3, 1, 0x5e ), EnhancedVectorOptions ( false, 4, true ), ),
+              InstructionType::VERR     =>  instruction.compileSystemInstruction      ( architecture,               addressSize,  0x00, 4,  ),
+              InstructionType::VERW     =>  instruction.compileSystemInstruction      ( architecture,               addressSize,  0x00, 5,  ),
+              InstructionType::VEXTRACTF128 =>  instruction.compileVectorLaneInstruction ( architecture,               addressSize,  InstructionSet::SandyBridge, 0x19, ),
+              InstructionType::VEXTRACTI128 =>  instruction.compileVectorLaneInstruction ( architecture,               addressSize,  InstructionSet::Haswell, 0x39, ),
+              InstructionType::VINSERTF128 =>  instruction.compileVectorLaneInstruction ( architecture,               addressSize,  InstructionSet::SandyBridge, 0x18, ),
+              InstructionType::VINSERTI128 =>  instruction.compileVectorLaneInstruction ( architecture,               addressSize,  InstructionSet::Haswell, 0x38, ),
+              InstructionType::VMAXPD   =>  instruction.compileEnhancedVectorInstruction ( architecture,               addressSize,  Some  ( InstructionSet::SandyBridge ), VectorOpcode ( 0x66, 1, 0x5f ), EnhancedVectorOptions ( true, 0, false ), ),
+              InstructionType::VMAXPS   =>  instruction.compileEnhancedVectorInstruction ( architecture,               addressSize,  Some  ( InstructionSet::SandyBridge ), VectorOpcode ( 0x00, 1, 0x5f ), EnhancedVectorOptions ( false, 0, false ), ),
+              InstructionType::VMINPD   =>  instruction.compileEnhancedVectorInstruction ( architecture,               addressSize,  Some  ( InstructionSet::SandyBridge ), VectorOpcode ( 0x66, 1, 0x5d ), EnhancedVectorOptions ( true, 0, false ), ),
+              InstructionType::VMINPS   =>  instruction.compileEnhancedVectorInstruction ( architecture,               addressSize,  Some  ( InstructionSet::SandyBridge ), VectorOpcode ( 0x00, 1, 0x5d ), EnhancedVectorOptions ( false, 0, false ), ),
+              InstructionType::VMOVAPD  =>  instruction.compileEnhancedVectorMoveInstruction ( architecture,               addressSize,  VectorOpcode ( 0x66, 1, 0x28 ), 0x29, true, true, ),
+              InstructionType::VMOVAPS  =>  instruction.compileEnhancedVectorMoveInstruction ( architecture,               addressSize,  VectorOpcode ( 0x00, 1, 0x28 ), 0x29, false, true, ),
+              InstructionType::VMOVDQA  =>  instruction.compileVectorMoveInstruction ( architecture,               addressSize,  VectorOpcode ( 0x66, 1, 0x6f ), 0x7f, ),
+              InstructionType::VMOVDQA32 =>  instruction.compileEnhancedVectorMoveInstruction ( architecture,               addressSize,  VectorOpcode ( 0x66, 1, 0x6f ), 0x7f, false, false, ),
+              InstructionType::VMOVDQA64 =>  instruction.compileEnhancedVectorMoveInstruction ( architecture,               addressSize,  VectorOpcode ( 0x66, 1, 0x6f ), 0x7f, true, false, ),
+              InstructionType::VMOVDQU  =>  instruction.compileVectorMoveInstruction ( architecture,               addressSize,  VectorOpcode ( 0xf3, 1, 0x6f ), 0x7f, ),
+              InstructionType::VMOVDQU32 =>  instruction.compileEnhancedVectorMoveInstruction ( architecture,               addressSize,  VectorOpcode ( 0xf3, 1, 0x6f ), 0x7f, false, false, ),
+              InstructionType::VMOVDQU64 =>  instruction.compileEnhancedVectorMoveInstruction ( architecture,               addressSize,  VectorOpcode ( 0xf3, 1, 0x6f ), 0x7f, true, false, ),
+              InstructionType::VMOVUPD  =>  instruction.compileEnhancedVectorMoveInstruction ( architecture,               addressSize,  VectorOpcode ( 0x66, 1, 0x10 ), 0x11, true, true, ),
+              InstructionType::VMOVUPS  =>  instruction.compileEnhancedVectorMoveInstruction ( architecture,               addressSize,  VectorOpcode ( 0x00, 1, 0x10 ), 0x11, false, true, ),
+              InstructionType::VMULPD   =>  instruction.compileEnhancedVectorInstruction ( architecture,               addressSize,  Some  ( InstructionSet::SandyBridge ), VectorOpcode ( 0x66, 1, 0x59 ), EnhancedVectorOptions ( true, 0, true ), ),
+              InstructionType::VMULPS   =>  instruction.compileEnhancedVectorInstruction ( architecture,               addressSize,  Some  ( InstructionSet::SandyBridge ), VectorOpcode ( 0x00, 1, 0x59 ), EnhancedVectorOptions ( false, 0, true ), ),
+              InstructionType::VMULSD   =>  instruction.compileEnhancedVectorInstruction ( architecture,               addressSize,  Some  ( InstructionSet::SandyBridge ), VectorOpcode ( 0xf2, 1, 0x59 ), EnhancedVectorOptions ( true, 8, true ), ),
+              InstructionType::VMULSS   =>  instruction.compileEnhancedVectorInstruction ( architecture,               addressSize,  Some  ( InstructionSet::SandyBridge ), VectorOpcode ( 0xf3, 1, 0x59 ), EnhancedVectorOptions ( false, 4, true ), ),
+              InstructionType::VORPD    =>  instruction.compileVectorInstruction ( architecture,               addressSize,  InstructionSet::SandyBridge, VectorOpcode ( 0x66, 1, 0x56 ), 0, ),
+              InstructionType::VORPS    =>  instruction.compileVectorInstruction ( architecture,               addressSize,  InstructionSet::SandyBridge, VectorOpcode ( 0x00, 1, 0x56 ), 0, ),
+              InstructionType::VPADDB   =>  instruction.compileVectorIntegerInstruction ( architecture,               addressSize,  1, 0xfc, ),
+              InstructionType::VPADDD   =>  instruction.compileEnhancedVectorIntegerInstruction ( architecture,               addressSize,  1, 0xfe, false, ),
+              InstructionType::VPADDQ   =>  instruction.compileEnhancedVectorIntegerInstruction ( architecture,               addressSize,  1, 0xd4, true, ),
+              InstructionType::VPADDW   =>  instruction.compileVectorIntegerInstruction ( architecture,               addressSize,  1, 0xfd, ),
+              InstructionType::VPAND    =>  instruction.compileVectorIntegerInstruction ( architecture,               addressSize,  1, 0xdb, ),
+              InstructionType::VPANDD   =>  instruction.compileEnhancedVectorInstruction ( architecture,               addressSize,  None, VectorOpcode ( 0x66, 1, 0xdb ), EnhancedVectorOptions ( false, 0, false ), ),
+              InstructionType::VPANDN   =>  instruction.compileVectorIntegerInstruction ( architecture,               addressSize,  1, 0xdf, ),
+              InstructionType::VPANDND  =>  instruction.compileEnhancedVectorInstruction ( architecture,               addressSize,  None, VectorOpcode ( 0x66, 1, 0xdf ), EnhancedVectorOptions ( false, 0, false ), ),
+              InstructionType::VPANDNQ  =>  instruction.compileEnhancedVectorInstruction ( architecture,               addressSize,  None, VectorOpcode ( 0x66, 1, 0xdf ), EnhancedVectorOptions ( true, 0, false ), ),
+              InstructionType::VPANDQ   =>  instruction.compileEnhancedVectorInstruction ( architecture,               addressSize,  None, VectorOpcode ( 0x66, 1, 0xdb ), EnhancedVectorOptions ( true, 0, false ), ),
+              InstructionType::VPBROADCASTD =>  instruction.compileEnhancedVectorBroadcastInstruction ( architecture,               addressSize,  InstructionSet::Haswell, 0x58, 4, false, ),
+              InstructionType::VPBROADCASTQ =>  instruction.compileEnhancedVectorBroadcastInstruction ( architecture,               addressSize,  InstructionSet::Haswell, 0x59, 8, true, ),
+              InstructionType::VPCMOV   =>  instruction.compileExtendedFourOperandInstruction ( architecture,               addressSize,  0xa2, true,  true, ),
+              InstructionType::VPCMPEQB =>  instruction.compileVectorIntegerInstruction ( architecture,               addressSize,  1, 0x74, ),
+              InstructionType::VPCMPEQD =>  instruction.compileMaskCompareInstruction ( architecture,               addressSize,  VectorOpcode ( 0x66, 1, 0x76 ), false, false, ),
+              InstructionType::VPCMPEQQ =>  instruction.compileMaskCompareInstruction ( architecture,               addressSize,  VectorOpcode ( 0x66, 2, 0x29 ), true, false, ),
+              InstructionType::VPCMPEQW =>  instruction.compileVectorIntegerInstruction ( architecture,               addressSize,  1, 0x75, ),
+              InstructionType::VPERM2F128 =>  instruction.compileVectorPermuteInstruction ( architecture,               addressSize,  InstructionSet::SandyBridge, 0x06, false, ),
+              InstructionType::VPERM2I128 =>  instruction.compileVectorPermuteInstruction ( architecture,               addressSize,  InstructionSet::Haswell, 0x46, false, ),
+              InstructionType::VPERMPD  =>  instruction.compileVectorPermuteInstruction ( architecture,               addressSize,  InstructionSet::Haswell, 0x01, true, ),
+              InstructionType::VPERMQ   =>  instruction.compileVectorPermuteInstruction ( architecture,               addressSize,  InstructionSet::Haswell, 0x00, true, ),
+              InstructionType::VPMACSDD =>  instruction.compileExtendedFourOperandInstruction ( architecture,               addressSize,  0x9e, false, false, ),
+              InstructionType::VPMACSDQH =>  instruction.compileExtendedFourOperandInstruction ( architecture,               addressSize,  0x9f, false, false, ),
+              InstructionType::VPMACSDQL =>  instruction.compileExtendedFourOperandInstruction ( architecture,               addressSize,  0x97, false, false, ),
+              InstructionType::VPMACSSDD =>  instruction.compileExtendedFourOperandInstruction ( architecture,               addressSize,  0x8e, false, false, ),
+              InstructionType::VPMACSSDQH =>  instruction.compileExtendedFourOperandInstruction ( architecture,               addressSize,  0x8f, false, false, ),
+              InstructionType::VPMACSSDQL =>  instruction.compileExtendedFourOperandInstruction ( architecture,               addressSize,  0x87, false, false, ),
+              InstructionType::VPMACSSWD =>  instruction.compileExtendedFourOperandInstruction ( architecture,               addressSize,  0x86, false, false, ),
+              InstructionType::VPMACSSWW =>  instruction.compileExtendedFourOperandInstruction ( architecture,               addressSize,  0x85, false, false, ),
+              InstructionType::VPMACSWD =>  instruction.compileExtendedFourOperandInstruction ( architecture,               addressSize,  0x96, false, false, ),
+              InstructionType::VPMACSWW =>  instruction.compileExtendedFourOperandInstruction ( architecture,               addressSize,  0x95, false, false, ),
+              InstructionType::VPMULLD  =>  instruction.compileEnhancedVectorIntegerInstruction ( architecture,               addressSize,  2, 0x40, false, ),
+              InstructionType::VPOR     =>  instruction.compileVectorIntegerInstruction ( architecture,               addressSize,  1, 0xeb, ),
+              InstructionType::VPORD    =>  instruction.compileEnhancedVectorInstruction ( architecture,               addressSize,  None, VectorOpcode ( 0x66, 1, 0xeb ), EnhancedVectorOptions ( false, 0, false ), ),
+              InstructionType::VPORQ    =>  instruction.compileEnhancedVectorInstruction ( architecture,               addressSize,  None, VectorOpcode ( 0x66, 1, 0xeb ), EnhancedVectorOptions ( true, 0, false ), ),
+              InstructionType::VPPERM   =>  instruction.compileExtendedFourOperandInstruction ( architecture,               addressSize,  0xa3, false, true, ),
+              InstructionType::VPROTB   =>  instruction.compileExtendedShiftInstruction ( architecture,               addressSize,  0x90, Some ( 0xc0 ), ),
+              InstructionType::VPROTD   =>  instruction.compileExtendedShiftInstruction ( architecture,               addressSize,  0x92, Some ( 0xc2 ), ),
+              InstructionType::VPROTQ   =>  instruction.compileExtendedShiftInstruction ( architecture,               addressSize,  0x93, Some ( 0xc3 ), ),
+              InstructionType::VPROTW   =>  instruction.compileExtendedShiftInstruction ( architecture,               addressSize,  0x91, Some ( 0xc1 ), ),
+              InstructionType::VPSHAB   =>  instruction.compileExtendedShiftInstruction ( architecture,               addressSize,  0x98, None, ),
+              InstructionType::VPSHAD   =>  instruction.compileExtendedShiftInstruction ( architecture,               addressSize,  0x9a, None, ),
+              InstructionType::VPSHAQ   =>  instruction.compileExtendedShiftInstruction ( architecture,               addressSize,  0x9b, None, ),
+              InstructionType::VPSHAW   =>  instruction.compileExtendedShiftInstruction ( architecture,               addressSize,  0x99, None, ),
+              InstructionType::VPSLLVD  =>  instruction.compileVectorShiftInstruction ( architecture,               addressSize,  0x47, false, ),
+              InstructionType::VPSLLVQ  =>  instruction.compileVectorShiftInstruction ( architecture,               addressSize,  0x47, true, ),
+              InstructionType::VPSRAVD  =>  instruction.compileVectorShiftInstruction ( architecture,               addressSize,  0x46, false, ),
+              InstructionType::VPSRLVD  =>  instruction.compileVectorShiftInstruction ( architecture,               addressSize,  0x45, false, ),
+              InstructionType::VPSRLVQ  =>  instruction.compileVectorShiftInstruction ( architecture,               addressSize,  0x45, true, ),
+              InstructionType::VPSUBB   =>  instruction.compileVectorIntegerInstruction ( architecture,               addressSize,  1, 0xf8, ),
+              InstructionType::VPSUBD   =>  instruction.compileEnhancedVectorIntegerInstruction ( architecture,               addressSize,  1, 0xfa, false, ),
+              InstructionType::VPSUBQ   =>  instruction.compileEnhancedVectorIntegerInstruction ( architecture,               addressSize,  1, 0xfb, true, ),
+              InstructionType::VPSUBW   =>  instruction.compileVectorIntegerInstruction ( architecture,               addressSize,  1, 0xf9, ),
+              InstructionType::VPTERNLOGD =>  instruction.compileTernaryLogicInstruction ( architecture,               addressSize,  false, ),
+              InstructionType::VPTERNLOGQ =>  instruction.compileTernaryLogicInstruction ( architecture,               addressSize,  true, ),
+              InstructionType::VPXOR    =>  instruction.compileVectorIntegerInstruction ( architecture,               addressSize,  1, 0xef, ),
+              InstructionType::VPXORD   =>  instruction.compileEnhancedVectorInstruction ( architecture,               addressSize,  None, VectorOpcode ( 0x66, 1, 0xef ), EnhancedVectorOptions ( false, 0, false ), ),
+              InstructionType::VPXORQ   =>  instruction.compileEnhancedVectorInstruction ( architecture,               addressSize,  None, VectorOpcode ( 0x66, 1, 0xef ), EnhancedVectorOptions ( true, 0, false ), ),
+              InstructionType::VSQRTPD  =>  instruction.compileEnhancedVectorUnaryInstruction ( architecture,               addressSize,  0x66, 0x51, true, ),
+              InstructionType::VSQRTPS  =>  instruction.compileEnhancedVectorUnaryInstruction ( architecture,               addressSize,  0x00, 0x51, false, ),
+              InstructionType::VSUBPD   =>  instruction.compileEnhancedVectorInstruction ( architecture,               addressSize,  Some  ( InstructionSet::SandyBridge ), VectorOpcode ( 0x66, 1, 0x5c ), EnhancedVectorOptions ( true, 0, true ), ),
+              InstructionType::VSUBPS   =>  instruction.compileEnhancedVectorInstruction ( architecture,               addressSize,  Some  ( InstructionSet::SandyBridge ), VectorOpcode ( 0x00, 1, 0x5c ), EnhancedVectorOptions ( false, 0, true ), ),
+              InstructionType::VSUBSD   =>  instruction.compileEnhancedVectorInstruction ( architecture,               addressSize,  Some  ( InstructionSet::SandyBridge ), VectorOpcode ( 0xf2, 1, 0x5c ), EnhancedVectorOptions ( true, 8, true ), ),
+              InstructionType::VSUBSS   =>  instruction.compileEnhancedVectorInstruction ( architecture,               addressSize,  Some  ( InstructionSet::SandyBridge ), VectorOpcode ( 0xf3, 1, 0x5c ), EnhancedVectorOptions ( false, 4, true ), ),
+              InstructionType::VXORPD   =>  instruction.compileVectorInstruction ( architecture,               addressSize,  InstructionSet::SandyBridge, VectorOpcode ( 0x66, 1, 0x57 ), 0, ),
+              InstructionType::VXORPS   =>  instruction.compileVectorInstruction ( architecture,               addressSize,  InstructionSet::SandyBridge, VectorOpcode ( 0x00, 1, 0x57 ), 0, ),
+              InstructionType::VZEROALL =>  instruction.compileVectorZeroInstruction ( architecture,                             true, ),
+              InstructionType::VZEROUPPER =>  instruction.compileVectorZeroInstruction ( architecture,                             false, ),
+              InstructionType::WAIT     =>  instruction.compileZeroOperandInstruction (                                           0x9b, ),
+              InstructionType::WRMSR    =>  instruction.compileTwoByteZeroOperandInstructionSince ( architecture, InstructionSet::Pentium, 0x30, ),
+              InstructionType::XCHG     =>  instruction.compileExchangeInstruction    ( architecture, operandSize,  addressSize,        ),
+              InstructionType::XADD     =>  instruction.compileAtomicExchangeInstruction ( architecture, operandSize,  addressSize,  0xc0, ),
               InstructionType::XLAT     =>  instruction.compileZeroOperandInstruction (                                           0xd7, ),
               InstructionType::XOR      =>  instruction.compileSimpleMathInstruction  ( architecture, operandSize,  addressSize,  0x30, ),
+              InstructionType::XORPD    =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium4, VectorOpcode ( 0x66, 1, 0x57 ), 16, ),
+              InstructionType::XORPS    =>  instruction.compileStreamingInstruction ( architecture,               addressSize,  InstructionSet::Pentium3, VectorOpcode ( 0x00, 1, 0x57 ), 16, ),
               _                         =>  panic!  ( "Unexpected Instruction. This should not happen here!"  ),
             }?;
+
+          //  the REX prefix and relative displacements depend on the complete instruction
+          length                        =   instruction.encodeRegisterExtension ( architecture, addressSize,  length  )?;
+          instruction.encodeRelativeDisplacement  ( );
         }
 
         //  address calculations
@@ -363,6 +870,9 @@ impl X86
         instruction.print       ();
         if let Some ( opcode ) = instruction.getOpcode()
         {
+          //  the 8087 needs the cpu to wait for the previous floating point instruction
+          if instruction.hazWait()                  { output.push ( Wait                              ); }
+
           //  Group 1
           if instruction.hazLock()                  { output.push ( Lock                              ); }
           if instruction.hazRepeat()                { output.push ( instruction.getRepeat()           ); }
@@ -377,9 +887,17 @@ impl X86
           //  Group 4
           if instruction.hazAddressSizeOverride()   { output.push ( AddressSizeOverride               ); }
 
+          //  0x66, 0xf2 and 0xf3 select the instruction and must immediately precede REX and opcode
+          if instruction.hazMandatoryPrefix()       { output.push ( instruction.getMandatoryPrefix()  ); }
+
           if instruction.hazThreeByteXOP()          { output.push ( ThreeByteXOP                      ); }
           if instruction.hazTwoByteVEX()            { output.push ( TwoByteVEX                        ); }
           if instruction.hazThreeByteVEX()          { output.push ( ThreeByteVEX                      ); }
+          if instruction.hazFourByteEVEX()          { output.push ( FourByteEVEX                      ); }
+          for value                     in  instruction.getVectorPayload()
+          {
+            output.push ( value );
+          }
           if instruction.hazREX()                   { output.push ( instruction.getREX()              ); }
 
           //  Opcode
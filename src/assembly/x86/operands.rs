@@ -11,6 +11,8 @@ use super::
   memory::
   {
     Memory16Registers,
+    Memory32InstructionPointer,
+    Memory32NoRegister,
   },
   registers::
   {
@@ -62,25 +64,37 @@ pub enum OperandType
     displacement:                       i128,
   },
   //  indirect addressing:  address =   16  * segment register  + base  + scale * index + displacement
+  //                        base and index are Memory32NoRegister, if not present, the base might be Memory32InstructionPointer
   Memory32
   {
+    width:                              usize,
     segment:                            SegmentRegisterNumber,
     base:                               u8,
     scale:                              u8,
     index:                              u8,
     displacement:                       i128,
   },
+  //  relative addressing:  address =   instruction pointer                               + displacement to symbol
+  //                        an abstract type, which resolves to Memory32 relative to the instruction pointer
+  RipRelative
+  {
+    segment:                            SegmentRegisterNumber,
+    target:                             SymbolIdentifier,
+  },
   //  registers
   GeneralPurposeRegister
   {
     rex:                                bool,
     number:                             u8,
+    size:                               usize,
   },
   SegmentRegister                       ( SegmentRegisterNumber ),
   ControlRegister                       ( u8                    ),
   DebugRegister                         ( u8                    ),
   TestRegister                          ( u8                    ),
+  FloatingPointRegister                 ( u8                    ),
   MulitMediaRegister                    ( u8                    ),
+  MaskRegister                          ( u8                    ),
 }
 
 impl OperandType
@@ -93,6 +107,24 @@ impl OperandType
   {
     print!  ( " {},", self.to_string  ( size ) );
   }
+  fn sizeName
+  (
+    size:                               usize,
+  ) ->  String
+  {
+    match size
+    {
+      1 =>  "byte".to_string(),
+      2 =>  "word".to_string(),
+      4 =>  "dword".to_string(),
+      8 =>  "qword".to_string(),
+      10  =>  "tword".to_string(),
+      16  =>  "oword".to_string(),
+      32  =>  "yword".to_string(),
+      64  =>  "zword".to_string(),
+      _ =>  format! ( "{}", size ),
+    }
+  }
   pub fn to_string
   (
     &self,
@@ -124,14 +156,7 @@ impl OperandType
       =>  format!
           (
             "{} {}:[ {}{} ]",
-            match size
-            {
-              1 =>  "byte".to_string(),
-              2 =>  "word".to_string(),
-              4 =>  "dword".to_string(),
-              8 =>  "qword".to_string(),
-              _ =>  format! ( "{}", size ),
-            },
+            OperandType::sizeName ( size ),
             segment.to_string(),
             displacement,
             match registers
@@ -148,9 +173,47 @@ impl OperandType
               _                         =>  " + ???",
             },
           ),
-      OperandType::Memory32               { .. }
-      =>  format! ( "[…]" ),
-      OperandType::GeneralPurposeRegister { rex, number }
+      OperandType::Memory32               { segment, base: Memory32InstructionPointer, displacement, .. }
+      =>  format!
+          (
+            "{} {}:[ rip + {} ]",
+            OperandType::sizeName ( size ),
+            segment.to_string(),
+            displacement,
+          ),
+      OperandType::RipRelative            { segment, target }
+      =>  format!
+          (
+            "{} {}:[ rip + ${{{}}} ]",
+            OperandType::sizeName ( size ),
+            segment.to_string(),
+            target,
+          ),
+      OperandType::Memory32               { width, segment, base, scale, index, displacement }
+      =>  format!
+          (
+            "{} {}:[ {}{}{} ]",
+            OperandType::sizeName ( size ),
+            segment.to_string(),
+            displacement,
+            if *base  ==  Memory32NoRegister
+            {
+              "".to_string()
+            }
+            else
+            {
+              format! ( " + {}",      OperandType::GeneralPurposeRegister { rex: false, number: *base,  size: width / 8 }.to_string ( width / 8 ) )
+            },
+            if *index ==  Memory32NoRegister
+            {
+              "".to_string()
+            }
+            else
+            {
+              format! ( " + {}·{}",   scale,  OperandType::GeneralPurposeRegister { rex: false, number: *index, size: width / 8 }.to_string ( width / 8 ) )
+            },
+          ),
+      OperandType::GeneralPurposeRegister { rex, number, .. }
       =>  {
             match size
             {
@@ -162,17 +225,17 @@ impl OperandType
                         2 =>  format! ( "dl"                      ),
                         3 =>  format! ( "bl"                      ),
                         4 if *rex
-                        =>    format! ( "ah"                      ),
-                        4 =>  format! ( "spl"                     ),
+                        =>    format! ( "spl"                     ),
+                        4 =>  format! ( "ah"                      ),
                         5 if *rex
-                        =>    format! ( "ch"                      ),
-                        5 =>  format! ( "bpl"                     ),
+                        =>    format! ( "bpl"                     ),
+                        5 =>  format! ( "ch"                      ),
                         6 if *rex
-                        =>    format! ( "dh"                      ),
-                        6 =>  format! ( "sil"                     ),
+                        =>    format! ( "sil"                     ),
+                        6 =>  format! ( "dh"                      ),
                         7 if *rex
-                        =>    format! ( "bh"                      ),
-                        7 =>  format! ( "dil"                     ),
+                        =>    format! ( "dil"                     ),
+                        7 =>  format! ( "bh"                      ),
                         8 ... 15
                         =>    format! ( "r{}b",           *number ),
                         _
@@ -240,7 +303,9 @@ impl OperandType
       OperandType::DebugRegister        ( register )
       =>  format! ( "dr{}", register ),
       OperandType::TestRegister         ( register )
-      =>  format! ( "dr{}", register ),
+      =>  format! ( "tr{}", register ),
+      OperandType::FloatingPointRegister  ( register )
+      =>  format! ( "st{}", register ),
       OperandType::MulitMediaRegister   ( register )
       =>  {
             match size
@@ -252,6 +317,8 @@ impl OperandType
               _   =>  format! ( "({})mm{}?",  size, register  ),
             }
           },
+      OperandType::MaskRegister         ( register )
+      =>  format! ( "k{}", register ),
       _
       =>  unimplemented!(),
     }
@@ -17,7 +17,7 @@ pub struct    GeneralPurposeRegister
 
 impl Operand                            for GeneralPurposeRegister
 {
-  fn this   ( self ) -> ( OperandType, usize ) { ( OperandType::GeneralPurposeRegister { rex: self.rex, number: self.number }, self.size ) }
+  fn this   ( self ) -> ( OperandType, usize ) { ( OperandType::GeneralPurposeRegister { rex: self.rex, number: self.number, size: self.size }, self.size ) }
 }
 
 macro_rules!  GeneralPurposeRegister
@@ -46,7 +46,7 @@ pub struct    SegmentRegister
 
 impl Operand                            for SegmentRegister
 {
-  fn this   ( self ) -> ( OperandType, usize ) { ( OperandType::SegmentRegister ( self.number ), 4 ) }
+  fn this   ( self ) -> ( OperandType, usize ) { ( OperandType::SegmentRegister ( self.number ), 0 ) }
 }
 
 macro_rules!  SegmentRegister
@@ -103,7 +103,7 @@ pub struct ControlRegister
 
 impl Operand                            for ControlRegister
 {
-  fn this   ( self ) -> ( OperandType, usize ) { ( OperandType::ControlRegister ( self.number ), 4 ) }
+  fn this   ( self ) -> ( OperandType, usize ) { ( OperandType::ControlRegister ( self.number ), 0 ) }
 }
 
 macro_rules!  ControlRegister
@@ -128,7 +128,7 @@ pub struct DebugRegister
 
 impl Operand                            for DebugRegister
 {
-  fn this   ( self ) -> ( OperandType, usize ) { ( OperandType::ControlRegister ( self.number ), 4 ) }
+  fn this   ( self ) -> ( OperandType, usize ) { ( OperandType::DebugRegister ( self.number ), 0 ) }
 }
 
 macro_rules!  DebugRegister
@@ -153,7 +153,7 @@ pub struct TestRegister
 
 impl Operand                            for TestRegister
 {
-  fn this   ( self ) -> ( OperandType, usize ) { ( OperandType::TestRegister ( self.number ), 4 ) }
+  fn this   ( self ) -> ( OperandType, usize ) { ( OperandType::TestRegister ( self.number ), 0 ) }
 }
 
 macro_rules!  TestRegister
@@ -171,6 +171,31 @@ macro_rules!  TestRegister
       }
 }
 
+pub struct FloatingPointRegister
+{
+  pub number:                           u8,
+}
+
+impl Operand                            for FloatingPointRegister
+{
+  fn this   ( self ) -> ( OperandType, usize ) { ( OperandType::FloatingPointRegister ( self.number ), 0 ) }
+}
+
+macro_rules!  FloatingPointRegister
+{
+  (
+    $theName:ident,
+    $theNumber:expr
+  )
+  =>  {
+        pub const $theName:             FloatingPointRegister
+        = FloatingPointRegister
+          {
+            number:                     $theNumber,
+          };
+      }
+}
+
 pub struct MulitMediaRegister
 {
   pub size:                             usize,
@@ -199,6 +224,31 @@ macro_rules! MulitMediaRegister
       }
 }
 
+pub struct MaskRegister
+{
+  pub number:                           u8,
+}
+
+impl Operand                            for MaskRegister
+{
+  fn this   ( self ) -> ( OperandType, usize ) { ( OperandType::MaskRegister ( self.number ), 0 ) }
+}
+
+macro_rules! MaskRegister
+{
+  (
+    $theName:ident,
+    $theNumber:expr
+  )
+  =>  {
+        pub const $theName:             MaskRegister
+        = MaskRegister
+          {
+            number:                     $theNumber,
+          };
+      }
+}
+
 impl X86
 {
   GeneralPurposeRegister! ( al,   1,  false,  0                         );
@@ -213,15 +263,31 @@ impl X86
   GeneralPurposeRegister! ( bpl,  1,  true,   5                         );
   GeneralPurposeRegister! ( sil,  1,  true,   6                         );
   GeneralPurposeRegister! ( dil,  1,  true,   7                         );
+  GeneralPurposeRegister! ( r8b,  1,  false,  8                         );
+  GeneralPurposeRegister! ( r9b,  1,  false,  9                         );
+  GeneralPurposeRegister! ( r10b, 1,  false, 10                         );
+  GeneralPurposeRegister! ( r11b, 1,  false, 11                         );
+  GeneralPurposeRegister! ( r12b, 1,  false, 12                         );
+  GeneralPurposeRegister! ( r13b, 1,  false, 13                         );
+  GeneralPurposeRegister! ( r14b, 1,  false, 14                         );
+  GeneralPurposeRegister! ( r15b, 1,  false, 15                         );
 
   GeneralPurposeRegister! ( ax,   2,  false,  0                         );
   GeneralPurposeRegister! ( cx,   2,  false,  1                         );
   GeneralPurposeRegister! ( dx,   2,  false,  2                         );
   GeneralPurposeRegister! ( bx,   2,  false,  3                         );
-  GeneralPurposeRegister! ( sp,   1,  false,  4                         );
-  GeneralPurposeRegister! ( bp,   1,  false,  5                         );
-  GeneralPurposeRegister! ( si,   1,  false,  6                         );
-  GeneralPurposeRegister! ( di,   1,  false,  7                         );
+  GeneralPurposeRegister! ( sp,   2,  false,  4                         );
+  GeneralPurposeRegister! ( bp,   2,  false,  5                         );
+  GeneralPurposeRegister! ( si,   2,  false,  6                         );
+  GeneralPurposeRegister! ( di,   2,  false,  7                         );
+  GeneralPurposeRegister! ( r8w,  2,  false,  8                         );
+  GeneralPurposeRegister! ( r9w,  2,  false,  9                         );
+  GeneralPurposeRegister! ( r10w, 2,  false, 10                         );
+  GeneralPurposeRegister! ( r11w, 2,  false, 11                         );
+  GeneralPurposeRegister! ( r12w, 2,  false, 12                         );
+  GeneralPurposeRegister! ( r13w, 2,  false, 13                         );
+  GeneralPurposeRegister! ( r14w, 2,  false, 14                         );
+  GeneralPurposeRegister! ( r15w, 2,  false, 15                         );
 
   GeneralPurposeRegister! ( eax,  4,  false,  0                         );
   GeneralPurposeRegister! ( ecx,  4,  false,  1                         );
@@ -231,6 +297,14 @@ impl X86
   GeneralPurposeRegister! ( ebp,  4,  false,  5                         );
   GeneralPurposeRegister! ( esi,  4,  false,  6                         );
   GeneralPurposeRegister! ( edi,  4,  false,  7                         );
+  GeneralPurposeRegister! ( r8d,  4,  false,  8                         );
+  GeneralPurposeRegister! ( r9d,  4,  false,  9                         );
+  GeneralPurposeRegister! ( r10d, 4,  false, 10                         );
+  GeneralPurposeRegister! ( r11d, 4,  false, 11                         );
+  GeneralPurposeRegister! ( r12d, 4,  false, 12                         );
+  GeneralPurposeRegister! ( r13d, 4,  false, 13                         );
+  GeneralPurposeRegister! ( r14d, 4,  false, 14                         );
+  GeneralPurposeRegister! ( r15d, 4,  false, 15                         );
 
   GeneralPurposeRegister! ( rax,  8,  false,  0                         );
   GeneralPurposeRegister! ( rcx,  8,  false,  1                         );
@@ -274,9 +348,21 @@ impl X86
   DebugRegister!          ( dr6,              6                         );
   DebugRegister!          ( dr7,              7                         );
 
+  TestRegister!           ( tr3,              3                         );
+  TestRegister!           ( tr4,              4                         );
+  TestRegister!           ( tr5,              5                         );
   TestRegister!           ( tr6,              6                         );
   TestRegister!           ( tr7,              7                         );
 
+  FloatingPointRegister!  ( st0,              0                         );
+  FloatingPointRegister!  ( st1,              1                         );
+  FloatingPointRegister!  ( st2,              2                         );
+  FloatingPointRegister!  ( st3,              3                         );
+  FloatingPointRegister!  ( st4,              4                         );
+  FloatingPointRegister!  ( st5,              5                         );
+  FloatingPointRegister!  ( st6,              6                         );
+  FloatingPointRegister!  ( st7,              7                         );
+
   MulitMediaRegister!     ( mm0,  8,          0                         );
   MulitMediaRegister!     ( mm1,  8,          1                         );
   MulitMediaRegister!     ( mm2,  8,          2                         );
@@ -294,6 +380,30 @@ impl X86
   MulitMediaRegister!     ( xmm5, 16,         5                         );
   MulitMediaRegister!     ( xmm6, 16,         6                         );
   MulitMediaRegister!     ( xmm7, 16,         7                         );
+  MulitMediaRegister!     ( xmm8, 16,         8                         );
+  MulitMediaRegister!     ( xmm9, 16,         9                         );
+  MulitMediaRegister!     ( xmm10, 16,       10                         );
+  MulitMediaRegister!     ( xmm11, 16,       11                         );
+  MulitMediaRegister!     ( xmm12, 16,       12                         );
+  MulitMediaRegister!     ( xmm13, 16,       13                         );
+  MulitMediaRegister!     ( xmm14, 16,       14                         );
+  MulitMediaRegister!     ( xmm15, 16,       15                         );
+  MulitMediaRegister!     ( xmm16, 16,       16                         );
+  MulitMediaRegister!     ( xmm17, 16,       17                         );
+  MulitMediaRegister!     ( xmm18, 16,       18                         );
+  MulitMediaRegister!     ( xmm19, 16,       19                         );
+  MulitMediaRegister!     ( xmm20, 16,       20                         );
+  MulitMediaRegister!     ( xmm21, 16,       21                         );
+  MulitMediaRegister!     ( xmm22, 16,       22                         );
+  MulitMediaRegister!     ( xmm23, 16,       23                         );
+  MulitMediaRegister!     ( xmm24, 16,       24                         );
+  MulitMediaRegister!     ( xmm25, 16,       25                         );
+  MulitMediaRegister!     ( xmm26, 16,       26                         );
+  MulitMediaRegister!     ( xmm27, 16,       27                         );
+  MulitMediaRegister!     ( xmm28, 16,       28                         );
+  MulitMediaRegister!     ( xmm29, 16,       29                         );
+  MulitMediaRegister!     ( xmm30, 16,       30                         );
+  MulitMediaRegister!     ( xmm31, 16,       31                         );
 
   MulitMediaRegister!     ( ymm0, 32,         0                         );
   MulitMediaRegister!     ( ymm1, 32,         1                         );
@@ -303,6 +413,30 @@ impl X86
   MulitMediaRegister!     ( ymm5, 32,         5                         );
   MulitMediaRegister!     ( ymm6, 32,         6                         );
   MulitMediaRegister!     ( ymm7, 32,         7                         );
+  MulitMediaRegister!     ( ymm8, 32,         8                         );
+  MulitMediaRegister!     ( ymm9, 32,         9                         );
+  MulitMediaRegister!     ( ymm10, 32,       10                         );
+  MulitMediaRegister!     ( ymm11, 32,       11                         );
+  MulitMediaRegister!     ( ymm12, 32,       12                         );
+  MulitMediaRegister!     ( ymm13, 32,       13                         );
+  MulitMediaRegister!     ( ymm14, 32,       14                         );
+  MulitMediaRegister!     ( ymm15, 32,       15                         );
+  MulitMediaRegister!     ( ymm16, 32,       16                         );
+  MulitMediaRegister!     ( ymm17, 32,       17                         );
+  MulitMediaRegister!     ( ymm18, 32,       18                         );
+  MulitMediaRegister!     ( ymm19, 32,       19                         );
+  MulitMediaRegister!     ( ymm20, 32,       20                         );
+  MulitMediaRegister!     ( ymm21, 32,       21                         );
+  MulitMediaRegister!     ( ymm22, 32,       22                         );
+  MulitMediaRegister!     ( ymm23, 32,       23                         );
+  MulitMediaRegister!     ( ymm24, 32,       24                         );
+  MulitMediaRegister!     ( ymm25, 32,       25                         );
+  MulitMediaRegister!     ( ymm26, 32,       26                         );
+  MulitMediaRegister!     ( ymm27, 32,       27                         );
+  MulitMediaRegister!     ( ymm28, 32,       28                         );
+  MulitMediaRegister!     ( ymm29, 32,       29                         );
+  MulitMediaRegister!     ( ymm30, 32,       30                         );
+  MulitMediaRegister!     ( ymm31, 32,       31                         );
 
   MulitMediaRegister!     ( zmm0, 64,         0                         );
   MulitMediaRegister!     ( zmm1, 64,         1                         );
@@ -312,4 +446,37 @@ impl X86
   MulitMediaRegister!     ( zmm5, 64,         5                         );
   MulitMediaRegister!     ( zmm6, 64,         6                         );
   MulitMediaRegister!     ( zmm7, 64,         7                         );
-}
\ No newline at end of file
+  MulitMediaRegister!     ( zmm8, 64,         8                         );
+  MulitMediaRegister!     ( zmm9, 64,         9                         );
+  MulitMediaRegister!     ( zmm10, 64,       10                         );
+  MulitMediaRegister!     ( zmm11, 64,       11                         );
+  MulitMediaRegister!     ( zmm12, 64,       12                         );
+  MulitMediaRegister!     ( zmm13, 64,       13                         );
+  MulitMediaRegister!     ( zmm14, 64,       14                         );
+  MulitMediaRegister!     ( zmm15, 64,       15                         );
+  MulitMediaRegister!     ( zmm16, 64,       16                         );
+  MulitMediaRegister!     ( zmm17, 64,       17                         );
+  MulitMediaRegister!     ( zmm18, 64,       18                         );
+  MulitMediaRegister!     ( zmm19, 64,       19                         );
+  MulitMediaRegister!     ( zmm20, 64,       20                         );
+  MulitMediaRegister!     ( zmm21, 64,       21                         );
+  MulitMediaRegister!     ( zmm22, 64,       22                         );
+  MulitMediaRegister!     ( zmm23, 64,       23                         );
+  MulitMediaRegister!     ( zmm24, 64,       24                         );
+  MulitMediaRegister!     ( zmm25, 64,       25                         );
+  MulitMediaRegister!     ( zmm26, 64,       26                         );
+  MulitMediaRegister!     ( zmm27, 64,       27                         );
+  MulitMediaRegister!     ( zmm28, 64,       28                         );
+  MulitMediaRegister!     ( zmm29, 64,       29                         );
+  MulitMediaRegister!     ( zmm30, 64,       30                         );
+  MulitMediaRegister!     ( zmm31, 64,       31                         );
+
+  MaskRegister!           ( k0,              0                         );
+  MaskRegister!           ( k1,              1                         );
+  MaskRegister!           ( k2,              2                         );
+  MaskRegister!           ( k3,              3                         );
+  MaskRegister!           ( k4,              4                         );
+  MaskRegister!           ( k5,              5                         );
+  MaskRegister!           ( k6,              6                         );
+  MaskRegister!           ( k7,              7                         );
+}
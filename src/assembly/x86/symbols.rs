@@ -29,9 +29,7 @@ impl  Operand                           for Symbol
 pub struct  SymbolEntry
 {
   title:                                SymbolIdentifier,
-  first:                                Option<OperandType>,
   value:                                Option<OperandType>,
-  round:                                usize,
 }
 
 pub type    SymbolIdentifier            =   &'static  str;
@@ -49,17 +47,11 @@ impl        SymbolList
     &mut self,
     title:                              SymbolIdentifier,
     value:                              Option<OperandType>,
-    round:                              usize,
   ) ->  SymbolReference
   {
     if let Some ( refer ) = self.hashMap.get  ( title )
     {
-      if self.symbols [ *refer  ].first.is_none ( )
-      {
-        self.symbols  [ *refer  ].first =   value.clone ( );
-      }
       self.symbols  [ *refer  ].value   =   value;
-      self.symbols  [ *refer  ].round   =   round;
       *refer
     }
     else
@@ -70,9 +62,7 @@ impl        SymbolList
         SymbolEntry
         {
           title:                        title,
-          first:                        value.clone ( ),
           value:                        value,
-          round:                        round,
         }
       );
       self.hashMap.insert
@@ -88,16 +78,14 @@ impl        SymbolList
     &mut self,
     title:                              SymbolIdentifier,
     value:                              Option<OperandType>,
-    round:                              usize,
   ) ->  Result<SymbolReference, &'static str>
   {
     if let Some ( refer ) = self.hashMap.get  ( title )
     {
-      if self.symbols [ *refer  ].first.is_none ( )
+      //  an expected symbol has no value until it is defined
+      if self.symbols [ *refer  ].value.is_none ( )
       {
-        self.symbols  [ *refer  ].first =   value.clone ( );
         self.symbols  [ *refer  ].value =   value;
-        self.symbols  [ *refer  ].round =   round;
         Ok  ( *refer  )
       }
       else
@@ -113,9 +101,7 @@ impl        SymbolList
         SymbolEntry
         {
           title:                        title,
-          first:                        value.clone ( ),
           value:                        value,
-          round:                        round,
         }
       );
       self.hashMap.insert
@@ -144,9 +130,7 @@ impl        SymbolList
         SymbolEntry
         {
           title:                        title,
-          first:                        None,
           value:                        None,
-          round:                        0,
         }
       );
       self.hashMap.insert
@@ -162,17 +146,11 @@ impl        SymbolList
     &mut self,
     refer:                              SymbolReference,
     value:                              Option<OperandType>,
-    round:                              usize,
   ) -> Option<&'static str>
   {
     if refer  < self.symbols.len  ( )
     {
-      if self.symbols [ refer ].first.is_none ( )
-      {
-        self.symbols  [ refer ].first   =   value.clone ( );
-      }
       self.symbols  [ refer ].value     =   value;
-      self.symbols  [ refer ].round     =   round;
       None
     }
     else
@@ -199,19 +177,12 @@ impl        SymbolList
   (
     &self,
     refer:                              SymbolReference,
-    round:                              usize,
   ) ->  Result<Option<OperandType>, &'static str>
   {
+    //  symbols not yet defined in this round still have the value of the previous round, which is the best guess
     if refer  < self.symbols.len  ( )
     {
-      if  self.symbols  [ refer ].round ==  round
-      {
-        Ok  ( self.symbols  [ refer ].value.clone ( ) )
-      }
-      else
-      {
-        Ok  ( self.symbols  [ refer ].first.clone ( ) )
-      }
+      Ok  ( self.symbols  [ refer ].value.clone ( ) )
     }
     else
     {
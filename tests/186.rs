@@ -0,0 +1,124 @@
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+
+#[macro_use]
+extern crate sucks2;
+use sucks2::
+{
+  assembly::
+  {
+    InstructionSet,
+    x86::
+    {
+      X86,
+      expressions::
+      {
+        Expression,
+        ExpressionToken,
+      },
+      memory::
+      {
+        Memory16Registers,
+      },
+      registers::
+      {
+        SegmentRegisterNumber,
+      },
+    },
+  },
+};
+
+mod common;
+
+#[test]
+fn main () -> Result<(), &'static str>
+{
+  let     myCode
+  = X86 ()
+    .label( "stack instructions"                                                                    )
+    .push ( 0x12                                                                                    ) //  Immediate Sign Extended
+    .push ( 0x1234                                                                                  ) //  Immediate
+    .push ( -2                                                                                      ) //  Immediate Sign Extended
+    .pop  ( X86::ax                                                                                 ) //  Register
+    .label( "stack frame instructions"                                                              )
+    .enter( 0x10,                                   0                                               ) //  Stack Frame
+    .enter( 0x400,                                  2                                               ) //  Nested Stack Frame
+    .leave(                                                                                         ) //  Release Stack Frame
+    .pusha(                                                                                         ) //  All Registers
+    .popa (                                                                                         ) //  All Registers
+    .bound( X86::si,                                x86Mem16! ( dword [ bx ] )                      ) //  Array Bounds
+    .label( "string port instructions"                                                              )
+    .insb (                                                                                         ) //  Byte from Port dx
+    .insw (                                                                                         ) //  Word from Port dx
+    .outsb(                                                                                         ) //  Byte to Port dx
+    .outsw(                                                                                         ) //  Word to Port dx
+    .label( "multiply instructions"                                                                 )
+    .imulImm  ( X86::ax,  X86::bx,                  0x10                                            ) //  Immediate Sign Extended
+    .imulImm  ( X86::cx,  x86Mem16! ( word [ si ] ),  0x1234                                        ) //  Immediate
+    .label( "shift instructions"                                                                    )
+    .shr  ( X86::bx,                                4                                               ) //  Shift by Immediate
+    .ror  ( x86Mem16! ( byte [ si ] ),              3                                               ) //  Shift by Immediate
+    .sal  ( X86::cx,                                1                                               ) //  Shift by One
+    ;
+
+  let myAssembly
+  = myCode.compile
+    (
+      InstructionSet::i186,
+      16,
+      16,
+      10,
+    ).unwrap();
+
+  common::assertAssembly
+  (
+    "186",
+    "i8086",
+    &myAssembly,
+    &[
+      0x6a, 0x12,                 //  push 0x12
+      0x68, 0x34, 0x12,           //  push 0x1234
+      0x6a, 0xfe,                 //  push 0xfffe
+      0x58,                       //  pop ax
+      0xc8, 0x10, 0x00, 0x00,     //  enter 0x10,0x0
+      0xc8, 0x00, 0x04, 0x02,     //  enter 0x400,0x2
+      0xc9,                       //  leave
+      0x60,                       //  pusha
+      0x61,                       //  popa
+      0x62, 0x37,                 //  bound si,DWORD PTR [bx]
+      0x6c,                       //  ins BYTE PTR es:[di],dx
+      0x6d,                       //  ins WORD PTR es:[di],dx
+      0x6e,                       //  outs dx,BYTE PTR ds:[si]
+      0x6f,                       //  outs dx,WORD PTR ds:[si]
+      0x6b, 0xc3, 0x10,           //  imul ax,bx,0x10
+      0x69, 0x0c, 0x34, 0x12,     //  imul cx,WORD PTR [si],0x1234
+      0xc1, 0xeb, 0x04,           //  shr bx,0x4
+      0xc0, 0x0c, 0x03,           //  ror BYTE PTR [si],0x3
+      0xd1, 0xe1,                 //  shl cx,1
+    ],
+  );
+  Ok(())
+}
+
+#[test]
+fn requires186 ()
+{
+  common::assertRejected
+  (
+    &[
+      | code  | code.enter   ( 0x10,     0                                               ),
+      | code  | code.leave   (                                                           ),
+      | code  | code.pusha   (                                                           ),
+      | code  | code.popa    (                                                           ),
+      | code  | code.bound   ( X86::si,  x86Mem16! ( dword [ bx ] )                      ),
+      | code  | code.insb    (                                                           ),
+      | code  | code.insw    (                                                           ),
+      | code  | code.outsb   (                                                           ),
+      | code  | code.outsw   (                                                           ),
+      | code  | code.imulImm ( X86::ax,  X86::bx,                   0x10                 ),
+    ],
+    InstructionSet::i8086,
+    16,
+    16,
+  );
+}
@@ -0,0 +1,111 @@
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+
+#[macro_use]
+extern crate sucks2;
+use sucks2::
+{
+  assembly::
+  {
+    InstructionSet,
+    x86::
+    {
+      X86,
+      expressions::
+      {
+        Expression,
+        ExpressionToken,
+      },
+      memory::
+      {
+        Memory16Registers,
+      },
+      registers::
+      {
+        SegmentRegisterNumber,
+      },
+    },
+  },
+};
+
+mod common;
+
+#[test]
+fn main () -> Result<(), &'static str>
+{
+  let     myCode
+  = X86 ()
+    .label( "descriptor table instructions"                                                         )
+    .lgdt ( x86Mem16! ( 6 [ 0x800 ] )                                                               ) //  Load Global Descriptor Table
+    .lidt ( x86Mem16! ( 6 [ bx ] )                                                                  ) //  Load Interrupt Descriptor Table
+    .sgdt ( x86Mem16! ( 6 [ bp 6 + ] )                                                              ) //  Store Global Descriptor Table
+    .sidt ( x86Mem16! ( 6 [ si ] )                                                                  ) //  Store Interrupt Descriptor Table
+    .label( "selector instructions"                                                                 )
+    .lldt ( X86::ax                                                                                 ) //  Load Local Descriptor Table
+    .sldt ( x86Mem16! ( word [ di ] )                                                               ) //  Store Local Descriptor Table
+    .ltr  ( x86Mem16! ( word [ bx 2 + ] )                                                           ) //  Load Task Register
+    .str  ( X86::dx                                                                                 ) //  Store Task Register
+    .verr ( X86::cx                                                                                 ) //  Verify Segment for Reading
+    .verw ( x86Mem16! ( word [ si ] )                                                               ) //  Verify Segment for Writing
+    .lar  ( X86::ax,                                X86::bx                                         ) //  Load Access Rights
+    .lsl  ( X86::cx,                                x86Mem16! ( word [ bp ] )                       ) //  Load Segment Limit
+    .arpl ( x86Mem16! ( word [ bx ] ),              X86::dx                                         ) //  Adjust Requested Privilege Level
+    .label( "machine status instructions"                                                           )
+    .lmsw ( X86::ax                                                                                 ) //  Load Machine Status Word
+    .smsw ( x86Mem16! ( word [ 0x400 ] )                                                            ) //  Store Machine Status Word
+    .clts (                                                                                         ) //  Clear Task Switched Flag
+    ;
+
+  let myAssembly
+  = myCode.compile
+    (
+      InstructionSet::i286,
+      16,
+      16,
+      10,
+    ).unwrap();
+
+  common::assertAssembly
+  (
+    "286",
+    "i8086",
+    &myAssembly,
+    &[
+      0x0f, 0x01, 0x16, 0x00, 0x08,   //  lgdtw ds:0x800
+      0x0f, 0x01, 0x1f,               //  lidtw [bx]
+      0x0f, 0x01, 0x46, 0x06,         //  sgdtw [bp+0x6]
+      0x0f, 0x01, 0x0c,               //  sidtw [si]
+      0x0f, 0x00, 0xd0,               //  lldt ax
+      0x0f, 0x00, 0x05,               //  sldt WORD PTR [di]
+      0x0f, 0x00, 0x5f, 0x02,         //  ltr WORD PTR [bx+0x2]
+      0x0f, 0x00, 0xca,               //  str dx
+      0x0f, 0x00, 0xe1,               //  verr cx
+      0x0f, 0x00, 0x2c,               //  verw WORD PTR [si]
+      0x0f, 0x02, 0xc3,               //  lar ax,bx
+      0x0f, 0x03, 0x4e, 0x00,         //  lsl cx,WORD PTR [bp+0x0]
+      0x63, 0x17,                     //  arpl WORD PTR [bx],dx
+      0x0f, 0x01, 0xf0,               //  lmsw ax
+      0x0f, 0x01, 0x26, 0x00, 0x04,   //  smsw WORD PTR ds:0x400
+      0x0f, 0x06,                     //  clts
+    ],
+  );
+  Ok(())
+}
+
+#[test]
+fn requires286 ()
+{
+  common::assertRejected
+  (
+    &[
+      | code  | code.lgdt    ( x86Mem16! ( 6 [ 0x800 ] )                                 ),
+      | code  | code.lldt    ( X86::ax                                                   ),
+      | code  | code.lar     ( X86::ax,  X86::bx                                         ),
+      | code  | code.arpl    ( X86::ax,  X86::dx                                         ),
+      | code  | code.clts    (                                                           ),
+    ],
+    InstructionSet::i186,
+    16,
+    16,
+  );
+}
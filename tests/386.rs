@@ -0,0 +1,294 @@
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+
+#[macro_use]
+extern crate sucks2;
+use sucks2::
+{
+  assembly::
+  {
+    InstructionSet,
+    x86::
+    {
+      X86,
+      expressions::
+      {
+        Expression,
+        ExpressionToken,
+      },
+      memory::
+      {
+        Memory16Registers,
+        Memory32NoRegister,
+      },
+      registers::
+      {
+        SegmentRegisterNumber,
+      },
+      symbols::
+      {
+        Symbol,
+      },
+    },
+  },
+};
+
+mod common;
+
+#[test]
+fn main () -> Result<(), &'static str>
+{
+  let     myCode
+  = X86 ()
+    .label( "jump instructions"                                                                     )
+    .jl   ( Symbol  ( "end"                             )                                           ) //  Near Conditional Jump forward
+    .loopz( Symbol  ( "end"                             )                                           ) //  Loop, expanded
+    .jmp  ( Symbol  ( "jump instructions"               )                                           ) //  Short Jump backward
+    .label( "padding"                                                                               )
+    .mov  ( x86Mem16! ( word [ bx si 0x1000 + + ] ),  0x1234                                        )
+    .mov  ( x86Mem16! ( word [ bx si 0x1000 + + ] ),  0x1234                                        )
+    .mov  ( x86Mem16! ( word [ bx si 0x1000 + + ] ),  0x1234                                        )
+    .mov  ( x86Mem16! ( word [ bx si 0x1000 + + ] ),  0x1234                                        )
+    .mov  ( x86Mem16! ( word [ bx si 0x1000 + + ] ),  0x1234                                        )
+    .mov  ( x86Mem16! ( word [ bx si 0x1000 + + ] ),  0x1234                                        )
+    .mov  ( x86Mem16! ( word [ bx si 0x1000 + + ] ),  0x1234                                        )
+    .mov  ( x86Mem16! ( word [ bx si 0x1000 + + ] ),  0x1234                                        )
+    .mov  ( x86Mem16! ( word [ bx si 0x1000 + + ] ),  0x1234                                        )
+    .mov  ( x86Mem16! ( word [ bx si 0x1000 + + ] ),  0x1234                                        )
+    .mov  ( x86Mem16! ( word [ bx si 0x1000 + + ] ),  0x1234                                        )
+    .mov  ( x86Mem16! ( word [ bx si 0x1000 + + ] ),  0x1234                                        )
+    .mov  ( x86Mem16! ( word [ bx si 0x1000 + + ] ),  0x1234                                        )
+    .mov  ( x86Mem16! ( word [ bx si 0x1000 + + ] ),  0x1234                                        )
+    .mov  ( x86Mem16! ( word [ bx si 0x1000 + + ] ),  0x1234                                        )
+    .mov  ( x86Mem16! ( word [ bx si 0x1000 + + ] ),  0x1234                                        )
+    .mov  ( x86Mem16! ( word [ bx si 0x1000 + + ] ),  0x1234                                        )
+    .mov  ( x86Mem16! ( word [ bx si 0x1000 + + ] ),  0x1234                                        )
+    .mov  ( x86Mem16! ( word [ bx si 0x1000 + + ] ),  0x1234                                        )
+    .mov  ( x86Mem16! ( word [ bx si 0x1000 + + ] ),  0x1234                                        )
+    .mov  ( x86Mem16! ( word [ bx si 0x1000 + + ] ),  0x1234                                        )
+    .mov  ( x86Mem16! ( word [ bx si 0x1000 + + ] ),  0x1234                                        )
+    .label( "32 bit addressing"                                                                     )
+    .mov  ( x86Mem32! ( dword [ eax ecx 4 * + 0x10 + ] ),  X86::edx                                  ) //  SIB with disp8 and 0x67
+    .mov  ( X86::ax,  x86Mem32! ( word [ esp ] )                                                    ) //  SIB for esp as base
+    .add  ( x86Mem32! ( word [ ebp ] ),  X86::cx                                                    ) //  ebp needs disp8
+    .inc  ( x86Mem32! ( byte fs: [ ebx 2 * 0x12345678 + ] )                                         ) //  Index without base needs disp32
+    .lea  ( X86::si,  x86Mem32! ( word [ eax 3 * ] )                                                ) //  eax + eax * 2
+    .sub  ( x86Mem32! ( word [ 0x1234 ] ),  0x56                                                    ) //  Direct address
+    .label( "i386 instructions"                                                                     )
+    .movzx( X86::ax,  X86::bl                                                                       )
+    .movzx( X86::eax, x86Mem32! ( word [ esi ] )                                                    )
+    .movsx( X86::edx, x86Mem16! ( byte [ bx di + ] )                                                )
+    .sete ( X86::al                                                                                 )
+    .setnle( x86Mem16! ( byte [ bp 2 + ] )                                                          )
+    .bt   ( X86::ax,  X86::cx                                                                       )
+    .bts  ( x86Mem32! ( dword [ eax ] ),  X86::edx                                                  )
+    .btr  ( X86::eax, 7                                                                             )
+    .btc  ( x86Mem16! ( word [ si ] ),  31                                                          )
+    .bsf  ( X86::cx,  X86::dx                                                                       )
+    .bsr  ( X86::ecx, x86Mem32! ( dword [ ebx ] )                                                   )
+    .shld ( X86::ax,  X86::bx,  4                                                                   )
+    .shrd ( x86Mem32! ( dword [ ecx ] ),  X86::eax,  X86::cl                                        )
+    .add  ( X86::eax, 0x12345678                                                                    ) //  Accumulator with 32 bit immediate
+    .sub  ( X86::ax,  0x1234                                                                        )
+    .add  ( X86::ebx, X86::ecx                                                                      )
+    .label( "system registers"                                                                      )
+    .mov  ( X86::eax, X86::cr0                                                                      )
+    .mov  ( X86::cr3, X86::ebx                                                                      )
+    .mov  ( X86::dr7, X86::ecx                                                                      )
+    .mov  ( X86::edx, X86::dr6                                                                      )
+    .mov  ( X86::tr6, X86::esi                                                                      )
+    .mov  ( X86::edi, X86::tr7                                                                      )
+    .label( "floating point instructions"                                                           )
+    .fld  ( x86Mem16! ( qword [ bx ] )                                                              ) //  No Wait Prefix on i386
+    .fstp ( X86::st1                                                                                ) //  No Wait Prefix on i386
+    .label( "end"                                                                                   )
+    .retn (                                                                                         )
+    ;
+
+  let myAssembly
+  = myCode.compile
+    (
+      InstructionSet::i386,
+      16,
+      16,
+      10,
+    ).unwrap();
+
+  common::assertAssembly
+  (
+    "386",
+    "i386",
+    &myAssembly,
+    &[
+      0x0f, 0x8c, 0x07, 0x01, 0xe1, 0x02,                             //  jl 0x2e1010d
+      0xeb, 0x03,                                                     //  jmp 0xb
+      0xe9, 0x00, 0x01, 0xeb, 0xf3,                                   //  jmp 0xf3eb010d
+      0xc7, 0x80, 0x00, 0x10, 0x34, 0x12, 0xc7, 0x80, 0x00, 0x10,     //  mov DWORD PTR [eax+0x12341000],0x100080c7
+      0x34, 0x12,                                                     //  xor al,0x12
+      0xc7, 0x80, 0x00, 0x10, 0x34, 0x12, 0xc7, 0x80, 0x00, 0x10,     //  mov DWORD PTR [eax+0x12341000],0x100080c7
+      0x34, 0x12,                                                     //  xor al,0x12
+      0xc7, 0x80, 0x00, 0x10, 0x34, 0x12, 0xc7, 0x80, 0x00, 0x10,     //  mov DWORD PTR [eax+0x12341000],0x100080c7
+      0x34, 0x12,                                                     //  xor al,0x12
+      0xc7, 0x80, 0x00, 0x10, 0x34, 0x12, 0xc7, 0x80, 0x00, 0x10,     //  mov DWORD PTR [eax+0x12341000],0x100080c7
+      0x34, 0x12,                                                     //  xor al,0x12
+      0xc7, 0x80, 0x00, 0x10, 0x34, 0x12, 0xc7, 0x80, 0x00, 0x10,     //  mov DWORD PTR [eax+0x12341000],0x100080c7
+      0x34, 0x12,                                                     //  xor al,0x12
+      0xc7, 0x80, 0x00, 0x10, 0x34, 0x12, 0xc7, 0x80, 0x00, 0x10,     //  mov DWORD PTR [eax+0x12341000],0x100080c7
+      0x34, 0x12,                                                     //  xor al,0x12
+      0xc7, 0x80, 0x00, 0x10, 0x34, 0x12, 0xc7, 0x80, 0x00, 0x10,     //  mov DWORD PTR [eax+0x12341000],0x100080c7
+      0x34, 0x12,                                                     //  xor al,0x12
+      0xc7, 0x80, 0x00, 0x10, 0x34, 0x12, 0xc7, 0x80, 0x00, 0x10,     //  mov DWORD PTR [eax+0x12341000],0x100080c7
+      0x34, 0x12,                                                     //  xor al,0x12
+      0xc7, 0x80, 0x00, 0x10, 0x34, 0x12, 0xc7, 0x80, 0x00, 0x10,     //  mov DWORD PTR [eax+0x12341000],0x100080c7
+      0x34, 0x12,                                                     //  xor al,0x12
+      0xc7, 0x80, 0x00, 0x10, 0x34, 0x12, 0xc7, 0x80, 0x00, 0x10,     //  mov DWORD PTR [eax+0x12341000],0x100080c7
+      0x34, 0x12,                                                     //  xor al,0x12
+      0xc7, 0x80, 0x00, 0x10, 0x34, 0x12, 0xc7, 0x80, 0x00, 0x10,     //  mov DWORD PTR [eax+0x12341000],0x100080c7
+      0x34, 0x12,                                                     //  xor al,0x12
+      0x66, 0x67, 0x89, 0x54, 0x88,                                   //  mov WORD PTR [si-0x78],dx
+      0x10, 0x67, 0x8b,                                               //  adc BYTE PTR [edi-0x75],ah
+      0x04, 0x24,                                                     //  add al,0x24
+      0x67, 0x01, 0x4d, 0x00,                                         //  add DWORD PTR [di+0x0],ecx
+      0x64, 0x67, 0xfe, 0x04,                                         //  inc BYTE PTR fs:[si]
+      0x5d,                                                           //  pop ebp
+      0x78, 0x56,                                                     //  js 0xfc
+      0x34, 0x12,                                                     //  xor al,0x12
+      0x67, 0x8d, 0x34,                                               //  lea esi,[si]
+      0x40,                                                           //  inc eax
+      0x67, 0x83, 0x2d, 0x34,                                         //  sub DWORD PTR [di],0x34
+      0x12, 0x00,                                                     //  adc al,BYTE PTR [eax]
+      0x00, 0x56, 0x0f,                                               //  add BYTE PTR [esi+0xf],dl
+      0xb6, 0xc3,                                                     //  mov dh,0xc3
+      0x66, 0x67, 0x0f, 0xb7, 0x06, 0x66, 0x0f,                       //  movzx ax,WORD PTR ds:0xf66
+      0xbe, 0x11, 0x0f, 0x94, 0xc0,                                   //  mov esi,0xc0940f11
+      0x0f, 0x9f, 0x46, 0x02,                                         //  setg BYTE PTR [esi+0x2]
+      0x0f, 0xa3, 0xc8,                                               //  bt eax,ecx
+      0x66, 0x67, 0x0f, 0xab, 0x10,                                   //  bts WORD PTR [bx+si],dx
+      0x66, 0x0f, 0xba, 0xf0, 0x07,                                   //  btr ax,0x7
+      0x0f, 0xba, 0x3c, 0x1f, 0x0f,                                   //  btc DWORD PTR [edi+ebx*1],0xf
+      0xbc, 0xca, 0x66, 0x67, 0x0f,                                   //  mov esp,0xf6766ca
+      0xbd, 0x0b, 0x0f, 0xa4, 0xd8,                                   //  mov ebp,0xd8a40f0b
+      0x04, 0x66,                                                     //  add al,0x66
+      0x67, 0x0f, 0xad, 0x01,                                         //  shrd DWORD PTR [bx+di],eax,cl
+      0x66, 0x05, 0x78, 0x56,                                         //  add ax,0x5678
+      0x34, 0x12,                                                     //  xor al,0x12
+      0x2d, 0x34, 0x12, 0x66, 0x01,                                   //  sub eax,0x1661234
+      0xcb,                                                           //  retf
+      0x0f, 0x20, 0xc0,                                               //  mov eax,cr0
+      0x0f, 0x22, 0xdb,                                               //  mov cr3,ebx
+      0x0f, 0x23, 0xf9,                                               //  mov dr7,ecx
+      0x0f, 0x21, 0xf2,                                               //  mov edx,dr6
+      0x0f, 0x26, 0xf6,                                               //  mov tr6,esi
+      0x0f, 0x24, 0xff,                                               //  mov edi,tr7
+      0xdd, 0x07,                                                     //  fld QWORD PTR [edi]
+      0xdd, 0xd9,                                                     //  fstp st(1)
+      0xc3,                                                           //  ret
+    ],
+  );
+  Ok(())
+}
+
+#[test]
+fn requires386 ()
+{
+  common::assertRejected
+  (
+    &[
+      | code  | code.mov     ( X86::ax,  x86Mem32! ( word [ eax ] )                     ),
+      | code  | code.inc     ( x86Mem32! ( byte [ 0x1234 ] )                             ),
+      | code  | code.movzx   ( X86::ax,  X86::bl                                         ),
+      | code  | code.sete    ( X86::al                                                   ),
+      | code  | code.bt      ( X86::ax,  X86::cx                                         ),
+      | code  | code.bsf     ( X86::ax,  X86::cx                                         ),
+      | code  | code.shld    ( X86::ax,  X86::bx,  4                                     ),
+      | code  | code.mov     ( X86::cr0, X86::eax                                        ),
+    ],
+    InstructionSet::i286,
+    16,
+    16,
+  );
+}
+
+#[test]
+fn missingOperator ()
+{
+  common::assertRejected
+  (
+    &[
+      | code  | code.movsx   ( X86::edx, x86Mem16! ( byte [ bx di ] )                    ),
+      | code  | code.mov     ( X86::eax, x86Mem32! ( dword [ eax ecx 4 * ] )             ),
+      | code  | code.add     ( X86::ax,  x86Mem16! ( word [ si 2 4 + ] )                 ),
+    ],
+    InstructionSet::i386,
+    16,
+    16,
+  );
+}
+
+#[test]
+fn systemRegisters ()
+{
+  common::assertRejected
+  (
+    &[
+      | code  | code.mov     ( X86::eax, X86::cr4                                        ),
+    ],
+    InstructionSet::i486,
+    16,
+    16,
+  );
+  common::assertRejected
+  (
+    &[
+      | code  | code.mov     ( X86::cr1, X86::eax                                        ),
+      | code  | code.mov     ( X86::eax, X86::dr5                                        ),
+      | code  | code.mov     ( X86::tr3, X86::eax                                        ),
+      | code  | code.mov     ( X86::ax,  X86::cr0                                        ),
+    ],
+    InstructionSet::i386,
+    16,
+    16,
+  );
+  common::assertRejected
+  (
+    &[
+      | code  | code.mov     ( X86::tr6, X86::eax                                        ),
+    ],
+    InstructionSet::Pentium,
+    16,
+    16,
+  );
+}
+
+#[test]
+fn shiftCount ()
+{
+  common::assertRejected
+  (
+    &[
+      | code  | code.shl     ( X86::ax,  X86::cx                                         ),
+      | code  | code.shl     ( X86::ax,  X86::ecx                                        ),
+      | code  | code.rcr     ( x86Mem32! ( dword [ eax ] ),  X86::cx                     ),
+      | code  | code.shld    ( X86::ax,  X86::bx,  X86::cx                               ),
+      | code  | code.shrd    ( X86::eax, X86::ebx, X86::ecx                              ),
+    ],
+    InstructionSet::i386,
+    32,
+    32,
+  );
+}
+
+#[test]
+fn portRegister ()
+{
+  common::assertRejected
+  (
+    &[
+      | code  | code.inn     ( X86::al,  X86::edx                                        ),
+      | code  | code.out     ( X86::edx, X86::eax                                        ),
+    ],
+    InstructionSet::i386,
+    32,
+    32,
+  );
+}
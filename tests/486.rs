@@ -0,0 +1,146 @@
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+
+#[macro_use]
+extern crate sucks2;
+use sucks2::
+{
+  assembly::
+  {
+    InstructionSet,
+    x86::
+    {
+      X86,
+      expressions::
+      {
+        Expression,
+        ExpressionToken,
+      },
+      memory::
+      {
+        Memory16Registers,
+        Memory32NoRegister,
+      },
+      registers::
+      {
+        SegmentRegisterNumber,
+      },
+    },
+  },
+};
+
+mod common;
+
+#[test]
+fn main () -> Result<(), &'static str>
+{
+  let     myCode
+  = X86 ()
+    .label( "i486 instructions"                                                                     )
+    .bswap( X86::eax                                                                                ) //  Byte Swap
+    .bswap( X86::edi                                                                                )
+    .xadd ( X86::ax,                                X86::bx                                         ) //  Exchange and Add
+    .cmpxchg( x86Mem32! ( byte [ esi ] ),           X86::cl                                         ) //  Compare and Exchange
+    .lock (                                                                                         )
+    .xadd ( x86Mem16! ( word [ bx ] ),              X86::ax                                         ) //  Atomic Exchange and Add
+    .lock (                                                                                         )
+    .cmpxchg( x86Mem32! ( dword [ edx ] ),          X86::ecx                                        ) //  Atomic Compare and Exchange
+    .invlpg( x86Mem16! ( byte [ si ] )                                                              ) //  Invalidate TLB Entry
+    .cpuid(                                                                                         ) //  CPU Identification
+    ;
+
+  let myAssembly
+  = myCode.compile
+    (
+      InstructionSet::i486,
+      16,
+      16,
+      10,
+    ).unwrap();
+
+  common::assertAssembly
+  (
+    "486",
+    "i386",
+    &myAssembly,
+    &[
+      0x66, 0x0f, 0xc8,                       //  bswap ax
+      0x66, 0x0f, 0xcf,                       //  bswap di
+      0x0f, 0xc1, 0xd8,                       //  xadd eax,ebx
+      0x67, 0x0f, 0xb0, 0x0e, 0xf0, 0x0f,     //  cmpxchg BYTE PTR ds:0xff0,cl
+      0xc1, 0x07, 0xf0,                       //  rol DWORD PTR [edi],0xf0
+      0x66, 0x67, 0x0f, 0xb1, 0x0a,           //  cmpxchg WORD PTR [bp+si],cx
+      0x0f, 0x01, 0x3c, 0x0f,                 //  invlpg BYTE PTR [edi+ecx*1]
+      0xa2,                                   //  .byte 0xa2
+    ],
+  );
+  Ok(())
+}
+
+#[test]
+fn pentium () -> Result<(), &'static str>
+{
+  let     myCode
+  = X86 ()
+    .label( "pentium instructions"                                                                  )
+    .cmpxchg8b( x86Mem16! ( qword [ bx si + ] )                                                     ) //  Compare and Exchange 8 Bytes
+    .lock (                                                                                         )
+    .cmpxchg8b( x86Mem32! ( qword [ eax ] )                                                         ) //  Atomic Compare and Exchange 8 Bytes
+    .rdtsc(                                                                                         ) //  Read Time Stamp Counter
+    .rdmsr(                                                                                         ) //  Read Model Specific Register
+    .wrmsr(                                                                                         ) //  Write Model Specific Register
+    ;
+
+  let myAssembly
+  = myCode.compile
+    (
+      InstructionSet::Pentium,
+      16,
+      16,
+      10,
+    ).unwrap();
+
+  common::assertAssembly
+  (
+    "586",
+    "i386",
+    &myAssembly,
+    &[
+      0x0f, 0xc7, 0x08,               //  cmpxchg8b QWORD PTR [eax]
+      0xf0, 0x67, 0x0f, 0xc7, 0x08,   //  lock cmpxchg8b QWORD PTR [bx+si]
+      0x0f, 0x31,                     //  rdtsc
+      0x0f, 0x32,                     //  rdmsr
+      0x0f, 0x30,                     //  wrmsr
+    ],
+  );
+  Ok(())
+}
+
+#[test]
+fn requires486 ()
+{
+  common::assertRejected
+  (
+    &[
+      | code  | code.bswap     ( X86::eax                                                ),
+      | code  | code.xadd      ( X86::ax,  X86::bx                                       ),
+      | code  | code.cpuid     (                                                         ),
+      | code  | code.invlpg    ( x86Mem16! ( byte [ si ] )                               ),
+    ],
+    InstructionSet::i386,
+    16,
+    16,
+  );
+  common::assertRejected
+  (
+    &[
+      | code  | code.cmpxchg8b ( x86Mem16! ( qword [ si ] )                              ),
+      | code  | code.rdtsc     (                                                         ),
+      | code  | code.bswap     ( X86::ax                                                 ),
+      | code  | code.lock      ( ).xadd ( X86::ax,  X86::bx                              ),
+    ],
+    InstructionSet::i486,
+    16,
+    16,
+  );
+}
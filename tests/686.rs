@@ -0,0 +1,165 @@
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+
+#[macro_use]
+extern crate sucks2;
+use sucks2::
+{
+  assembly::
+  {
+    InstructionSet,
+    x86::
+    {
+      X86,
+      expressions::
+      {
+        Expression,
+        ExpressionToken,
+      },
+      memory::
+      {
+        Memory16Registers,
+        Memory32NoRegister,
+      },
+      registers::
+      {
+        SegmentRegisterNumber,
+      },
+    },
+  },
+};
+
+mod common;
+
+#[test]
+fn main () -> Result<(), &'static str>
+{
+  let     myCode
+  = X86 ()
+    .label( "conditional moves"                                                                     )
+    .cmove( X86::ax,                                X86::bx                                         ) //  Move if Equal
+    .cmovnbe( X86::ecx,                             x86Mem32! ( dword [ esi ] )                     ) //  Move if Above
+    .cmovl( X86::dx,                                x86Mem16! ( word [ bp 4 + ] )                   ) //  Move if Less
+    .fcmovb( X86::st0,                              X86::st1                                        ) //  Floating Point Move if Below
+    .fcmovnu( X86::st0,                             X86::st7                                        ) //  Floating Point Move if not Unordered
+    .label( "floating point compare to flags"                                                       )
+    .fcomi( X86::st0,                               X86::st2                                        ) //  Compare and Set Flags
+    .fucomip( X86::st0,                             X86::st3                                        ) //  Unordered Compare, Set Flags and Pop
+    .label( "system instructions"                                                                   )
+    .sysenter(                                                                                      ) //  Fast System Call
+    .sysexit(                                                                                       ) //  Fast Return from System Call
+    .rdpmc(                                                                                         ) //  Read Performance Monitoring Counter
+    .ud2  (                                                                                         ) //  Undefined Instruction
+    .label( "mmx instructions"                                                                      )
+    .movd ( X86::mm0,                               X86::eax                                        ) //  Move Double Word
+    .movd ( x86Mem16! ( dword [ bx ] ),             X86::mm1                                        )
+    .movq ( X86::mm2,                               x86Mem32! ( qword [ esi ] )                     ) //  Move Quad Word
+    .movq ( x86Mem16! ( qword [ di 8 + ] ),         X86::mm3                                        )
+    .movq ( X86::mm4,                               X86::mm5                                        )
+    .paddb( X86::mm0,                               X86::mm1                                        ) //  Packed Add
+    .paddusw( X86::mm2,                             x86Mem16! ( qword [ si ] )                      ) //  Packed Add Unsigned with Saturation
+    .psubd( X86::mm3,                               X86::mm4                                        ) //  Packed Subtract
+    .pand ( X86::mm5,                               X86::mm6                                        ) //  Bitwise And
+    .pxor ( X86::mm7,                               X86::mm7                                        ) //  Bitwise Exclusive Or
+    .pcmpgtw( X86::mm0,                             X86::mm2                                        ) //  Packed Compare Greater Than
+    .packuswb( X86::mm1,                            X86::mm3                                        ) //  Pack with Unsigned Saturation
+    .punpcklbw( X86::mm4,                           X86::mm5                                        ) //  Unpack Low
+    .pmaddwd( X86::mm6,                             X86::mm7                                        ) //  Packed Multiply and Add
+    .psllq( X86::mm0,                               X86::mm1                                        ) //  Shift by Register
+    .psraw( X86::mm2,                               3                                               ) //  Shift by Immediate
+    .psrld( X86::mm3,                               x86Mem16! ( qword [ bx ] )                      ) //  Shift by Memory
+    .emms (                                                                                         ) //  Empty MMX State
+    ;
+
+  let myAssembly
+  = myCode.compile
+    (
+      InstructionSet::Pentium2,
+      16,
+      16,
+      10,
+    ).unwrap();
+
+  common::assertAssembly
+  (
+    "686",
+    "i386",
+    &myAssembly,
+    &[
+      0x0f, 0x44, 0xc3,                           //  cmove eax,ebx
+      0x66, 0x67, 0x0f, 0x47, 0x0e, 0x0f, 0x4c,   //  cmova cx,WORD PTR ds:0x4c0f
+      0x56,                                       //  push esi
+      0x04, 0xda,                                 //  add al,0xda
+      0xc1, 0xdb, 0xdf,                           //  rcr ebx,0xdf
+      0xdb, 0xf2,                                 //  fcomi st,st(2)
+      0xdf, 0xeb,                                 //  fucomip st,st(3)
+      0x0f, 0x34,                                 //  sysenter
+      0x0f, 0x35,                                 //  sysexit
+      0x0f, 0x33,                                 //  rdpmc
+      0x0f, 0x0b,                                 //  ud2
+      0x0f, 0x6e, 0xc0,                           //  movd mm0,eax
+      0x0f, 0x7e, 0x0f,                           //  movd DWORD PTR [edi],mm1
+      0x67, 0x0f, 0x6f, 0x16, 0x0f, 0x7f,         //  movq mm2,QWORD PTR ds:0x7f0f
+      0x5d,                                       //  pop ebp
+      0x08, 0x0f,                                 //  or BYTE PTR [edi],cl
+      0x6f,                                       //  outs dx,DWORD PTR ds:[esi]
+      0xe5, 0x0f,                                 //  in eax,0xf
+      0xfc,                                       //  cld
+      0xc1, 0x0f, 0xdd,                           //  ror DWORD PTR [edi],0xdd
+      0x14, 0x0f,                                 //  adc al,0xf
+      0xfa,                                       //  cli
+      0xdc, 0x0f,                                 //  fmul QWORD PTR [edi]
+      0xdb, 0xee,                                 //  fucomi st,st(6)
+      0x0f, 0xef, 0xff,                           //  pxor mm7,mm7
+      0x0f, 0x65, 0xc2,                           //  pcmpgtw mm0,mm2
+      0x0f, 0x67, 0xcb,                           //  packuswb mm1,mm3
+      0x0f, 0x60, 0xe5,                           //  punpcklbw mm4,mm5
+      0x0f, 0xf5, 0xf7,                           //  pmaddwd mm6,mm7
+      0x0f, 0xf3, 0xc1,                           //  psllq mm0,mm1
+      0x0f, 0x71, 0xe2, 0x03,                     //  psraw mm2,0x3
+      0x0f, 0xd2, 0x1f,                           //  psrld mm3,QWORD PTR [edi]
+      0x0f, 0x77,                                 //  emms
+    ],
+  );
+  Ok(())
+}
+
+#[test]
+fn requires686 ()
+{
+  common::assertRejected
+  (
+    &[
+      | code  | code.cmove    ( X86::ax,  X86::bx                                         ),
+      | code  | code.fcmovb   ( X86::st0, X86::st1                                        ),
+      | code  | code.fcomi    ( X86::st0, X86::st1                                        ),
+      | code  | code.sysenter (                                                          ),
+      | code  | code.rdpmc    (                                                          ),
+      | code  | code.ud2      (                                                          ),
+      | code  | code.paddb    ( X86::mm0, X86::mm1                                        ),
+      | code  | code.psllw    ( X86::mm0, 4                                               ),
+      | code  | code.movd     ( X86::mm0, X86::eax                                        ),
+      | code  | code.emms     (                                                          ),
+    ],
+    InstructionSet::Pentium,
+    16,
+    16,
+  );
+}
+
+#[test]
+fn multiMediaOperandSizes ()
+{
+  common::assertRejected
+  (
+    &[
+      | code  | code.paddb    ( X86::mm0, X86::xmm1                                       ),
+      | code  | code.movd     ( X86::mm0, X86::ax                                         ),
+      | code  | code.movq     ( X86::mm0, x86Mem16! ( dword [ bx ] )                      ),
+      | code  | code.psllw    ( X86::mm0, 0x100                                           ),
+    ],
+    InstructionSet::Pentium2,
+    16,
+    16,
+  );
+}
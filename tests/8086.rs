@@ -7,6 +7,7 @@ use sucks2::
 {
   assembly::
   {
+    AssemblyFeatures,
     InstructionSet,
     x86::
     {
@@ -18,6 +19,7 @@ use sucks2::
       },
       memory::
       {
+        Intersegment,
         Memory16Registers,
       },
       registers::
@@ -32,108 +34,16 @@ use sucks2::
   },
 };
 
-use std::
-{
-  fs::
-  {
-    File,
-  },
-  io::
-  {
-    Write,
-  },
-  process::
-  {
-    Command,
-  },
-};
-
-fn _hexDump
-(
-  buffer:                               Box<[u8]>,
-  width:                                usize,
-  offset:                               usize,
-  mut length:                           usize,
-) -> Result<usize, &'static str>
-{
-  let   size                            =   buffer.len();
-  if offset < size
-  {
-    if length == 0
-    {
-      length                            =   size - offset;
-    }
-    if length <= ( size - offset )
-    {
-      let   lines                       =   length / width;
-      for line                          in  0 .. lines
-      {
-        for pos                         in  0 .. width
-        {
-          print!  ( "{:02x} ", buffer [ offset + width * line + pos ] );
-        }
-        print!  ( "| " );
-        for pos                         in  0 .. width
-        {
-          let char                      =   buffer [ offset + width * line + pos ];
-          if  ( char >= 0x20 && char <= 0x7e )
-          ||  ( char >= 0xa0 )
-          {
-            print!  ( "{}", char as char );
-          }
-          else
-          {
-            print!  ( "." );
-          }
-        }
-        println!  ( "" );
-      }
-      let   remainder                   =   length % width;
-      if remainder > 0
-      {
-        for pos                         in  0 .. remainder
-        {
-          print!  ( "{:02x} ", buffer [ offset + width * lines + pos ] );
-        }
-        for _                           in  remainder .. width
-        {
-          print!  ( "   " );
-        }
-        print!  ( "| " );
-        for pos                         in  0 .. remainder
-        {
-          let char                      =   buffer [ offset + width * lines + pos ];
-          if  ( char >= 0x20 && char <= 0x7e )
-          ||  ( char >= 0xa0 )
-          {
-            print!  ( "{}", char as char );
-          }
-          else
-          {
-            print!  ( "." );
-          }
-        }
-        println!  ( "" );
-      }
-      Ok ( length )
-    }
-    else
-    {
-      Err ( "Length Out Of Bonds" )
-    }
-  }
-  else
-  {
-    Err ( "Offset Out Of Bonds" )
-  }
-}
-
+mod common;
 
 #[test]
 fn main () -> Result<(), &'static str>
 {
   let     myCode
   = X86 ()
+    .jmp  ( Symbol  ( "one byte instructions"           )                                           ) //  Near Jump forward
+    .jne  ( Symbol  ( "one byte instructions"           )                                           ) //  Conditional Jump forward, expanded
+    .jcxz ( Symbol  ( "one byte instructions"           )                                           ) //  Conditional Jump forward, expanded
     .label( "simple math instructions 8 bit"                                                        )
     .add  ( X86::cl,                                X86::dl                                         ) //  Register  to  Register
     .add  ( x86Mem16! ( byte [ bp si 0x80 - + ] ),  X86::dl                                         ) //  Register  to  Memory
@@ -150,9 +60,124 @@ fn main () -> Result<(), &'static str>
     .add  ( x86Mem16! ( word [ bp si 0x80 - + ] ),  0x42                                            ) //  Immediate to  Memory    Sign Extended
     .add  ( x86Mem16! ( word [ bp si 0x80 - + ] ),  0x1337                                          ) //  Immediate to  Memory
     .add  ( X86::ax,                                0x1337                                          ) //  Immediate to  Accumulator
+    .label( "move instructions"                                                                     )
+    .mov  ( X86::cl,                                X86::dl                                         ) //  Register  to  Register
+    .mov  ( x86Mem16! ( word [ bx di 4 + + ] ),     X86::si                                         ) //  Register  to  Memory
+    .mov  ( X86::sp,                                x86Mem16! ( word [ bp ] )                       ) //  Memory    to  Register
+    .mov  ( X86::bh,                                0x12                                            ) //  Immediate to  Register
+    .mov  ( X86::di,                                0x1234                                          ) //  Immediate to  Register
+    .mov  ( x86Mem16! ( byte es : [ si ] ),         0x42                                            ) //  Immediate to  Memory
+    .mov  ( x86Mem16! ( word [ bp 0x100 + ] ),      0x1337                                          ) //  Immediate to  Memory
+    .mov  ( X86::al,                                x86Mem16! ( byte [ 0x1234 ] )                   ) //  Memory    to  Accumulator
+    .mov  ( x86Mem16! ( word cs : [ 0x5678 ] ),     X86::ax                                         ) //  Accumulator to Memory
+    .mov  ( x86Mem16! ( word [ 0x5678 ] ),          X86::cx                                         ) //  Register  to  Direct Address
+    .mov  ( X86::ds,                                X86::ax                                         ) //  Register  to  Segment Register
+    .mov  ( X86::es,                                x86Mem16! ( word [ bx ] )                       ) //  Memory    to  Segment Register
+    .mov  ( X86::dx,                                X86::ss                                         ) //  Segment Register to Register
+    .mov  ( x86Mem16! ( word [ di 2 - ] ),          X86::cs                                         ) //  Segment Register to Memory
+    .label( "test and exchange instructions"                                                        )
+    .test ( X86::al,                                0x80                                            ) //  Immediate and Accumulator
+    .test ( X86::bx,                                0x8000                                          ) //  Immediate and Register
+    .test ( x86Mem16! ( byte [ si ] ),              1                                               ) //  Immediate and Memory
+    .test ( X86::cx,                                X86::dx                                         ) //  Register  and Register
+    .test ( X86::dl,                                x86Mem16! ( byte [ bx ] )                       ) //  Register  and Memory
+    .xchg ( X86::ax,                                X86::bx                                         ) //  Register  with Accumulator
+    .xchg ( X86::si,                                X86::ax                                         ) //  Accumulator with Register
+    .xchg ( X86::cl,                                X86::dl                                         ) //  Register  with Register
+    .xchg ( x86Mem16! ( word [ bp 2 + ] ),          X86::cx                                         ) //  Register  with Memory
+    .xchg ( X86::ax,                                X86::ax                                         ) //  No Operation
+    .nop  (                                                                                         ) //  No Operation
+    .label( "load address instructions"                                                             )
+    .lea  ( X86::si,                                x86Mem16! ( word [ bx di 0x10 + + ] )           ) //  Address Arithmetic
+    .lds  ( X86::si,                                x86Mem16! ( dword [ bp 4 + ] )                  ) //  Far Pointer into ds
+    .les  ( X86::di,                                x86Mem16! ( dword es : [ 0x400 ] )              ) //  Far Pointer into es
+    .label( "stack instructions"                                                                    )
+    .push ( X86::ax                                                                                 ) //  Register
+    .push ( X86::es                                                                                 ) //  Segment Register
+    .push ( X86::cs                                                                                 ) //  Segment Register
+    .push ( x86Mem16! ( word [ bx si + ] )                                                          ) //  Memory
+    .pop  ( x86Mem16! ( word [ bp 6 + ] )                                                           ) //  Memory
+    .pop  ( X86::ds                                                                                 ) //  Segment Register
+    .pop  ( X86::di                                                                                 ) //  Register
+    .label( "unary math instructions"                                                               )
+    .inc  ( X86::cx                                                                                 ) //  Word Register
+    .dec  ( X86::bl                                                                                 ) //  Byte Register
+    .inc  ( x86Mem16! ( byte [ bx ] )                                                               ) //  Memory
+    .dec  ( x86Mem16! ( word [ si 8 + ] )                                                           ) //  Memory
+    .neg  ( X86::ax                                                                                 ) //  Register
+    .not  ( x86Mem16! ( byte [ di ] )                                                               ) //  Memory
+    .mul  ( X86::bl                                                                                 ) //  Register
+    .imul ( x86Mem16! ( word [ bp 4 - ] )                                                           ) //  Memory
+    .div  ( X86::cx                                                                                 ) //  Register
+    .idiv ( x86Mem16! ( byte [ bx si + ] )                                                          ) //  Memory
+    .label( "shift instructions"                                                                    )
+    .shl  ( X86::ax,                                1                                               ) //  Shift by One
+    .sar  ( x86Mem16! ( byte [ bx 2 + ] ),          1                                               ) //  Shift by One
+    .rol  ( X86::dl,                                X86::cl                                         ) //  Shift by cl
+    .rcr  ( x86Mem16! ( word [ di ] ),              X86::cl                                         ) //  Shift by cl
     .label( "jump instruction"                                                                      )
     .je   ( Symbol  ( "simple math instructions 16 bit" )                                           ) //  Conditional Jump backward
     .jz   ( Symbol  ( "one byte instructions"           )                                           ) //  Conditional Jump forward
+    .label( "ascii adjust and escape instructions"                                                  )
+    .aam  (                                                                                         ) //  Decimal Base
+    .aad  (                                                                                         ) //  Decimal Base
+    .aamImm ( 16                                                                                    ) //  Hexadecimal Base
+    .aadImm ( 8                                                                                     ) //  Octal Base
+    .esc  ( 0x0d,                                   x86Mem16! ( word [ bx ] )                       ) //  fldcw word [bx]
+    .esc  ( 0x08,                                   X86::cx                                         ) //  fld st(1)
+    .label( "floating point instructions"                                                           )
+    .fninit (                                                                                       ) //  No Wait
+    .finit  (                                                                                       ) //  Wait and Initialise
+    .fld  ( x86Mem16! ( dword [ bx ] )                                                              ) //  Single Precision
+    .fld  ( x86Mem16! ( qword [ bp 8 + ] )                                                          ) //  Double Precision
+    .fld  ( x86Mem16! ( tword [ si ] )                                                              ) //  Extended Precision
+    .fld  ( X86::st3                                                                                ) //  Stack Register
+    .fst  ( x86Mem16! ( qword [ di ] )                                                              ) //  Double Precision
+    .fst  ( X86::st2                                                                                ) //  Stack Register
+    .fstp ( x86Mem16! ( tword [ bx 0x10 + ] )                                                       ) //  Extended Precision
+    .fstp ( X86::st1                                                                                ) //  Stack Register
+    .fadd ( X86::st0,                               X86::st4                                        ) //  st0 and Register
+    .fsub ( X86::st5,                               X86::st0                                        ) //  Register and st0
+    .fsubr( X86::st0,                               x86Mem16! ( dword [ bx ] )                      ) //  st0 and Memory
+    .fmul ( X86::st0,                               x86Mem16! ( qword [ bx ] )                      ) //  st0 and Memory
+    .fdiv ( X86::st2,                               X86::st0                                        ) //  Register and st0
+    .fdivrp ( X86::st1,                             X86::st0                                        ) //  Register and st0, Pop
+    .faddp  ( X86::st3,                             X86::st0                                        ) //  Register and st0, Pop
+    .fcom ( X86::st1                                                                                ) //  Register
+    .fcomp( x86Mem16! ( qword [ bp 4 - ] )                                                          ) //  Memory, Pop
+    .fcompp (                                                                                       ) //  Pop Twice
+    .fwait  (                                                                                       ) //  Wait
+    .label( "interrupt and port instructions"                                                       )
+    .int  ( 0x10                                                                                    ) //  Interrupt
+    .int  ( 3                                                                                       ) //  Interrupt, two Bytes
+    .int3 (                                                                                         ) //  Breakpoint, one Byte
+    .inn  ( X86::al,                                0x60                                            ) //  Byte from Constant Port
+    .inn  ( X86::ax,                                X86::dx                                         ) //  Word from Port dx
+    .out  ( 0x20,                                   X86::al                                         ) //  Byte to Constant Port
+    .out  ( X86::dx,                                X86::ax                                         ) //  Word to Port dx
+    .label( "prefixes"                                                                              )
+    .rep  ( ).movsb (                                                                               ) //  Repeat Move String
+    .repe ( ).cmpsw (                                                                               ) //  Repeat While Equal
+    .repne( ).scasb (                                                                               ) //  Repeat While Not Equal
+    .segment ( X86::es ).lodsb (                                                                    ) //  Segment Override of the Source
+    .rep  ( ).segment ( X86::cs ).movsw (                                                           ) //  Both Prefixes
+    .lock ( ).add  ( x86Mem16! ( word [ bx ] ),     X86::ax                                         ) //  Atomic Add
+    .lock ( ).xchg ( X86::al,                       x86Mem16! ( byte [ si ] )                       ) //  Atomic Exchange
+    .lock ( ).xchg ( x86Mem16! ( word [ bx ] ),     X86::cx                                         ) //  Atomic Exchange
+    .segment ( X86::ss ).mov ( X86::ax,             x86Mem16! ( word [ bx ] )                       ) //  Segment Override of a Memory Operand
+    .label( "branch instructions"                                                                   )
+    .jmp  ( Symbol  ( "branch instructions"             )                                           ) //  Short Jump backward
+    .call ( Symbol  ( "simple math instructions 8 bit"  )                                           ) //  Near Call backward
+    .call ( Symbol  ( "one byte instructions"           )                                           ) //  Near Call forward
+    .jmp  ( X86::bx                                                                                 ) //  Indirect Near Jump
+    .call ( x86Mem16! ( word [ bx si + ] )                                                          ) //  Indirect Near Call
+    .jmp  ( x86Mem16! ( dword [ 0x1234 ] )                                                          ) //  Indirect Far Jump
+    .call ( Intersegment ( 0xf000, 0xfff0 )                                                         ) //  Direct Far Call
+    .jmp  ( Intersegment ( 0x07c0, 0x0000 )                                                         ) //  Direct Far Jump
+    .retn (                                                                                         ) //  Near Return
+    .retnImm ( 4                                                                                    ) //  Near Return with Stack Adjustment
+    .retf (                                                                                         ) //  Far Return
+    .retfImm ( 8                                                                                    ) //  Far Return with Stack Adjustment
     .label( "one byte instructions"                                                                 )
     .iret (                                                                                         )
     ;
@@ -166,17 +191,203 @@ fn main () -> Result<(), &'static str>
       10,
     ).unwrap();
 
-  let mut file                          =   File::create  ( "build/8086.bin"  ).unwrap();
-  file.write_all  ( &myAssembly ).unwrap();
-  file.sync_all   (             ).unwrap();
-
-  Command::new("objdump")
-  .arg("--disassemble-all")
-  .arg("--disassembler-options=intel")
-  .arg("--target=binary")
-  .arg("--architecture=i8086")
-  .arg("build/8086.bin")
-  .status()
-  .unwrap();
+  common::assertAssembly
+  (
+    "8086",
+    "i8086",
+    &myAssembly,
+    &[
+      0xe9, 0x45, 0x01,                       //  jmp 0x148
+      0x74, 0x03,                             //  je 0x8
+      0xe9, 0x40, 0x01,                       //  jmp 0x148
+      0xe3, 0x02,                             //  jcxz 0xc
+      0xeb, 0x03,                             //  jmp 0xf
+      0xe9, 0x39, 0x01,                       //  jmp 0x148
+      0x00, 0xd1,                             //  add cl,dl
+      0x00, 0x52, 0x80,                       //  add BYTE PTR [bp+si-0x80],dl
+      0x02, 0x52, 0x80,                       //  add dl,BYTE PTR [bp+si-0x80]
+      0x80, 0xc1, 0x90,                       //  add cl,0x90
+      0x80, 0x42, 0x80, 0x42,                 //  add BYTE PTR [bp+si-0x80],0x42
+      0x04, 0x23,                             //  add al,0x23
+      0x01, 0xd1,                             //  add cx,dx
+      0x01, 0x52, 0x80,                       //  add WORD PTR [bp+si-0x80],dx
+      0x03, 0x52, 0x80,                       //  add dx,WORD PTR [bp+si-0x80]
+      0x83, 0xc1, 0x32,                       //  add cx,0x32
+      0x81, 0xc1, 0x00, 0x90,                 //  add cx,0x9000
+      0x83, 0x42, 0x80, 0x42,                 //  add WORD PTR [bp+si-0x80],0x42
+      0x81, 0x42, 0x80, 0x37, 0x13,           //  add WORD PTR [bp+si-0x80],0x1337
+      0x05, 0x37, 0x13,                       //  add ax,0x1337
+      0x88, 0xd1,                             //  mov cl,dl
+      0x89, 0x71, 0x04,                       //  mov WORD PTR [bx+di+0x4],si
+      0x8b, 0x66, 0x00,                       //  mov sp,WORD PTR [bp+0x0]
+      0xb7, 0x12,                             //  mov bh,0x12
+      0xbf, 0x34, 0x12,                       //  mov di,0x1234
+      0x26, 0xc6, 0x04, 0x42,                 //  mov BYTE PTR es:[si],0x42
+      0xc7, 0x86, 0x00, 0x01, 0x37, 0x13,     //  mov WORD PTR [bp+0x100],0x1337
+      0xa0, 0x34, 0x12,                       //  mov al,ds:0x1234
+      0x2e, 0xa3, 0x78, 0x56,                 //  mov cs:0x5678,ax
+      0x89, 0x0e, 0x78, 0x56,                 //  mov WORD PTR ds:0x5678,cx
+      0x8e, 0xd8,                             //  mov ds,ax
+      0x8e, 0x07,                             //  mov es,WORD PTR [bx]
+      0x8c, 0xd2,                             //  mov dx,ss
+      0x8c, 0x4d, 0xfe,                       //  mov WORD PTR [di-0x2],cs
+      0xa8, 0x80,                             //  test al,0x80
+      0xf7, 0xc3, 0x00, 0x80,                 //  test bx,0x8000
+      0xf6, 0x04, 0x01,                       //  test BYTE PTR [si],0x1
+      0x85, 0xd1,                             //  test cx,dx
+      0x84, 0x17,                             //  test BYTE PTR [bx],dl
+      0x93,                                   //  xchg bx,ax
+      0x96,                                   //  xchg si,ax
+      0x86, 0xd1,                             //  xchg cl,dl
+      0x87, 0x4e, 0x02,                       //  xchg WORD PTR [bp+0x2],cx
+      0x90,                                   //  nop
+      0x90,                                   //  nop
+      0x8d, 0x71, 0x10,                       //  lea si,[bx+di+0x10]
+      0xc5, 0x76, 0x04,                       //  lds si,DWORD PTR [bp+0x4]
+      0x26, 0xc4, 0x3e, 0x00, 0x04,           //  les di,DWORD PTR es:0x400
+      0x50,                                   //  push ax
+      0x06,                                   //  push es
+      0x0e,                                   //  push cs
+      0xff, 0x30,                             //  push WORD PTR [bx+si]
+      0x8f, 0x46, 0x06,                       //  pop WORD PTR [bp+0x6]
+      0x1f,                                   //  pop ds
+      0x5f,                                   //  pop di
+      0x41,                                   //  inc cx
+      0xfe, 0xcb,                             //  dec bl
+      0xfe, 0x07,                             //  inc BYTE PTR [bx]
+      0xff, 0x4c, 0x08,                       //  dec WORD PTR [si+0x8]
+      0xf7, 0xd8,                             //  neg ax
+      0xf6, 0x15,                             //  not BYTE PTR [di]
+      0xf6, 0xe3,                             //  mul bl
+      0xf7, 0x6e, 0xfc,                       //  imul WORD PTR [bp-0x4]
+      0xf7, 0xf1,                             //  div cx
+      0xf6, 0x38,                             //  idiv BYTE PTR [bx+si]
+      0xd1, 0xe0,                             //  shl ax,1
+      0xd0, 0x7f, 0x02,                       //  sar BYTE PTR [bx+0x2],1
+      0xd2, 0xc2,                             //  rol dl,cl
+      0xd3, 0x1d,                             //  rcr WORD PTR [di],cl
+      0x75, 0x03,                             //  jne 0xb4
+      0xe9, 0x6c, 0xff,                       //  jmp 0x20
+      0x75, 0x03,                             //  jne 0xb9
+      0xe9, 0x8f, 0x00,                       //  jmp 0x148
+      0xd4, 0x0a,                             //  aam 0xa
+      0xd5, 0x0a,                             //  aad 0xa
+      0xd4, 0x10,                             //  aam 0x10
+      0xd5, 0x08,                             //  aad 0x8
+      0xd9, 0x2f,                             //  fldcw WORD PTR [bx]
+      0xd9, 0xc1,                             //  fld st(1)
+      0xdb, 0xe3,                             //  fninit
+      0x9b, 0xdb, 0xe3,                       //  finit
+      0x9b, 0xd9, 0x07,                       //  fld DWORD PTR [bx]
+      0x9b, 0xdd, 0x46, 0x08,                 //  fld QWORD PTR [bp+0x8]
+      0x9b, 0xdb, 0x2c,                       //  fld TBYTE PTR [si]
+      0x9b, 0xd9, 0xc3,                       //  fld st(3)
+      0x9b, 0xdd, 0x15,                       //  fst QWORD PTR [di]
+      0x9b, 0xdd, 0xd2,                       //  fst st(2)
+      0x9b, 0xdb, 0x7f, 0x10,                 //  fstp TBYTE PTR [bx+0x10]
+      0x9b, 0xdd, 0xd9,                       //  fstp st(1)
+      0x9b, 0xd8, 0xc4,                       //  fadd st,st(4)
+      0x9b, 0xdc, 0xed,                       //  fsub st(5),st
+      0x9b, 0xd8, 0x2f,                       //  fsubr DWORD PTR [bx]
+      0x9b, 0xdc, 0x0f,                       //  fmul QWORD PTR [bx]
+      0x9b, 0xdc, 0xfa,                       //  fdiv st(2),st
+      0x9b, 0xde, 0xf1,                       //  fdivrp st(1),st
+      0x9b, 0xde, 0xc3,                       //  faddp st(3),st
+      0x9b, 0xd8, 0xd1,                       //  fcom st(1)
+      0x9b, 0xdc, 0x5e, 0xfc,                 //  fcomp QWORD PTR [bp-0x4]
+      0x9b, 0xde, 0xd9,                       //  fcompp
+      0x9b,                                   //  fwait
+      0xcd, 0x10,                             //  int 0x10
+      0xcd, 0x03,                             //  int 0x3
+      0xcc,                                   //  int3
+      0xe4, 0x60,                             //  in al,0x60
+      0xed,                                   //  in ax,dx
+      0xe6, 0x20,                             //  out 0x20,al
+      0xef,                                   //  out dx,ax
+      0xf3, 0xa4,                             //  rep movs BYTE PTR es:[di],BYTE PTR ds:[si]
+      0xf3, 0xa7,                             //  repz cmps WORD PTR ds:[si],WORD PTR es:[di]
+      0xf2, 0xae,                             //  repnz scas al,BYTE PTR es:[di]
+      0x26, 0xac,                             //  lods al,BYTE PTR es:[si]
+      0xf3, 0x2e, 0xa5,                       //  rep movs WORD PTR es:[di],WORD PTR cs:[si]
+      0xf0, 0x01, 0x07,                       //  lock add WORD PTR [bx],ax
+      0xf0, 0x86, 0x04,                       //  lock xchg BYTE PTR [si],al
+      0xf0, 0x87, 0x0f,                       //  lock xchg WORD PTR [bx],cx
+      0x36, 0x8b, 0x07,                       //  mov ax,WORD PTR ss:[bx]
+      0xeb, 0xfe,                             //  jmp 0x126
+      0xe8, 0xe4, 0xfe,                       //  call 0xf
+      0xe8, 0x1a, 0x00,                       //  call 0x148
+      0xff, 0xe3,                             //  jmp bx
+      0xff, 0x10,                             //  call WORD PTR [bx+si]
+      0xff, 0x2e, 0x34, 0x12,                 //  jmp DWORD PTR ds:0x1234
+      0x9a, 0xf0, 0xff, 0x00, 0xf0,           //  call 0xf000:0xfff0
+      0xea, 0x00, 0x00, 0xc0, 0x07,           //  jmp 0x7c0:0x0
+      0xc3,                                   //  ret
+      0xc2, 0x04, 0x00,                       //  ret 0x4
+      0xcb,                                   //  retf
+      0xca, 0x08, 0x00,                       //  retf 0x8
+      0xcf,                                   //  iret
+    ],
+  );
   Ok(())
 }
+
+#[test]
+fn branchExpansion ()
+{
+  //  200 bytes between the conditional jump and its destination
+  let     program                       =   | mut code: X86 |
+                                            {
+                                              code              =   code.je ( Symbol  ( "far away" ) );
+                                              for _             in  0 .. 100
+                                              {
+                                                code            =   code.mov  ( X86::ax,  X86::bx );
+                                              }
+                                              code.label  ( "far away" )
+                                            };
+  assert!
+  (
+    program ( X86 () ).compile
+    (
+      InstructionSet::i8086,
+      16,
+      16,
+      10,
+    ).is_ok()
+  );
+  //  the option applies to the whole program, even to instructions before it
+  assert!
+  (
+    program ( X86 () ).disable ( AssemblyFeatures::X86BranchExpansion ).compile
+    (
+      InstructionSet::i8086,
+      16,
+      16,
+      10,
+    ).is_err()
+  );
+}
+
+#[test]
+fn prefixValidation ()
+{
+  common::assertRejected
+  (
+    &[
+      | code  | code.lock ( ).add     ( X86::ax,  X86::bx                           ),
+      | code  | code.lock ( ).mov     ( x86Mem16! ( word [ bx ] ),  X86::ax         ),
+      | code  | code.lock ( ).xchg    ( X86::ax,  X86::bx                           ),
+      | code  | code.lock ( ).xchg    ( X86::cl,  X86::dl                           ),
+      | code  | code.rep  ( ).add     ( X86::ax,  X86::bx                           ),
+      | code  | code.repne ( ).movsb  (                                             ),
+      | code  | code.segment ( X86::es ).add  ( X86::ax,  X86::bx                   ),
+      | code  | code.segment ( X86::es ).stosb  (                                   ),
+      | code  | code.segment ( X86::fs ).movsb  (                                   ),
+      | code  | code.segment ( X86::cs ).mov  ( X86::ax,  x86Mem16! ( word es:[ bx ] ) ),
+      | code  | code.rep  ( ).label   ( "no instruction"                            ),
+      | code  | code.rep  (                                                         ),
+    ],
+    InstructionSet::i8086,
+    16,
+    16,
+  );
+}
@@ -18,86 +18,7 @@ use sucks2::
   },
 };
 
-fn _hexDump
-(
-  buffer:                               Box<[u8]>,
-  width:                                usize,
-  offset:                               usize,
-  mut length:                           usize,
-) -> Result<usize, &'static str>
-{
-  let   size                            =   buffer.len();
-  if offset < size
-  {
-    if length == 0
-    {
-      length                            =   size - offset;
-    }
-    if length <= ( size - offset )
-    {
-      let   lines                       =   length / width;
-      for line                          in  0 .. lines
-      {
-        for pos                         in  0 .. width
-        {
-          print!  ( "{:02x} ", buffer [ offset + width * line + pos ] );
-        }
-        print!  ( "| " );
-        for pos                         in  0 .. width
-        {
-          let char                      =   buffer [ offset + width * line + pos ];
-          if  ( char >= 0x20 && char <= 0x7e )
-          ||  ( char >= 0xa0 )
-          {
-            print!  ( "{}", char as char );
-          }
-          else
-          {
-            print!  ( "." );
-          }
-        }
-        println!  ( "" );
-      }
-      let   remainder                   =   length % width;
-      if remainder > 0
-      {
-        for pos                         in  0 .. remainder
-        {
-          print!  ( "{:02x} ", buffer [ offset + width * lines + pos ] );
-        }
-        for _                           in  remainder .. width
-        {
-          print!  ( "   " );
-        }
-        print!  ( "| " );
-        for pos                         in  0 .. remainder
-        {
-          let char                      =   buffer [ offset + width * lines + pos ];
-          if  ( char >= 0x20 && char <= 0x7e )
-          ||  ( char >= 0xa0 )
-          {
-            print!  ( "{}", char as char );
-          }
-          else
-          {
-            print!  ( "." );
-          }
-        }
-        println!  ( "" );
-      }
-      Ok ( length )
-    }
-    else
-    {
-      Err ( "Length Out Of Bonds" )
-    }
-  }
-  else
-  {
-    Err ( "Offset Out Of Bonds" )
-  }
-}
-
+mod common;
 
 #[test]
 fn main () -> Result<(), &'static str>
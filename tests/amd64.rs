@@ -0,0 +1,257 @@
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+
+#[macro_use]
+extern crate sucks2;
+use sucks2::
+{
+  assembly::
+  {
+    InstructionSet,
+    x86::
+    {
+      X86,
+      expressions::
+      {
+        Expression,
+        ExpressionToken,
+      },
+      memory::
+      {
+        Intersegment,
+        Memory16Registers,
+        Memory32NoRegister,
+        RipRelative,
+      },
+      registers::
+      {
+        SegmentRegisterNumber,
+      },
+      symbols::
+      {
+        Symbol,
+      },
+    },
+  },
+};
+
+mod common;
+
+#[test]
+fn main () -> Result<(), &'static str>
+{
+  let     myCode
+  = X86 ()
+    .label( "64 bit operands"                                                                       )
+    .mov  ( X86::rax,                               X86::rbx                                        ) //  REX.W
+    .mov  ( X86::r8,                                X86::r15                                        ) //  REX.R and REX.B
+    .mov  ( X86::r9d,                               X86::eax                                        ) //  REX.B without REX.W
+    .mov  ( X86::r10w,                              X86::cx                                         ) //  Operand Size Override and REX.B
+    .mov  ( X86::rax,                               0x123456789abcdef0                              ) //  Move 64 Bit Immediate
+    .mov  ( X86::rcx,                               -1                                              ) //  Move Sign Extended Immediate
+    .mov  ( X86::r11,                               0x12345678                                      )
+    .add  ( X86::rax,                               0x1000                                          ) //  Accumulator
+    .sub  ( X86::r12,                               8                                               ) //  Sign Extended Byte
+    .cmp  ( X86::r13d,                              0x12345678                                      )
+    .inc  ( X86::rdx                                                                                ) //  no one byte encoding
+    .dec  ( X86::r14w                                                                               )
+    .push ( X86::rbp                                                                                ) //  64 Bit Stack
+    .push ( X86::r12                                                                                )
+    .pop  ( X86::r15                                                                                )
+    .push ( 0x1234                                                                                  )
+    .xchg ( X86::rax,                               X86::r9                                         )
+    .bswap( X86::r10                                                                                )
+    .movzx( X86::r8,                                X86::sil                                        ) //  Extend Byte to Quad Word
+    .movsx( X86::rax,                               X86::r11w                                       )
+    .label( "byte registers"                                                                        )
+    .mov  ( X86::spl,                               X86::al                                         ) //  REX without Bits
+    .mov  ( X86::dil,                               X86::r8b                                        )
+    .mov  ( X86::ah,                                X86::bl                                         ) //  no REX
+    .xor  ( X86::r15b,                              0x7f                                            )
+    .label( "64 bit addressing"                                                                     )
+    .mov  ( X86::rax,                               x86Mem64! ( qword [ rbx ] )                     )
+    .mov  ( x86Mem64! ( qword [ r12 ] ),            X86::rcx                                        ) //  SIB Byte Required
+    .mov  ( X86::edx,                               x86Mem64! ( dword [ r13 ] )                     ) //  Displacement Required
+    .lea  ( X86::rsi,                               x86Mem64! ( qword [ rax r9 4 * + 0x10 + ] )     ) //  REX.X
+    .add  ( x86Mem64! ( dword [ rsp 8 + ] ),        0x55                                            )
+    .mov  ( X86::rax,                               x86Mem64! ( qword [ 0x1000 ] )                  ) //  Absolute Address
+    .mov  ( X86::eax,                               x86Mem32! ( dword [ ecx ] )                     ) //  Address Size Override
+    .label( "instruction pointer relative"                                                          )
+    .lea  ( X86::rdi,                               RipRelative ( 8, SegmentRegisterNumber::Default, "data" ) )
+    .mov  ( X86::rax,                               RipRelative ( 8, SegmentRegisterNumber::Default, "data" ) )
+    .cmp  ( RipRelative ( 4, SegmentRegisterNumber::Default, "64 bit operands" ), 0x1234           ) //  Backward with Immediate
+    .jmp  ( Symbol  ( "64 bit operands"                 )                                           ) //  Near Jump backward
+    .jmp  ( X86::rax                                                                                ) //  Indirect Near Jump without REX.W
+    .call ( x86Mem64! ( qword [ r12 ] )                                                             ) //  Indirect Near Call
+    .label( "system registers"                                                                      )
+    .mov  ( X86::cr3,                               X86::rax                                        ) //  Quad Word without REX.W
+    .mov  ( X86::r8,                                X86::cr0                                        ) //  REX.B
+    .label( "extended xmm registers"                                                                )
+    .movaps( X86::xmm8,                             X86::xmm15                                      ) //  REX.R and REX.B
+    .addsd( X86::xmm9,                              x86Mem64! ( qword [ r12 ] )                     )
+    .cvtsi2sd( X86::xmm10,                          X86::rax                                        ) //  REX.W selects a Quad Word
+    .cvttss2si( X86::r11,                           X86::xmm1                                       )
+    .movdqu( x86Mem64! ( oword [ rsp ] ),           X86::xmm12                                      )
+    .label( "extended ymm registers"                                                                )
+    .vmovaps( X86::ymm8,                            X86::ymm1                                       ) //  Two Byte VEX with R
+    .vaddps( X86::ymm9,                             X86::ymm14,                     X86::ymm15      ) //  Three Byte VEX with B
+    .vmulsd( X86::xmm10,                            X86::xmm11,                     x86Mem64! ( qword [ r13 rcx 8 * + ] ) )
+    .vpxor( X86::ymm12,                             X86::ymm12,                     X86::ymm12      )
+    .label( "data"                                                                                  )
+    .retn (                                                                                         )
+    ;
+
+  let myAssembly
+  = myCode.compile
+    (
+      InstructionSet::Haswell,
+      64,
+      64,
+      10,
+    ).unwrap();
+
+  common::assertAssembly
+  (
+    "amd64",
+    "i386:x86-64",
+    &myAssembly,
+    &[
+      0x48, 0x89, 0xd8,                                               //  mov rax,rbx
+      0x4d, 0x89, 0xf8,                                               //  mov r8,r15
+      0x41, 0x89, 0xc1,                                               //  mov r9d,eax
+      0x66, 0x41, 0x89, 0xca,                                         //  mov r10w,cx
+      0x48, 0xb8, 0xf0, 0xde, 0xbc, 0x9a, 0x78, 0x56, 0x34, 0x12,     //  movabs rax,0x123456789abcdef0
+      0x48, 0xc7, 0xc1, 0xff, 0xff, 0xff, 0xff,                       //  mov rcx,0xffffffffffffffff
+      0x49, 0xc7, 0xc3, 0x78, 0x56, 0x34, 0x12,                       //  mov r11,0x12345678
+      0x48, 0x05, 0x00, 0x10, 0x00, 0x00,                             //  add rax,0x1000
+      0x49, 0x83, 0xec, 0x08,                                         //  sub r12,0x8
+      0x41, 0x81, 0xfd, 0x78, 0x56, 0x34, 0x12,                       //  cmp r13d,0x12345678
+      0x48, 0xff, 0xc2,                                               //  inc rdx
+      0x66, 0x41, 0xff, 0xce,                                         //  dec r14w
+      0x55,                                                           //  push rbp
+      0x41, 0x54,                                                     //  push r12
+      0x41, 0x5f,                                                     //  pop r15
+      0x68, 0x34, 0x12, 0x00, 0x00,                                   //  push 0x1234
+      0x49, 0x91,                                                     //  xchg r9,rax
+      0x49, 0x0f, 0xca,                                               //  bswap r10
+      0x4c, 0x0f, 0xb6, 0xc6,                                         //  movzx r8,sil
+      0x49, 0x0f, 0xbf, 0xc3,                                         //  movsx rax,r11w
+      0x40, 0x88, 0xc4,                                               //  mov spl,al
+      0x44, 0x88, 0xc7,                                               //  mov dil,r8b
+      0x88, 0xdc,                                                     //  mov ah,bl
+      0x41, 0x80, 0xf7, 0x7f,                                         //  xor r15b,0x7f
+      0x48, 0x8b, 0x03,                                               //  mov rax,QWORD PTR [rbx]
+      0x49, 0x89, 0x0c, 0x24,                                         //  mov QWORD PTR [r12],rcx
+      0x41, 0x8b, 0x55, 0x00,                                         //  mov edx,DWORD PTR [r13+0x0]
+      0x4a, 0x8d, 0x74, 0x88, 0x10,                                   //  lea rsi,[rax+r9*4+0x10]
+      0x83, 0x44, 0x24, 0x08, 0x55,                                   //  add DWORD PTR [rsp+0x8],0x55
+      0x48, 0x8b, 0x04, 0x25, 0x00, 0x10, 0x00, 0x00,                 //  mov rax,QWORD PTR ds:0x1000
+      0x67, 0x8b, 0x01,                                               //  mov eax,DWORD PTR [ecx]
+      0x48, 0x8d, 0x3d, 0x52, 0x00, 0x00, 0x00,                       //  lea rdi,[rip+0x52] # 0xd9
+      0x48, 0x8b, 0x05, 0x4b, 0x00, 0x00, 0x00,                       //  mov rax,QWORD PTR [rip+0x4b] # 0xd9
+      0x81, 0x3d, 0x68, 0xff, 0xff, 0xff, 0x34, 0x12, 0x00, 0x00,     //  cmp DWORD PTR [rip+0xffffffffffffff68],0x1234 # 0x0
+      0xe9, 0x63, 0xff, 0xff, 0xff,                                   //  jmp 0x0
+      0xff, 0xe0,                                                     //  jmp rax
+      0x41, 0xff, 0x14, 0x24,                                         //  call QWORD PTR [r12]
+      0x0f, 0x22, 0xd8,                                               //  mov cr3,rax
+      0x41, 0x0f, 0x20, 0xc0,                                         //  mov r8,cr0
+      0x45, 0x0f, 0x28, 0xc7,                                         //  movaps xmm8,xmm15
+      0xf2, 0x45, 0x0f, 0x58, 0x0c, 0x24,                             //  addsd xmm9,QWORD PTR [r12]
+      0xf2, 0x4c, 0x0f, 0x2a, 0xd0,                                   //  cvtsi2sd xmm10,rax
+      0xf3, 0x4c, 0x0f, 0x2c, 0xd9,                                   //  cvttss2si r11,xmm1
+      0xf3, 0x44, 0x0f, 0x7f, 0x24, 0x24,                             //  movdqu XMMWORD PTR [rsp],xmm12
+      0xc5, 0x7c, 0x28, 0xc1,                                         //  vmovaps ymm8,ymm1
+      0xc4, 0x41, 0x0c, 0x58, 0xcf,                                   //  vaddps ymm9,ymm14,ymm15
+      0xc4, 0x41, 0x23, 0x59, 0x54, 0xcd, 0x00,                       //  vmulsd xmm10,xmm11,QWORD PTR [r13+rcx*8+0x0]
+      0xc4, 0x41, 0x1d, 0xef, 0xe4,                                   //  vpxor ymm12,ymm12,ymm12
+      0xc3,                                                           //  ret
+    ],
+  );
+  Ok(())
+}
+
+#[test]
+fn requiresLongMode ()
+{
+  common::assertRejected
+  (
+    &[
+      | code  | code.mov      ( X86::rax, X86::rbx                                        ),
+      | code  | code.mov      ( X86::r8d, X86::eax                                        ),
+      | code  | code.mov      ( X86::sil, X86::al                                         ),
+      | code  | code.mov      ( X86::eax, x86Mem64! ( dword [ rax ] )                     ),
+      | code  | code.lea      ( X86::eax, RipRelative ( 4, SegmentRegisterNumber::Default, "here" ) ).label ( "here" ),
+    ],
+    InstructionSet::Pentium2,
+    32,
+    32,
+  );
+}
+
+#[test]
+fn highByteRegisters ()
+{
+  common::assertRejected
+  (
+    &[
+      | code  | code.mov      ( X86::ah,  X86::sil                                        ),
+      | code  | code.mov      ( X86::bh,  X86::r8b                                        ),
+      | code  | code.add      ( X86::ch,  X86::dil                                        ),
+      | code  | code.movzx    ( X86::r8d, X86::dh                                         ),
+      | code  | code.mov      ( x86Mem64! ( byte [ r9 ] ), X86::ah                        ),
+      | code  | code.mov      ( X86::eax, x86Mem16! ( dword [ bx ] )                      ),
+    ],
+    InstructionSet::amd64,
+    64,
+    64,
+  );
+}
+
+#[test]
+fn directMemoryOffset ()
+{
+  common::assertRejected
+  (
+    &[
+      | code  | code.mov      ( X86::al,  x86Mem16! ( byte [ 0x1234 ] )                   ),
+      | code  | code.mov      ( X86::rax, x86Mem16! ( qword [ 0x1234 ] )                  ),
+      | code  | code.mov      ( x86Mem16! ( dword [ 0x1234 ] ), X86::eax                  ),
+    ],
+    InstructionSet::amd64,
+    64,
+    64,
+  );
+}
+
+#[test]
+fn systemRegistersInLongMode ()
+{
+  common::assertRejected
+  (
+    &[
+      | code  | code.mov      ( X86::cr3, X86::eax                                        ),
+      | code  | code.mov      ( X86::ebx, X86::cr0                                        ),
+    ],
+    InstructionSet::amd64,
+    64,
+    64,
+  );
+}
+
+#[test]
+fn branchesInLongMode ()
+{
+  common::assertRejected
+  (
+    &[
+      | code  | code.jmp      ( X86::eax                                                  ),
+      | code  | code.call     ( X86::r9d                                                  ),
+      | code  | code.jmp      ( x86Mem64! ( dword [ rax ] )                               ),
+      | code  | code.jmp      ( Intersegment ( 0x0008, 0x1000 )                           ),
+      | code  | code.call     ( Intersegment ( 0x0008, 0x1000 )                           ),
+    ],
+    InstructionSet::amd64,
+    64,
+    64,
+  );
+}
@@ -0,0 +1,172 @@
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+
+#[macro_use]
+extern crate sucks2;
+use sucks2::
+{
+  assembly::
+  {
+    AssemblyFeatures,
+    InstructionSet,
+    x86::
+    {
+      X86,
+      expressions::
+      {
+        Expression,
+        ExpressionToken,
+      },
+      memory::
+      {
+        Memory32NoRegister,
+      },
+      registers::
+      {
+        SegmentRegisterNumber,
+      },
+    },
+  },
+};
+
+mod common;
+
+#[test]
+fn main () -> Result<(), &'static str>
+{
+  let     myCode
+  = X86 ()
+    .label( "avx moves"                                                                             )
+    .vmovaps( X86::ymm0,                            X86::ymm1                                       ) //  Move Aligned Packed Single
+    .vmovups( x86Mem32! ( yword [ esi ] ),          X86::ymm2                                       ) //  Move Unaligned Packed Single
+    .vmovdqa( X86::xmm3,                            x86Mem32! ( oword [ eax ebx 4 * + ] )           ) //  Move Aligned Double Quad Word
+    .vmovdqu( X86::ymm4,                            x86Mem32! ( yword [ ebp 8 + ] )                 ) //  Move Unaligned Double Quad Word
+    .label( "avx arithmetic"                                                                        )
+    .vaddps( X86::ymm0,                             X86::ymm1,                      X86::ymm2       ) //  Add Packed Single
+    .vmulpd( X86::xmm3,                             X86::xmm4,                      x86Mem32! ( oword [ ecx ] ) ) //  Multiply Packed Double
+    .vsubss( X86::xmm5,                             X86::xmm6,                      x86Mem32! ( dword [ edx ] ) ) //  Subtract Scalar Single
+    .vdivsd( X86::xmm7,                             X86::xmm0,                      X86::xmm1       ) //  Divide Scalar Double
+    .vxorps( X86::ymm2,                             X86::ymm2,                      X86::ymm2       ) //  Bitwise Exclusive Or
+    .vsqrtpd( X86::ymm3,                            X86::ymm4                                       ) //  Square Root
+    .vcmpps( X86::ymm5,                             X86::ymm6,                      X86::ymm7,  29  ) //  Compare Greater Than or Equal, Ordered, Quiet
+    .vbroadcastss( X86::ymm0,                       x86Mem32! ( dword [ edi ] )                     ) //  Broadcast Single
+    .vinsertf128( X86::ymm1,                        X86::ymm2,                      X86::xmm3,  1   ) //  Insert Upper Half
+    .vextractf128( X86::xmm4,                       X86::ymm5,                                  1   ) //  Extract Upper Half
+    .vperm2f128( X86::ymm6,                         X86::ymm7,                      X86::ymm0,  0x21 ) //  Permute Halves
+    .vpxor( X86::xmm1,                              X86::xmm2,                      X86::xmm3       ) //  Integer on XMM Registers
+    .vzeroupper(                                                                                    ) //  Clear Upper Halves
+    .vzeroall(                                                                                      ) //  Clear All Registers
+    .label( "avx2 instructions"                                                                     )
+    .vpaddd( X86::ymm0,                             X86::ymm1,                      X86::ymm2       ) //  Packed Add
+    .vpcmpeqb( X86::ymm3,                           X86::ymm4,                      x86Mem32! ( yword [ eax ] ) ) //  Packed Compare Equal
+    .vpmulld( X86::ymm5,                            X86::ymm6,                      X86::ymm7       ) //  Packed Multiply Low
+    .vpsllvd( X86::ymm0,                            X86::ymm1,                      X86::ymm2       ) //  Variable Shift Double Words
+    .vpsllvq( X86::xmm3,                            X86::xmm4,                      X86::xmm5       ) //  Variable Shift Quad Words
+    .vpbroadcastd( X86::ymm6,                       X86::xmm7                                       ) //  Broadcast Double Word
+    .vpbroadcastq( X86::xmm0,                       x86Mem32! ( qword [ esi ] )                     ) //  Broadcast Quad Word
+    .vpermq( X86::ymm1,                             X86::ymm2,                                  0x1b ) //  Permute Quad Words
+    .vinserti128( X86::ymm3,                        X86::ymm4,                      x86Mem32! ( oword [ ebx ] ), 0 ) //  Insert Lower Half
+    .vextracti128( x86Mem32! ( oword [ ecx ] ),     X86::ymm5,                                  1   ) //  Extract Upper Half
+    .label( "three byte vex"                                                                        )
+    .enable( AssemblyFeatures::X86ThreeByteVEX                                                      )
+    .vaddps( X86::ymm0,                             X86::ymm1,                      X86::ymm2       ) //  Equivalent Encoding
+    .vmovaps( X86::xmm3,                            X86::xmm4                                       )
+    .disable( AssemblyFeatures::X86ThreeByteVEX                                                     )
+    ;
+
+  let myAssembly
+  = myCode.compile
+    (
+      InstructionSet::Haswell,
+      32,
+      32,
+      10,
+    ).unwrap();
+
+  common::assertAssembly
+  (
+    "avx",
+    "i386",
+    &myAssembly,
+    &[
+      0xc5, 0xfc, 0x28, 0xc1,                 //  vmovaps ymm0,ymm1
+      0xc5, 0xfc, 0x11, 0x16,                 //  vmovups YMMWORD PTR [esi],ymm2
+      0xc5, 0xf9, 0x6f, 0x1c, 0x98,           //  vmovdqa xmm3,XMMWORD PTR [eax+ebx*4]
+      0xc5, 0xfe, 0x6f, 0x65, 0x08,           //  vmovdqu ymm4,YMMWORD PTR [ebp+0x8]
+      0xc5, 0xf4, 0x58, 0xc2,                 //  vaddps ymm0,ymm1,ymm2
+      0xc5, 0xd9, 0x59, 0x19,                 //  vmulpd xmm3,xmm4,XMMWORD PTR [ecx]
+      0xc5, 0xca, 0x5c, 0x2a,                 //  vsubss xmm5,xmm6,DWORD PTR [edx]
+      0xc5, 0xfb, 0x5e, 0xf9,                 //  vdivsd xmm7,xmm0,xmm1
+      0xc5, 0xec, 0x57, 0xd2,                 //  vxorps ymm2,ymm2,ymm2
+      0xc5, 0xfd, 0x51, 0xdc,                 //  vsqrtpd ymm3,ymm4
+      0xc5, 0xcc, 0xc2, 0xef, 0x1d,           //  vcmpge_oqps ymm5,ymm6,ymm7
+      0xc4, 0xe2, 0x7d, 0x18, 0x07,           //  vbroadcastss ymm0,DWORD PTR [edi]
+      0xc4, 0xe3, 0x6d, 0x18, 0xcb, 0x01,     //  vinsertf128 ymm1,ymm2,xmm3,0x1
+      0xc4, 0xe3, 0x7d, 0x19, 0xec, 0x01,     //  vextractf128 xmm4,ymm5,0x1
+      0xc4, 0xe3, 0x45, 0x06, 0xf0, 0x21,     //  vperm2f128 ymm6,ymm7,ymm0,0x21
+      0xc5, 0xe9, 0xef, 0xcb,                 //  vpxor xmm1,xmm2,xmm3
+      0xc5, 0xf8, 0x77,                       //  vzeroupper
+      0xc5, 0xfc, 0x77,                       //  vzeroall
+      0xc5, 0xf5, 0xfe, 0xc2,                 //  vpaddd ymm0,ymm1,ymm2
+      0xc5, 0xdd, 0x74, 0x18,                 //  vpcmpeqb ymm3,ymm4,YMMWORD PTR [eax]
+      0xc4, 0xe2, 0x4d, 0x40, 0xef,           //  vpmulld ymm5,ymm6,ymm7
+      0xc4, 0xe2, 0x75, 0x47, 0xc2,           //  vpsllvd ymm0,ymm1,ymm2
+      0xc4, 0xe2, 0xd9, 0x47, 0xdd,           //  vpsllvq xmm3,xmm4,xmm5
+      0xc4, 0xe2, 0x7d, 0x58, 0xf7,           //  vpbroadcastd ymm6,xmm7
+      0xc4, 0xe2, 0x79, 0x59, 0x06,           //  vpbroadcastq xmm0,QWORD PTR [esi]
+      0xc4, 0xe3, 0xfd, 0x00, 0xca, 0x1b,     //  vpermq ymm1,ymm2,0x1b
+      0xc4, 0xe3, 0x5d, 0x38, 0x1b, 0x00,     //  vinserti128 ymm3,ymm4,XMMWORD PTR [ebx],0x0
+      0xc4, 0xe3, 0x7d, 0x39, 0x29, 0x01,     //  vextracti128 XMMWORD PTR [ecx],ymm5,0x1
+      0xc4, 0xe1, 0x74, 0x58, 0xc2,           //  vaddps ymm0,ymm1,ymm2
+      0xc4, 0xe1, 0x78, 0x28, 0xdc,           //  vmovaps xmm3,xmm4
+    ],
+  );
+  Ok(())
+}
+
+#[test]
+fn requiresVectorExtensions ()
+{
+  common::assertRejected
+  (
+    &[
+      | code  | code.vaddps       ( X86::ymm0,  X86::ymm1,  X86::ymm2                     ),
+      | code  | code.vmovdqa      ( X86::xmm0,  X86::xmm1                                 ),
+      | code  | code.vzeroupper   (                                                       ),
+    ],
+    InstructionSet::amd64,
+    32,
+    32,
+  );
+  common::assertRejected
+  (
+    &[
+      | code  | code.vpaddd       ( X86::ymm0,  X86::ymm1,  X86::ymm2                     ),
+      | code  | code.vpsllvd      ( X86::xmm0,  X86::xmm1,  X86::xmm2                     ),
+      | code  | code.vpbroadcastd ( X86::xmm0,  X86::xmm1                                 ),
+      | code  | code.vpermq       ( X86::ymm0,  X86::ymm1,  0                             ),
+    ],
+    InstructionSet::SandyBridge,
+    32,
+    32,
+  );
+}
+
+#[test]
+fn vectorOperandSizes ()
+{
+  common::assertRejected
+  (
+    &[
+      | code  | code.vaddps       ( X86::ymm0,  X86::xmm1,  X86::ymm2                     ),
+      | code  | code.vaddss       ( X86::ymm0,  X86::ymm1,  X86::ymm2                     ),
+      | code  | code.vmovaps      ( X86::ymm0,  x86Mem32! ( oword [ eax ] )               ),
+      | code  | code.vperm2f128   ( X86::xmm0,  X86::xmm1,  X86::xmm2,  0                 ),
+      | code  | code.vcmpps       ( X86::xmm0,  X86::xmm1,  X86::xmm2,  32                ),
+      | code  | code.vaddps       ( X86::ymm8,  X86::ymm1,  X86::ymm2                     ),
+    ],
+    InstructionSet::Haswell,
+    32,
+    32,
+  );
+}
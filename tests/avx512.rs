@@ -0,0 +1,202 @@
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+
+#[macro_use]
+extern crate sucks2;
+use sucks2::
+{
+  assembly::
+  {
+    InstructionSet,
+    x86::
+    {
+      RoundingControl,
+      X86,
+      expressions::
+      {
+        Expression,
+        ExpressionToken,
+      },
+      memory::
+      {
+        Memory32NoRegister,
+      },
+      registers::
+      {
+        SegmentRegisterNumber,
+      },
+    },
+  },
+};
+
+mod common;
+
+#[test]
+fn main () -> Result<(), &'static str>
+{
+  let     myCode
+  = X86 ()
+    .label( "avx-512 arithmetic"                                                                    )
+    .vaddps( X86::zmm0,                             X86::zmm1,                      X86::zmm2       ) //  Add Packed Single
+    .vaddpd( X86::zmm16,                            X86::zmm17,                     X86::zmm31      ) //  EVEX.R', EVEX.V' and EVEX.X
+    .vmulps( X86::zmm3,                             X86::zmm4,                      x86Mem64! ( zword [ rax 0x40 + ] ) ) //  Compressed Displacement
+    .vsubpd( X86::zmm5,                             X86::zmm6,                      x86Mem64! ( qword [ rbx 8 + ] ) ).broadcast() //  Broadcast Element
+    .vdivps( X86::zmm7,                             X86::zmm8,                      X86::zmm9       ).rounding ( RoundingControl::Zero ) //  Embedded Rounding
+    .vaddsd( X86::xmm0,                             X86::xmm1,                      X86::xmm2       ).rounding ( RoundingControl::Down ) //  Scalar Rounding
+    .vsqrtps( X86::zmm1,                            X86::zmm2                                       ).mask ( X86::k1 ).zeroing() //  Zero Masking
+    .vmaxpd( X86::ymm20,                            X86::ymm21,                     X86::ymm22      ) //  Vector Length 256
+    .label( "avx-512 moves"                                                                         )
+    .vmovaps( X86::zmm0,                            x86Mem64! ( zword [ rsi ] )                     ).mask ( X86::k2 ) //  Merge Masking
+    .vmovups( x86Mem64! ( zword [ rdi 0x1000 + ] ), X86::zmm31                                      ) //  Compressed Displacement
+    .vmovdqa32( X86::zmm1,                          X86::zmm2                                       )
+    .vmovdqu64( x86Mem64! ( zword [ rax 3 + ] ),    X86::zmm3                                       ) //  Uncompressable Displacement
+    .vmovdqa64( X86::xmm16,                         x86Mem64! ( oword [ rcx ] )                     )
+    .label( "avx-512 integer"                                                                       )
+    .vpaddd( X86::zmm0,                             X86::zmm1,                      x86Mem64! ( dword [ rax ] ) ).broadcast().mask ( X86::k3 )
+    .vpsubq( X86::zmm2,                             X86::zmm3,                      X86::zmm4       )
+    .vpmulld( X86::zmm5,                            X86::zmm6,                      X86::zmm7       )
+    .vpandd( X86::zmm8,                             X86::zmm9,                      X86::zmm10      )
+    .vpandnq( X86::zmm11,                           X86::zmm12,                     X86::zmm13      )
+    .vpord( X86::xmm14,                             X86::xmm15,                     X86::xmm16      )
+    .vpxorq( X86::zmm17,                            X86::zmm18,                     x86Mem64! ( zword [ r8 r9 2 * + 0x80 + ] ) )
+    .vpternlogd( X86::zmm0,                         X86::zmm1,                      X86::zmm2,  0xff ) //  Ternary Logic
+    .vpbroadcastd( X86::zmm0,                       X86::xmm1                                       )
+    .vpbroadcastq( X86::zmm2,                       x86Mem64! ( qword [ rax 8 + ] )                 )
+    .vbroadcastss( X86::zmm3,                       x86Mem64! ( dword [ rax ] )                     )
+    .label( "avx-512 comparisons"                                                                   )
+    .vpcmpeqd( X86::k1,                             X86::zmm0,                      X86::zmm1       ) //  Compare into Mask Register
+    .vpcmpeqq( X86::k2,                             X86::zmm2,                      X86::zmm3       ).mask ( X86::k1 )
+    .vcmpps( X86::k3,                               X86::zmm4,                      X86::zmm5,  1   )
+    .vcmppd( X86::k4,                               X86::ymm6,                      x86Mem64! ( qword [ rax ] ), 0 ).broadcast()
+    .label( "mask registers"                                                                        )
+    .kmovw( X86::k1,                                X86::k2                                         )
+    .kmovw( X86::k3,                                x86Mem64! ( word [ rax ] )                      )
+    .kmovw( x86Mem64! ( word [ rax ] ),             X86::k4                                         )
+    .kmovw( X86::k5,                                X86::eax                                        )
+    .kmovw( X86::r8d,                               X86::k6                                         )
+    .kandw( X86::k1,                                X86::k2,                        X86::k3         )
+    .kandnw( X86::k4,                               X86::k5,                        X86::k6         )
+    .korw( X86::k7,                                 X86::k0,                        X86::k1         )
+    .kxorw( X86::k2,                                X86::k3,                        X86::k4         )
+    .knotw( X86::k5,                                X86::k6                                         )
+    .kortestw( X86::k7,                             X86::k0                                         )
+    .label( "vex encodings"                                                                         )
+    .vaddps( X86::ymm0,                             X86::ymm1,                      X86::ymm2       ) //  No AVX-512 Feature Used
+    .vpcmpeqd( X86::xmm3,                           X86::xmm4,                      X86::xmm5       )
+    ;
+
+  let myAssembly
+  = myCode.compile
+    (
+      InstructionSet::SkylakeX,
+      64,
+      64,
+      10,
+    ).unwrap();
+
+  common::assertAssembly
+  (
+    "avx512",
+    "i386:x86-64",
+    &myAssembly,
+    &[
+      0x62, 0xf1, 0x74, 0x48, 0x58, 0xc2,                             //  vaddps zmm0,zmm1,zmm2
+      0x62, 0x81, 0xf5, 0x40, 0x58, 0xc7,                             //  vaddpd zmm16,zmm17,zmm31
+      0x62, 0xf1, 0x5c, 0x48, 0x59, 0x58, 0x01,                       //  vmulps zmm3,zmm4,ZMMWORD PTR [rax+0x40]
+      0x62, 0xf1, 0xcd, 0x58, 0x5c, 0x6b, 0x01,                       //  vsubpd zmm5,zmm6,QWORD BCST [rbx+0x8]
+      0x62, 0xd1, 0x3c, 0x78, 0x5e, 0xf9,                             //  vdivps zmm7,zmm8,zmm9{rz-sae}
+      0x62, 0xf1, 0xf7, 0x38, 0x58, 0xc2,                             //  vaddsd xmm0,xmm1,xmm2{rd-sae}
+      0x62, 0xf1, 0x7c, 0xc9, 0x51, 0xca,                             //  vsqrtps zmm1{k1}{z},zmm2
+      0x62, 0xa1, 0xd5, 0x20, 0x5f, 0xe6,                             //  vmaxpd ymm20,ymm21,ymm22
+      0x62, 0xf1, 0x7c, 0x4a, 0x28, 0x06,                             //  vmovaps zmm0{k2},ZMMWORD PTR [rsi]
+      0x62, 0x61, 0x7c, 0x48, 0x11, 0x7f, 0x40,                       //  vmovups ZMMWORD PTR [rdi+0x1000],zmm31
+      0x62, 0xf1, 0x7d, 0x48, 0x6f, 0xca,                             //  vmovdqa32 zmm1,zmm2
+      0x62, 0xf1, 0xfe, 0x48, 0x7f, 0x98, 0x03, 0x00, 0x00, 0x00,     //  vmovdqu64 ZMMWORD PTR [rax+0x3],zmm3
+      0x62, 0xe1, 0xfd, 0x08, 0x6f, 0x01,                             //  vmovdqa64 xmm16,XMMWORD PTR [rcx]
+      0x62, 0xf1, 0x75, 0x5b, 0xfe, 0x00,                             //  vpaddd zmm0{k3},zmm1,DWORD BCST [rax]
+      0x62, 0xf1, 0xe5, 0x48, 0xfb, 0xd4,                             //  vpsubq zmm2,zmm3,zmm4
+      0x62, 0xf2, 0x4d, 0x48, 0x40, 0xef,                             //  vpmulld zmm5,zmm6,zmm7
+      0x62, 0x51, 0x35, 0x48, 0xdb, 0xc2,                             //  vpandd zmm8,zmm9,zmm10
+      0x62, 0x51, 0x9d, 0x48, 0xdf, 0xdd,                             //  vpandnq zmm11,zmm12,zmm13
+      0x62, 0x31, 0x05, 0x08, 0xeb, 0xf0,                             //  vpord xmm14,xmm15,xmm16
+      0x62, 0x81, 0xed, 0x40, 0xef, 0x4c, 0x48, 0x02,                 //  vpxorq zmm17,zmm18,ZMMWORD PTR [r8+r9*2+0x80]
+      0x62, 0xf3, 0x75, 0x48, 0x25, 0xc2, 0xff,                       //  vpternlogd zmm0,zmm1,zmm2,0xff
+      0x62, 0xf2, 0x7d, 0x48, 0x58, 0xc1,                             //  vpbroadcastd zmm0,xmm1
+      0x62, 0xf2, 0xfd, 0x48, 0x59, 0x50, 0x01,                       //  vpbroadcastq zmm2,QWORD PTR [rax+0x8]
+      0x62, 0xf2, 0x7d, 0x48, 0x18, 0x18,                             //  vbroadcastss zmm3,DWORD PTR [rax]
+      0x62, 0xf1, 0x7d, 0x48, 0x76, 0xc9,                             //  vpcmpeqd k1,zmm0,zmm1
+      0x62, 0xf2, 0xed, 0x49, 0x29, 0xd3,                             //  vpcmpeqq k2{k1},zmm2,zmm3
+      0x62, 0xf1, 0x5c, 0x48, 0xc2, 0xdd, 0x01,                       //  vcmpltps k3,zmm4,zmm5
+      0x62, 0xf1, 0xcd, 0x38, 0xc2, 0x20, 0x00,                       //  vcmpeqpd k4,ymm6,QWORD BCST [rax]
+      0xc5, 0xf8, 0x90, 0xca,                                         //  kmovw k1,k2
+      0xc5, 0xf8, 0x90, 0x18,                                         //  kmovw k3,WORD PTR [rax]
+      0xc5, 0xf8, 0x91, 0x20,                                         //  kmovw WORD PTR [rax],k4
+      0xc5, 0xf8, 0x92, 0xe8,                                         //  kmovw k5,eax
+      0xc5, 0x78, 0x93, 0xc6,                                         //  kmovw r8d,k6
+      0xc5, 0xec, 0x41, 0xcb,                                         //  kandw k1,k2,k3
+      0xc5, 0xd4, 0x42, 0xe6,                                         //  kandnw k4,k5,k6
+      0xc5, 0xfc, 0x45, 0xf9,                                         //  korw k7,k0,k1
+      0xc5, 0xe4, 0x47, 0xd4,                                         //  kxorw k2,k3,k4
+      0xc5, 0xf8, 0x44, 0xee,                                         //  knotw k5,k6
+      0xc5, 0xf8, 0x98, 0xf8,                                         //  kortestw k7,k0
+      0xc5, 0xf4, 0x58, 0xc2,                                         //  vaddps ymm0,ymm1,ymm2
+      0xc5, 0xd9, 0x76, 0xdd,                                         //  vpcmpeqd xmm3,xmm4,xmm5
+    ],
+  );
+  Ok(())
+}
+
+#[test]
+fn requiresEnhancedVectorExtensions ()
+{
+  common::assertRejected
+  (
+    &[
+      | code  | code.vaddps       ( X86::zmm0,  X86::zmm1,  X86::zmm2                     ),
+      | code  | code.vaddps       ( X86::ymm0,  X86::ymm1,  X86::ymm2                     ).mask ( X86::k1 ),
+      | code  | code.vpandd       ( X86::xmm0,  X86::xmm1,  X86::xmm2                     ),
+      | code  | code.vpcmpeqd     ( X86::k1,    X86::xmm1,  X86::xmm2                     ),
+      | code  | code.kmovw        ( X86::k1,    X86::k2                                   ),
+    ],
+    InstructionSet::Haswell,
+    64,
+    64,
+  );
+}
+
+#[test]
+fn enhancedVectorDecorations ()
+{
+  common::assertRejected
+  (
+    &[
+      | code  | code.addps        ( X86::xmm0,  X86::xmm1                                 ).mask ( X86::k1 ),
+      | code  | code.addps        ( X86::xmm16, X86::xmm1                                 ),
+      | code  | code.vaddps       ( X86::zmm0,  X86::zmm1,  X86::zmm2                     ).zeroing(),
+      | code  | code.vmovaps      ( x86Mem64! ( zword [ rax ] ),  X86::zmm0               ).mask ( X86::k1 ).zeroing(),
+      | code  | code.vaddps       ( X86::zmm0,  X86::zmm1,  X86::zmm2                     ).broadcast(),
+      | code  | code.vpaddd       ( X86::zmm0,  X86::zmm1,  x86Mem64! ( qword [ rax ] )   ).broadcast(),
+      | code  | code.vaddsd       ( X86::xmm0,  X86::xmm1,  x86Mem64! ( qword [ rax ] )   ).broadcast(),
+      | code  | code.vmovaps      ( X86::zmm0,  x86Mem64! ( zword [ rax ] )               ).broadcast(),
+      | code  | code.vpbroadcastd ( X86::zmm0,  x86Mem64! ( dword [ rax ] )               ).broadcast(),
+      | code  | code.vaddps       ( X86::zmm0,  X86::zmm1,  x86Mem64! ( zword [ rax ] )   ).rounding ( RoundingControl::Nearest ),
+      | code  | code.vaddps       ( X86::ymm0,  X86::ymm1,  X86::ymm2                     ).rounding ( RoundingControl::Up ),
+      | code  | code.vmaxps       ( X86::zmm0,  X86::zmm1,  X86::zmm2                     ).rounding ( RoundingControl::Up ),
+      | code  | code.vpcmpeqd     ( X86::k1,    X86::zmm1,  X86::zmm2                     ).mask ( X86::k2 ).zeroing(),
+      | code  | code.kmovw        ( X86::k1,    x86Mem64! ( dword [ rax ] )               ),
+    ],
+    InstructionSet::SkylakeX,
+    64,
+    64,
+  );
+  assert!
+  (
+    X86 ().vaddps ( X86::zmm8,  X86::zmm1,  X86::zmm2 ).compile
+    (
+      InstructionSet::SkylakeX,
+      32,
+      32,
+      10,
+    ).is_err()
+  );
+}
@@ -0,0 +1,134 @@
+//  every integration test includes this module, but not every test uses every helper
+#![allow(dead_code)]
+
+use sucks2::
+{
+  assembly::
+  {
+    InstructionSet,
+    x86::
+    {
+      X86,
+    },
+  },
+};
+
+use std::
+{
+  fs::
+  {
+    File,
+  },
+  io::
+  {
+    Write,
+  },
+  process::
+  {
+    Command,
+  },
+};
+
+pub fn hexDump
+(
+  buffer:                               &[u8],
+  width:                                usize,
+  offset:                               usize,
+  mut length:                           usize,
+) -> Result<usize, &'static str>
+{
+  let   size                            =   buffer.len();
+  if offset < size
+  {
+    if length == 0
+    {
+      length                            =   size - offset;
+    }
+    if length <= ( size - offset )
+    {
+      for line                          in  buffer [ offset .. offset + length ].chunks ( width )
+      {
+        for byte                        in  line
+        {
+          print!  ( "{:02x} ", byte );
+        }
+        for _                           in  line.len() .. width
+        {
+          print!  ( "   " );
+        }
+        print!  ( "| " );
+        for &char                       in  line
+        {
+          if  ( 0x20 ..= 0x7e ).contains ( &char )
+          ||  char >= 0xa0
+          {
+            print!  ( "{}", char as char );
+          }
+          else
+          {
+            print!  ( "." );
+          }
+        }
+        println!  ( );
+      }
+      Ok ( length )
+    }
+    else
+    {
+      Err ( "Length Out Of Bonds" )
+    }
+  }
+  else
+  {
+    Err ( "Offset Out Of Bonds" )
+  }
+}
+
+//  the binary is disassembled with objdump before the comparison, to see what went wrong
+pub fn assertAssembly
+(
+  name:                                 &str,
+  architecture:                         &str,
+  assembly:                             &[u8],
+  expected:                             &[u8],
+)
+{
+  let   path                            =   format! ( "build/{}.bin", name );
+  let mut file                          =   File::create  ( &path ).unwrap();
+  file.write_all  ( assembly  ).unwrap();
+  file.sync_all   (           ).unwrap();
+
+  Command::new("objdump")
+  .arg("--disassemble-all")
+  .arg("--disassembler-options=intel")
+  .arg("--target=binary")
+  .arg(format! ( "--architecture={}", architecture ))
+  .arg(&path)
+  .status()
+  .unwrap();
+  assert_eq!  ( assembly, expected  );
+}
+
+//  each instruction is compiled on its own, because the first error would abort the whole program
+pub fn assertRejected
+(
+  instructions:                         &[fn ( X86 ) -> X86],
+  architecture:                         InstructionSet,
+  operandSize:                          usize,
+  addressSize:                          usize,
+)
+{
+  for instruction                       in  instructions
+  {
+    assert!
+    (
+      instruction ( X86 () ).compile
+      (
+        architecture,
+        operandSize,
+        addressSize,
+        10,
+      ).is_err()
+    );
+  }
+}
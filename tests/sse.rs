@@ -0,0 +1,177 @@
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+
+#[macro_use]
+extern crate sucks2;
+use sucks2::
+{
+  assembly::
+  {
+    InstructionSet,
+    x86::
+    {
+      X86,
+      expressions::
+      {
+        Expression,
+        ExpressionToken,
+      },
+      memory::
+      {
+        Memory32NoRegister,
+      },
+      registers::
+      {
+        SegmentRegisterNumber,
+      },
+    },
+  },
+};
+
+mod common;
+
+#[test]
+fn main () -> Result<(), &'static str>
+{
+  let     myCode
+  = X86 ()
+    .label( "sse moves"                                                                             )
+    .movaps( X86::xmm0,                             X86::xmm1                                       ) //  Move Aligned Packed Single
+    .movaps( x86Mem32! ( oword [ esi ] ),           X86::xmm2                                       )
+    .movups( X86::xmm3,                             x86Mem32! ( oword [ eax ebx 4 * + ] )           ) //  Move Unaligned Packed Single
+    .movss( X86::xmm4,                              x86Mem32! ( dword [ ebp 8 + ] )                 ) //  Move Scalar Single
+    .movss( x86Mem32! ( dword [ edi ] ),            X86::xmm5                                       )
+    .label( "sse arithmetic"                                                                        )
+    .addps( X86::xmm0,                              X86::xmm1                                       ) //  Add Packed Single
+    .mulss( X86::xmm2,                              x86Mem32! ( dword [ ecx ] )                     ) //  Multiply Scalar Single
+    .sqrtps( X86::xmm3,                             X86::xmm4                                       ) //  Square Root
+    .maxss( X86::xmm5,                              X86::xmm6                                       ) //  Maximum
+    .xorps( X86::xmm7,                              X86::xmm7                                       ) //  Bitwise Exclusive Or
+    .andnps( X86::xmm0,                             x86Mem32! ( oword [ edx ] )                     ) //  Bitwise And Not
+    .cmpps( X86::xmm1,                              X86::xmm2,                                  1   ) //  Compare Less Than
+    .comiss( X86::xmm3,                             X86::xmm4                                       ) //  Compare and Set Flags
+    .cvtsi2ss( X86::xmm5,                           X86::eax                                        ) //  Convert from Integer
+    .cvttss2si( X86::ecx,                           X86::xmm6                                       ) //  Convert to Integer with Truncation
+    .cvtss2si( X86::edx,                            x86Mem32! ( dword [ esi ] )                     ) //  Convert to Integer
+    .label( "sse2 instructions"                                                                     )
+    .movapd( X86::xmm0,                             X86::xmm1                                       ) //  Move Aligned Packed Double
+    .movsd( X86::xmm2,                              x86Mem32! ( qword [ esp 4 + ] )                 ) //  Move Scalar Double
+    .movdqa( X86::xmm3,                             x86Mem32! ( oword [ eax ] )                     ) //  Move Aligned Double Quad Word
+    .movdqu( x86Mem32! ( oword [ ebx ] ),           X86::xmm4                                       ) //  Move Unaligned Double Quad Word
+    .addsd( X86::xmm5,                              X86::xmm6                                       ) //  Add Scalar Double
+    .divpd( X86::xmm7,                              x86Mem32! ( oword [ ecx ] )                     ) //  Divide Packed Double
+    .cmpsd( X86::xmm0,                              x86Mem32! ( qword [ edx ] ),                4   ) //  Compare not Equal
+    .ucomisd( X86::xmm1,                            X86::xmm2                                       ) //  Unordered Compare and Set Flags
+    .cvtsd2si( X86::eax,                            X86::xmm3                                       ) //  Convert to Integer
+    .cvtsi2sd( X86::xmm4,                           x86Mem32! ( dword [ edi ] )                     ) //  Convert from Integer
+    .cvtss2sd( X86::xmm5,                           X86::xmm6                                       ) //  Convert Single to Double
+    .cvtps2pd( X86::xmm7,                           x86Mem32! ( qword [ esi ] )                     ) //  Convert Packed Single to Double
+    .cvtdq2ps( X86::xmm0,                           X86::xmm1                                       ) //  Convert Double Words to Single
+    .paddd( X86::xmm2,                              X86::xmm3                                       ) //  Packed Add on XMM Registers
+    .pxor ( X86::xmm4,                              x86Mem32! ( oword [ eax ] )                     ) //  Bitwise Exclusive Or
+    .psllq( X86::xmm5,                              7                                               ) //  Shift by Immediate
+    .movd ( X86::xmm6,                              X86::ecx                                        ) //  Move Double Word
+    .movq ( X86::xmm7,                              x86Mem32! ( qword [ ebx ] )                     ) //  Move Quad Word
+    .movq ( x86Mem32! ( qword [ ebx ] ),            X86::xmm0                                       )
+    ;
+
+  let myAssembly
+  = myCode.compile
+    (
+      InstructionSet::Pentium4,
+      32,
+      32,
+      10,
+    ).unwrap();
+
+  common::assertAssembly
+  (
+    "sse",
+    "i386",
+    &myAssembly,
+    &[
+      0x0f, 0x28, 0xc1,                       //  movaps xmm0,xmm1
+      0x0f, 0x29, 0x16,                       //  movaps XMMWORD PTR [esi],xmm2
+      0x0f, 0x10, 0x1c, 0x98,                 //  movups xmm3,XMMWORD PTR [eax+ebx*4]
+      0xf3, 0x0f, 0x10, 0x65, 0x08,           //  movss xmm4,DWORD PTR [ebp+0x8]
+      0xf3, 0x0f, 0x11, 0x2f,                 //  movss DWORD PTR [edi],xmm5
+      0x0f, 0x58, 0xc1,                       //  addps xmm0,xmm1
+      0xf3, 0x0f, 0x59, 0x11,                 //  mulss xmm2,DWORD PTR [ecx]
+      0x0f, 0x51, 0xdc,                       //  sqrtps xmm3,xmm4
+      0xf3, 0x0f, 0x5f, 0xee,                 //  maxss xmm5,xmm6
+      0x0f, 0x57, 0xff,                       //  xorps xmm7,xmm7
+      0x0f, 0x55, 0x02,                       //  andnps xmm0,XMMWORD PTR [edx]
+      0x0f, 0xc2, 0xca, 0x01,                 //  cmpltps xmm1,xmm2
+      0x0f, 0x2f, 0xdc,                       //  comiss xmm3,xmm4
+      0xf3, 0x0f, 0x2a, 0xe8,                 //  cvtsi2ss xmm5,eax
+      0xf3, 0x0f, 0x2c, 0xce,                 //  cvttss2si ecx,xmm6
+      0xf3, 0x0f, 0x2d, 0x16,                 //  cvtss2si edx,DWORD PTR [esi]
+      0x66, 0x0f, 0x28, 0xc1,                 //  movapd xmm0,xmm1
+      0xf2, 0x0f, 0x10, 0x54, 0x24, 0x04,     //  movsd xmm2,QWORD PTR [esp+0x4]
+      0x66, 0x0f, 0x6f, 0x18,                 //  movdqa xmm3,XMMWORD PTR [eax]
+      0xf3, 0x0f, 0x7f, 0x23,                 //  movdqu XMMWORD PTR [ebx],xmm4
+      0xf2, 0x0f, 0x58, 0xee,                 //  addsd xmm5,xmm6
+      0x66, 0x0f, 0x5e, 0x39,                 //  divpd xmm7,XMMWORD PTR [ecx]
+      0xf2, 0x0f, 0xc2, 0x02, 0x04,           //  cmpneqsd xmm0,QWORD PTR [edx]
+      0x66, 0x0f, 0x2e, 0xca,                 //  ucomisd xmm1,xmm2
+      0xf2, 0x0f, 0x2d, 0xc3,                 //  cvtsd2si eax,xmm3
+      0xf2, 0x0f, 0x2a, 0x27,                 //  cvtsi2sd xmm4,DWORD PTR [edi]
+      0xf3, 0x0f, 0x5a, 0xee,                 //  cvtss2sd xmm5,xmm6
+      0x0f, 0x5a, 0x3e,                       //  cvtps2pd xmm7,QWORD PTR [esi]
+      0x0f, 0x5b, 0xc1,                       //  cvtdq2ps xmm0,xmm1
+      0x66, 0x0f, 0xfe, 0xd3,                 //  paddd xmm2,xmm3
+      0x66, 0x0f, 0xef, 0x20,                 //  pxor xmm4,XMMWORD PTR [eax]
+      0x66, 0x0f, 0x73, 0xf5, 0x07,           //  psllq xmm5,0x7
+      0x66, 0x0f, 0x6e, 0xf1,                 //  movd xmm6,ecx
+      0xf3, 0x0f, 0x7e, 0x3b,                 //  movq xmm7,QWORD PTR [ebx]
+      0x66, 0x0f, 0xd6, 0x03,                 //  movq QWORD PTR [ebx],xmm0
+    ],
+  );
+  Ok(())
+}
+
+#[test]
+fn requiresStreamingExtensions ()
+{
+  common::assertRejected
+  (
+    &[
+      | code  | code.movaps   ( X86::xmm0,  X86::xmm1                                     ),
+      | code  | code.addss    ( X86::xmm0,  X86::xmm1                                     ),
+      | code  | code.cvtsi2ss ( X86::xmm0,  X86::eax                                      ),
+    ],
+    InstructionSet::Pentium2,
+    32,
+    32,
+  );
+  common::assertRejected
+  (
+    &[
+      | code  | code.movdqa   ( X86::xmm0,  X86::xmm1                                     ),
+      | code  | code.addsd    ( X86::xmm0,  X86::xmm1                                     ),
+      | code  | code.paddb    ( X86::xmm0,  X86::xmm1                                     ),
+      | code  | code.movd     ( X86::xmm0,  X86::eax                                      ),
+    ],
+    InstructionSet::Pentium3,
+    32,
+    32,
+  );
+}
+
+#[test]
+fn streamingOperandSizes ()
+{
+  common::assertRejected
+  (
+    &[
+      | code  | code.addps    ( X86::xmm0,  X86::mm1                                      ),
+      | code  | code.movss    ( X86::xmm0,  x86Mem32! ( qword [ eax ] )                   ),
+      | code  | code.cvtsi2ss ( X86::xmm0,  X86::ax                                       ),
+      | code  | code.cmpps    ( X86::xmm0,  X86::xmm1,  8                                 ),
+      | code  | code.movq     ( X86::xmm0,  X86::mm1                                      ),
+    ],
+    InstructionSet::Pentium4,
+    32,
+    32,
+  );
+}
@@ -0,0 +1,116 @@
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+
+#[macro_use]
+extern crate sucks2;
+use sucks2::
+{
+  assembly::
+  {
+    InstructionSet,
+    x86::
+    {
+      X86,
+      expressions::
+      {
+        Expression,
+        ExpressionToken,
+      },
+      memory::
+      {
+        Memory32NoRegister,
+      },
+      registers::
+      {
+        SegmentRegisterNumber,
+      },
+    },
+  },
+};
+
+mod common;
+
+#[test]
+fn main () -> Result<(), &'static str>
+{
+  let     myCode
+  = X86 ()
+    .label( "rotations"                                                                             )
+    .vprotb( X86::xmm0,                             X86::xmm1,                      X86::xmm2       ) //  Rotate by Register
+    .vprotw( X86::xmm3,                             x86Mem32! ( oword [ esi ] ),    X86::xmm4       ) //  Rotate Memory
+    .vprotd( X86::xmm5,                             X86::xmm6,                      x86Mem32! ( oword [ edi ] ) ) //  Rotate by Memory
+    .vprotq( X86::xmm7,                             X86::xmm0,                      5               ) //  Rotate by Immediate
+    .label( "arithmetic shifts"                                                                     )
+    .vpshab( X86::xmm1,                             X86::xmm2,                      X86::xmm3       ) //  Shift Bytes
+    .vpshad( X86::xmm4,                             X86::xmm5,                      x86Mem32! ( oword [ eax ] ) ) //  Shift by Memory
+    .label( "four operands"                                                                         )
+    .vpcmov( X86::xmm0,                             X86::xmm1,                      X86::xmm2,      X86::xmm3 ) //  Conditional Move
+    .vpcmov( X86::ymm4,                             X86::ymm5,                      X86::ymm6,      x86Mem32! ( yword [ ebx ] ) )
+    .vpperm( X86::xmm7,                             X86::xmm0,                      x86Mem32! ( oword [ ecx ] ), X86::xmm1 ) //  Permute Bytes
+    .vpmacsww( X86::xmm2,                           X86::xmm3,                      X86::xmm4,      X86::xmm5 ) //  Multiply and Accumulate
+    .vpmacssdqh( X86::xmm6,                         X86::xmm7,                      x86Mem32! ( oword [ edx ] ), X86::xmm0 )
+    .label( "not an xop prefix"                                                                     )
+    .pop  ( x86Mem32! ( dword [ eax ] )                                                             ) //  0x8f /0
+    ;
+
+  let myAssembly
+  = myCode.compile
+    (
+      InstructionSet::Bulldozer,
+      32,
+      32,
+      10,
+    ).unwrap();
+
+  common::assertAssembly
+  (
+    "xop",
+    "i386",
+    &myAssembly,
+    &[
+      0x8f, 0xe9, 0x68, 0x90, 0xc1,           //  vprotb xmm0,xmm1,xmm2
+      0x8f, 0xe9, 0x58, 0x91, 0x1e,           //  vprotw xmm3,XMMWORD PTR [esi],xmm4
+      0x8f, 0xe9, 0xc8, 0x92, 0x2f,           //  vprotd xmm5,xmm6,XMMWORD PTR [edi]
+      0x8f, 0xe8, 0x78, 0xc3, 0xf8, 0x05,     //  vprotq xmm7,xmm0,0x5
+      0x8f, 0xe9, 0x60, 0x98, 0xca,           //  vpshab xmm1,xmm2,xmm3
+      0x8f, 0xe9, 0xd0, 0x9a, 0x20,           //  vpshad xmm4,xmm5,XMMWORD PTR [eax]
+      0x8f, 0xe8, 0x70, 0xa2, 0xc2, 0x30,     //  vpcmov xmm0,xmm1,xmm2,xmm3
+      0x8f, 0xe8, 0xd4, 0xa2, 0x23, 0x60,     //  vpcmov ymm4,ymm5,ymm6,YMMWORD PTR [ebx]
+      0x8f, 0xe8, 0x78, 0xa3, 0x39, 0x10,     //  vpperm xmm7,xmm0,XMMWORD PTR [ecx],xmm1
+      0x8f, 0xe8, 0x60, 0x95, 0xd4, 0x50,     //  vpmacsww xmm2,xmm3,xmm4,xmm5
+      0x8f, 0xe8, 0x40, 0x8f, 0x32, 0x00,     //  vpmacssdqh xmm6,xmm7,XMMWORD PTR [edx],xmm0
+      0x8f, 0x00,                             //  pop DWORD PTR [eax]
+    ],
+  );
+  Ok(())
+}
+
+#[test]
+fn extendedOperationsOperands ()
+{
+  common::assertRejected
+  (
+    &[
+      | code  | code.vprotb       ( X86::ymm0,  X86::ymm1,  X86::ymm2                     ),
+      | code  | code.vpshab       ( X86::xmm0,  X86::xmm1,  4                             ),
+      | code  | code.vpperm       ( X86::ymm0,  X86::ymm1,  X86::ymm2,  X86::ymm3         ),
+      | code  | code.vpmacsww     ( X86::xmm0,  X86::xmm1,  X86::xmm2,  x86Mem32! ( oword [ eax ] ) ),
+    ],
+    InstructionSet::Bulldozer,
+    32,
+    32,
+  );
+  for architecture                      in  [ InstructionSet::SandyBridge, InstructionSet::Haswell, InstructionSet::SkylakeX ]
+  {
+    assert!
+    (
+      X86 ().vprotb ( X86::xmm0,  X86::xmm1,  X86::xmm2 ).compile
+      (
+        architecture,
+        64,
+        64,
+        10,
+      ).is_err()
+    );
+  }
+}